@@ -11,6 +11,8 @@ use figment::{
 const CONFIG_FILE_PATH: &str = "config_file_path";
 const CONFIG_FILE_NAME: &str = "config.toml";
 const AWS_CREDENTIALS_FILE: &str = ".aws";
+const SAVED_QUERIES_FILE_NAME: &str = "saved_queries.json";
+const KUBECONFIG_FILE: &str = ".kube/config";
 
 #[derive(Clone, Default, Debug, Deserialize, Serialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -86,6 +88,30 @@ fn get_default_config_path() -> PathBuf {
     config_path
 }
 
+pub fn get_saved_queries_path() -> PathBuf {
+    get_default_config_path().with_file_name(SAVED_QUERIES_FILE_NAME)
+}
+
+pub fn get_default_download_path() -> PathBuf {
+    match UserDirs::new() {
+        None => PathBuf::from("."),
+        Some(user_dirs) => user_dirs
+            .download_dir()
+            .unwrap_or(user_dirs.home_dir())
+            .to_path_buf(),
+    }
+}
+
+pub fn get_default_kubeconfig_path() -> PathBuf {
+    let mut kubeconfig_path = match UserDirs::new() {
+        None => PathBuf::from("."),
+        Some(user_dirs) => user_dirs.home_dir().to_path_buf(),
+    };
+
+    kubeconfig_path.push(KUBECONFIG_FILE);
+    kubeconfig_path
+}
+
 fn get_default_aws_credential_path() -> PathBuf {
     let mut credential_path = match UserDirs::new() {
         None => PathBuf::from("."),
@@ -159,7 +185,7 @@ mod tests {
     performance = "yes"
 
     [aws]
-    credentialsPath = "/home/test"
+    filePath = "/home/test"
     profiles = ["dev", "prod", "staging"]
     regions = ["eu-central-1", "eu-central-2"]
     services = ["ECS", "EKS", "S3"]
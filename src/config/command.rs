@@ -4,7 +4,7 @@ use clap::{builder::PossibleValuesParser, command, Arg};
 
 use crate::ui;
 
-pub(crate) const CREDENTIALS_KEY: &str = "aws.credentialsPath";
+pub(crate) const CREDENTIALS_KEY: &str = "aws.filePath";
 pub(crate) const ENDPOINT_KEY: &str = "aws.endpoint";
 pub(crate) const LOG_LEVEL_KEY: &str = "logging.level";
 pub(crate) const LOG_FILE_PATH: &str = "logging.logFilePath";
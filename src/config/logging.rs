@@ -37,13 +37,13 @@ fn create_writer(
 }
 
 fn level_from_string(log_level: &str) -> Level {
-    return match log_level.to_uppercase().as_str() {
+    match log_level.to_uppercase().as_str() {
         "TRACE" => Level::TRACE,
         "DEBUG" => Level::DEBUG,
         "INFO" => Level::INFO,
         "WARN" => Level::WARN,
         _ => Level::ERROR,
-    };
+    }
 }
 
 #[cfg(test)]
@@ -64,11 +64,13 @@ mod tests_logging {
             log_file_path: temp_dir.as_os_str().to_os_string().into_string().unwrap(),
         };
 
-        let _guard = init_tracing(&config);
+        let guard = init_tracing(&config);
         let warn_message = "My nice logging message";
         event!(Level::WARN, "{}", warn_message);
         let info_message = "Something different";
         event!(Level::INFO, "{}", info_message);
+        // dropping the guard flushes the non-blocking writer
+        drop(guard);
 
         let mut entries = fs::read_dir(temp_dir.path()).unwrap();
         let path = entries.next().unwrap().unwrap().path();
@@ -1,4 +1,20 @@
 pub mod cloud_watch_logs;
+pub mod dynamodb;
+pub mod dynamodb_export;
+pub mod dynamodb_json;
 pub mod ec2;
+pub mod ec2_instance;
+pub mod ec2_network;
+pub mod ec2_storage;
+pub mod ec2_vpc;
+pub mod eks;
+pub mod kubeconfig;
+pub mod live_tail;
+pub mod log_event_export;
 pub mod login;
 pub mod profile;
+pub mod s3;
+pub mod s3_bucket_config;
+pub mod s3_sync;
+pub mod s3_transfer;
+pub mod saved_queries;
@@ -1,9 +1,20 @@
 use anyhow::anyhow;
 use aws_config::SdkConfig;
-use aws_sdk_cloudwatchlogs::{config, Client};
+use aws_sdk_cloudwatchlogs::{
+    config,
+    types::{OrderBy, QueryStatus},
+    Client,
+};
 use tracing::{event, Level};
 
-use crate::{config::app_config::AWSConfig, state::cloud_watch_logs_state::CloudWatchLogGroup};
+use crate::{
+    config::app_config::AWSConfig,
+    state::cloud_watch_logs_state::{
+        CloudWatchFilterMatch, CloudWatchInsightsResults, CloudWatchLogEvent, CloudWatchLogGroup,
+        CloudWatchLogGroupNameFilter, CloudWatchLogStream, CloudWatchMetricFilter,
+        CloudWatchSubscriptionFilter,
+    },
+};
 
 pub struct CloudWatchLogsRepository;
 
@@ -11,13 +22,23 @@ impl CloudWatchLogsRepository {
     pub async fn describe_log_groups(
         aws_config: &AWSConfig,
         sdk_config: &SdkConfig,
+        name_filter: Option<&CloudWatchLogGroupNameFilter>,
         next_token: Option<String>,
-    ) -> anyhow::Result<Vec<CloudWatchLogGroup>> {
+    ) -> anyhow::Result<(Vec<CloudWatchLogGroup>, Option<String>)> {
         let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
         let mut log_group_client = client.describe_log_groups();
         if let Some(next_token_string) = next_token {
             log_group_client = log_group_client.next_token(next_token_string);
         };
+        log_group_client = match name_filter {
+            Some(CloudWatchLogGroupNameFilter::Prefix(prefix)) => {
+                log_group_client.log_group_name_prefix(prefix)
+            }
+            Some(CloudWatchLogGroupNameFilter::Pattern(pattern)) => {
+                log_group_client.log_group_name_pattern(pattern)
+            }
+            None => log_group_client,
+        };
 
         match log_group_client.send().await {
             Ok(response) => {
@@ -31,12 +52,109 @@ impl CloudWatchLogsRepository {
                         retention_days: group.retention_in_days(),
                         stored_bytes: group.stored_bytes(),
                         log_streams: vec![],
+                        log_streams_next_token: None,
                     })
                     .collect();
 
                 event!(Level::DEBUG, "{:?}", log_groups);
 
-                Ok(log_groups)
+                Ok((log_groups, response.next_token().map(|token| token.into())))
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// `None` removes the retention policy, the log events then never expire.
+    pub async fn put_retention_policy(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+        retention_days: Option<i32>,
+    ) -> anyhow::Result<()> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+        let result = match retention_days {
+            Some(retention_days) => client
+                .put_retention_policy()
+                .log_group_name(log_group_name)
+                .retention_in_days(retention_days)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+            None => client
+                .delete_retention_policy()
+                .log_group_name(log_group_name)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+        };
+
+        if let Err(err) = &result {
+            event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+        }
+
+        result
+    }
+
+    pub async fn delete_log_group(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+    ) -> anyhow::Result<()> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client
+            .delete_log_group()
+            .log_group_name(log_group_name)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn describe_log_streams(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+        next_token: Option<String>,
+    ) -> anyhow::Result<(Vec<CloudWatchLogStream>, Option<String>)> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+        let log_stream_client = client
+            .describe_log_streams()
+            .log_group_name(log_group_name)
+            .order_by(OrderBy::LastEventTime)
+            .descending(true)
+            .set_next_token(next_token);
+
+        match log_stream_client.send().await {
+            Ok(response) => {
+                let log_streams = response
+                    .log_streams()
+                    .iter()
+                    .map(|stream| CloudWatchLogStream {
+                        arn: stream.arn().unwrap_or_default().into(),
+                        log_stream_name: stream.log_stream_name().map(|name| name.into()),
+                        creation_time: stream.creation_time(),
+                        first_event_timestamp: stream.first_event_timestamp(),
+                        last_event_timestamp: stream.last_event_timestamp(),
+                        last_ingestion_time: stream.last_ingestion_time(),
+                        #[allow(deprecated)]
+                        stored_bytes: stream.stored_bytes(),
+                    })
+                    .collect();
+
+                event!(Level::DEBUG, "{:?}", log_streams);
+
+                Ok((log_streams, response.next_token().map(|token| token.into())))
             }
             Err(err) => {
                 event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
@@ -45,6 +163,347 @@ impl CloudWatchLogsRepository {
         }
     }
 
+    pub async fn get_log_events(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+        log_stream_name: &str,
+    ) -> anyhow::Result<Vec<CloudWatchLogEvent>> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+        let log_event_client = client
+            .get_log_events()
+            .log_group_name(log_group_name)
+            .log_stream_name(log_stream_name);
+
+        match log_event_client.send().await {
+            Ok(response) => {
+                let log_events = response
+                    .events()
+                    .iter()
+                    .map(|event| CloudWatchLogEvent {
+                        event_id: None,
+                        log_stream_name: Some(log_stream_name.into()),
+                        timestamp: event.timestamp(),
+                        ingestion_time: event.ingestion_time(),
+                        message: event.message().map(|message| message.into()),
+                    })
+                    .collect();
+
+                event!(Level::DEBUG, "{:?}", log_events);
+
+                Ok(log_events)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn filter_log_events(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+        log_stream_names: &[String],
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+    ) -> anyhow::Result<Vec<CloudWatchLogEvent>> {
+        let mut log_events = vec![];
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let (page_log_events, page_next_token) =
+                CloudWatchLogsRepository::filter_log_events_page(
+                    aws_config,
+                    sdk_config,
+                    log_group_name,
+                    log_stream_names,
+                    (start_time, end_time),
+                    next_token.take(),
+                )
+                .await?;
+            log_events.extend(page_log_events);
+
+            match page_next_token {
+                Some(token) => next_token = Some(token),
+                None => break,
+            }
+        }
+
+        event!(Level::DEBUG, "{:?}", log_events);
+
+        Ok(log_events)
+    }
+
+    pub async fn filter_log_events_page(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+        log_stream_names: &[String],
+        (start_time, end_time): (Option<i64>, Option<i64>),
+        next_token: Option<String>,
+    ) -> anyhow::Result<(Vec<CloudWatchLogEvent>, Option<String>)> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+        let log_event_client = client
+            .filter_log_events()
+            .log_group_name(log_group_name)
+            .set_log_stream_names(if log_stream_names.is_empty() {
+                None
+            } else {
+                Some(log_stream_names.to_vec())
+            })
+            .set_start_time(start_time)
+            .set_end_time(end_time)
+            .set_next_token(next_token);
+
+        match log_event_client.send().await {
+            Ok(response) => {
+                let log_events = response
+                    .events()
+                    .iter()
+                    .map(|event| CloudWatchLogEvent {
+                        event_id: event.event_id().map(|event_id| event_id.into()),
+                        log_stream_name: event.log_stream_name().map(|name| name.into()),
+                        timestamp: event.timestamp(),
+                        ingestion_time: event.ingestion_time(),
+                        message: event.message().map(|message| message.into()),
+                    })
+                    .collect();
+
+                Ok((log_events, response.next_token().map(|token| token.into())))
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn describe_metric_filters(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+    ) -> anyhow::Result<Vec<CloudWatchMetricFilter>> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client
+            .describe_metric_filters()
+            .log_group_name(log_group_name)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let metric_filters = response
+                    .metric_filters()
+                    .iter()
+                    .map(|metric_filter| CloudWatchMetricFilter {
+                        filter_name: metric_filter.filter_name().map(|name| name.into()),
+                        filter_pattern: metric_filter
+                            .filter_pattern()
+                            .map(|pattern| pattern.into()),
+                        metric_transformations: metric_filter
+                            .metric_transformations()
+                            .iter()
+                            .map(|transformation| {
+                                format!(
+                                    "{}/{} = {}",
+                                    transformation.metric_namespace(),
+                                    transformation.metric_name(),
+                                    transformation.metric_value()
+                                )
+                            })
+                            .collect(),
+                    })
+                    .collect();
+
+                event!(Level::DEBUG, "{:?}", metric_filters);
+
+                Ok(metric_filters)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn describe_subscription_filters(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_name: &str,
+    ) -> anyhow::Result<Vec<CloudWatchSubscriptionFilter>> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client
+            .describe_subscription_filters()
+            .log_group_name(log_group_name)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let subscription_filters = response
+                    .subscription_filters()
+                    .iter()
+                    .map(|subscription_filter| CloudWatchSubscriptionFilter {
+                        filter_name: subscription_filter.filter_name().map(|name| name.into()),
+                        filter_pattern: subscription_filter
+                            .filter_pattern()
+                            .map(|pattern| pattern.into()),
+                        destination_arn: subscription_filter
+                            .destination_arn()
+                            .map(|destination_arn| destination_arn.into()),
+                        distribution: subscription_filter
+                            .distribution()
+                            .map(|distribution| distribution.as_str().into()),
+                    })
+                    .collect();
+
+                event!(Level::DEBUG, "{:?}", subscription_filters);
+
+                Ok(subscription_filters)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn test_metric_filter(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        filter_pattern: &str,
+        log_event_messages: &[String],
+    ) -> anyhow::Result<Vec<CloudWatchFilterMatch>> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client
+            .test_metric_filter()
+            .filter_pattern(filter_pattern)
+            .set_log_event_messages(Some(log_event_messages.to_vec()))
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .matches()
+                .iter()
+                .map(|filter_match| {
+                    let mut extracted_values = filter_match
+                        .extracted_values()
+                        .map(|values| {
+                            values
+                                .iter()
+                                .map(|(key, value)| (key.clone(), value.clone()))
+                                .collect::<Vec<(String, String)>>()
+                        })
+                        .unwrap_or_default();
+                    extracted_values.sort();
+
+                    CloudWatchFilterMatch {
+                        event_number: filter_match.event_number(),
+                        event_message: filter_match.event_message().map(|message| message.into()),
+                        extracted_values,
+                    }
+                })
+                .collect()),
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn start_query(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        log_group_names: &[String],
+        query_string: &str,
+        (start_time, end_time): (i64, i64),
+    ) -> anyhow::Result<String> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+        let query_client = client
+            .start_query()
+            .set_log_group_names(Some(log_group_names.to_vec()))
+            .query_string(query_string)
+            .start_time(start_time)
+            .end_time(end_time);
+
+        match query_client.send().await {
+            Ok(response) => Ok(response.query_id().unwrap_or_default().into()),
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn get_query_results(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        query_id: &str,
+    ) -> anyhow::Result<CloudWatchInsightsResults> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client.get_query_results().query_id(query_id).send().await {
+            Ok(response) => {
+                let status = response.status();
+                let results = CloudWatchInsightsResults {
+                    status: status.map_or("Unknown", |status| status.as_str()).into(),
+                    is_finished: matches!(
+                        status,
+                        Some(
+                            QueryStatus::Complete
+                                | QueryStatus::Failed
+                                | QueryStatus::Cancelled
+                                | QueryStatus::Timeout
+                        )
+                    ),
+                    records_matched: response
+                        .statistics()
+                        .map(|statistics| statistics.records_matched()),
+                    rows: response
+                        .results()
+                        .iter()
+                        .map(|row| {
+                            row.iter()
+                                .map(|field| {
+                                    (
+                                        field.field().unwrap_or_default().into(),
+                                        field.value().unwrap_or_default().into(),
+                                    )
+                                })
+                                .collect()
+                        })
+                        .collect(),
+                };
+
+                event!(Level::DEBUG, "{:?}", results);
+
+                Ok(results)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn stop_query(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        query_id: &str,
+    ) -> anyhow::Result<()> {
+        let client = CloudWatchLogsRepository::get_client(aws_config, sdk_config);
+
+        match client.stop_query().query_id(query_id).send().await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error CloudWatch Logs Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
     fn get_client(aws_config: &AWSConfig, sdk_config: &SdkConfig) -> Client {
         let mut client_builder = config::Builder::from(sdk_config);
 
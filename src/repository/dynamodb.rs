@@ -0,0 +1,617 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use aws_config::SdkConfig;
+use aws_sdk_dynamodb::{
+    config::{self, Region},
+    error::{ProvideErrorMetadata, SdkError},
+    operation::{batch_write_item::BatchWriteItemError, query::QueryError, scan::ScanError},
+    types::{
+        AttributeValue, KeySchemaElement, KeyType, PutRequest, TableDescription, WriteRequest,
+    },
+    Client,
+};
+use futures::future::try_join_all;
+use serde_json::Value;
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::AWSConfig,
+    state::dynamodb_state::{
+        DynamoDbIndex, DynamoDbKey, DynamoDbKeyCondition, DynamoDbQuery, DynamoDbSortKeyOperator,
+        DynamoDbTable,
+    },
+};
+
+use super::dynamodb_json::{item_to_json, json_to_attribute_value, json_to_item};
+
+/// Scans and queries fetch this many items per page.
+const PAGE_SIZE: i32 = 100;
+
+/// A batch write puts at most this many items.
+pub const BATCH_WRITE_SIZE: usize = 25;
+
+const THROTTLING_ERROR_CODES: [&str; 3] = [
+    "ProvisionedThroughputExceededException",
+    "ThrottlingException",
+    "RequestLimitExceeded",
+];
+
+/// An expression with its attribute names and values.
+type Expression = (
+    String,
+    HashMap<String, String>,
+    HashMap<String, AttributeValue>,
+);
+
+pub struct DynamoDBRepository;
+
+impl DynamoDBRepository {
+    pub async fn list_tables(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<DynamoDbTable>> {
+        let client = &DynamoDBRepository::create_client(aws_config, config, region);
+        let mut table_names = vec![];
+        let mut exclusive_start_table_name = None;
+
+        loop {
+            match client
+                .list_tables()
+                .set_exclusive_start_table_name(exclusive_start_table_name)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    table_names.extend_from_slice(response.table_names());
+                    exclusive_start_table_name = response
+                        .last_evaluated_table_name()
+                        .map(|table_name| table_name.to_string());
+                    if exclusive_start_table_name.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        // the counts, sizes and keys of a table are an extra request, they run concurrently
+        let tables = try_join_all(
+            table_names
+                .iter()
+                .map(|table_name| DynamoDBRepository::describe_table(client, table_name)),
+        )
+        .await?;
+        event!(Level::DEBUG, "{:?}", tables);
+
+        Ok(tables)
+    }
+
+    /// Fetches a page of the scan or query, continuing after the given key.
+    pub async fn fetch_items(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        query: &DynamoDbQuery,
+        exclusive_start_key: Option<&Value>,
+    ) -> anyhow::Result<(Vec<Value>, Option<Value>)> {
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        let exclusive_start_key = exclusive_start_key.map(json_to_item).transpose()?;
+
+        let result = match &query.key_condition {
+            Some(key_condition) => {
+                let (expression, names, values) = create_key_condition_expression(key_condition)?;
+                client
+                    .query()
+                    .table_name(&query.table_name)
+                    .set_index_name(query.index_name.clone())
+                    .key_condition_expression(expression)
+                    .set_expression_attribute_names(Some(names))
+                    .set_expression_attribute_values(Some(values))
+                    .set_exclusive_start_key(exclusive_start_key)
+                    .limit(PAGE_SIZE)
+                    .send()
+                    .await
+                    .map(|response| {
+                        (
+                            response.items().iter().map(item_to_json).collect(),
+                            response.last_evaluated_key().map(item_to_json),
+                        )
+                    })
+                    .map_err(|err| anyhow!(err))
+            }
+            None => client
+                .scan()
+                .table_name(&query.table_name)
+                .set_index_name(query.index_name.clone())
+                .set_exclusive_start_key(exclusive_start_key)
+                .limit(PAGE_SIZE)
+                .send()
+                .await
+                .map(|response| {
+                    (
+                        response.items().iter().map(item_to_json).collect(),
+                        response.last_evaluated_key().map(item_to_json),
+                    )
+                })
+                .map_err(|err| anyhow!(err)),
+        };
+
+        if let Err(err) = &result {
+            event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+        }
+
+        result
+    }
+
+    /// Writes the whole item. With a partition key, an existing item of the same key is
+    /// not overwritten, so that a new item cannot replace one by accident.
+    pub async fn put_item(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        table_name: &str,
+        item: &Value,
+        partition_key: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        let mut request = client
+            .put_item()
+            .table_name(table_name)
+            .set_item(Some(json_to_item(item)?));
+        if let Some(partition_key) = partition_key {
+            request = request
+                .condition_expression("attribute_not_exists(#pk)")
+                .expression_attribute_names("#pk", partition_key);
+        }
+
+        match request.send().await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Sets the changed attributes and removes the deleted ones, the other attributes
+    /// are left as they are, even if they were changed meanwhile.
+    pub async fn update_item(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        table_name: &str,
+        key: &Value,
+        original_item: &Value,
+        item: &Value,
+    ) -> anyhow::Result<()> {
+        let Some((expression, names, values)) = create_update_expression(key, original_item, item)?
+        else {
+            return Ok(());
+        };
+
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        match client
+            .update_item()
+            .table_name(table_name)
+            .set_key(Some(json_to_item(key)?))
+            .update_expression(expression)
+            .set_expression_attribute_names(Some(names))
+            .set_expression_attribute_values((!values.is_empty()).then_some(values))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn delete_item(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        table_name: &str,
+        key: &Value,
+    ) -> anyhow::Result<()> {
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        match client
+            .delete_item()
+            .table_name(table_name)
+            .set_key(Some(json_to_item(key)?))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Executes a PartiQL statement, continuing after the given token for the next page
+    /// of a select.
+    pub async fn execute_statement(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        statement: &str,
+        next_token: Option<String>,
+    ) -> anyhow::Result<(Vec<Value>, Option<String>)> {
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        match client
+            .execute_statement()
+            .statement(statement)
+            .set_next_token(next_token)
+            .limit(PAGE_SIZE)
+            .send()
+            .await
+        {
+            Ok(response) => Ok((
+                response.items().iter().map(item_to_json).collect(),
+                response
+                    .next_token()
+                    .map(|next_token| next_token.to_string()),
+            )),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    async fn describe_table(client: &Client, table_name: &str) -> anyhow::Result<DynamoDbTable> {
+        match client.describe_table().table_name(table_name).send().await {
+            Ok(response) => Ok(response
+                .table()
+                .map(create_dynamodb_table)
+                .unwrap_or_else(|| DynamoDbTable {
+                    table_name: table_name.into(),
+                    ..DynamoDbTable::default()
+                })),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Puts the items of a batch, returning the items DynamoDB did not process, which
+    /// have to be written again.
+    pub async fn batch_write_items(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        table_name: &str,
+        items: &[Value],
+    ) -> anyhow::Result<Vec<Value>> {
+        let client = DynamoDBRepository::create_client(aws_config, config, region);
+        let write_requests = items
+            .iter()
+            .map(|item| {
+                let put_request = PutRequest::builder()
+                    .set_item(Some(json_to_item(item)?))
+                    .build()?;
+                anyhow::Ok(WriteRequest::builder().put_request(put_request).build())
+            })
+            .collect::<anyhow::Result<Vec<WriteRequest>>>()?;
+
+        match client
+            .batch_write_item()
+            .request_items(table_name, write_requests)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .unprocessed_items()
+                .and_then(|unprocessed_items| unprocessed_items.get(table_name))
+                .map(|write_requests| {
+                    write_requests
+                        .iter()
+                        .filter_map(|write_request| write_request.put_request())
+                        .map(|put_request| item_to_json(put_request.item()))
+                        .collect()
+                })
+                .unwrap_or_default()),
+            Err(err) => {
+                event!(Level::WARN, "Error DynamoDB Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Whether a scan, query or batch write failed as the table or account is
+    /// throttled, so that it succeeds when retried later.
+    pub fn is_throttling_error(err: &anyhow::Error) -> bool {
+        let code = err
+            .downcast_ref::<SdkError<ScanError>>()
+            .and_then(|err| err.code())
+            .or_else(|| {
+                err.downcast_ref::<SdkError<QueryError>>()
+                    .and_then(|err| err.code())
+            })
+            .or_else(|| {
+                err.downcast_ref::<SdkError<BatchWriteItemError>>()
+                    .and_then(|err| err.code())
+            });
+
+        code.is_some_and(|code| THROTTLING_ERROR_CODES.contains(&code))
+    }
+
+    fn create_client(aws_config: &AWSConfig, config: &SdkConfig, region: Option<&str>) -> Client {
+        let mut client_builder = config::Builder::from(config);
+
+        if !aws_config.endpoint.is_empty() {
+            client_builder = client_builder.endpoint_url(&aws_config.endpoint);
+        }
+
+        if let Some(region) = region {
+            client_builder = client_builder.region(Region::new(region.to_string()));
+        }
+
+        Client::from_conf(client_builder.build())
+    }
+}
+
+fn create_dynamodb_table(description: &TableDescription) -> DynamoDbTable {
+    let attribute_types = description
+        .attribute_definitions()
+        .iter()
+        .map(|definition| {
+            (
+                definition.attribute_name(),
+                definition.attribute_type().as_str(),
+            )
+        })
+        .collect::<HashMap<&str, &str>>();
+    let (partition_key, sort_key) = create_keys(description.key_schema(), &attribute_types);
+
+    DynamoDbTable {
+        table_name: description.table_name().unwrap_or_default().into(),
+        status: description
+            .table_status()
+            .map(|status| status.as_str().into()),
+        item_count: description.item_count(),
+        size_bytes: description.table_size_bytes(),
+        // tables created without a billing mode are provisioned
+        billing_mode: Some(
+            description
+                .billing_mode_summary()
+                .and_then(|summary| summary.billing_mode())
+                .map(|billing_mode| billing_mode.as_str())
+                .unwrap_or("PROVISIONED")
+                .into(),
+        ),
+        partition_key,
+        sort_key,
+        indexes: description
+            .global_secondary_indexes()
+            .iter()
+            .map(|index| (index.index_name(), index.key_schema(), true))
+            .chain(
+                description
+                    .local_secondary_indexes()
+                    .iter()
+                    .map(|index| (index.index_name(), index.key_schema(), false)),
+            )
+            .map(|(index_name, key_schema, is_global)| {
+                let (partition_key, sort_key) = create_keys(key_schema, &attribute_types);
+                DynamoDbIndex {
+                    index_name: index_name.unwrap_or_default().into(),
+                    is_global,
+                    partition_key,
+                    sort_key,
+                }
+            })
+            .collect(),
+    }
+}
+
+fn create_keys(
+    key_schema: &[KeySchemaElement],
+    attribute_types: &HashMap<&str, &str>,
+) -> (DynamoDbKey, Option<DynamoDbKey>) {
+    let create_key = |element: &KeySchemaElement| DynamoDbKey {
+        attribute_name: element.attribute_name().into(),
+        attribute_type: attribute_types
+            .get(element.attribute_name())
+            .unwrap_or(&"S")
+            .to_string(),
+    };
+
+    (
+        key_schema
+            .iter()
+            .find(|element| *element.key_type() == KeyType::Hash)
+            .map(create_key)
+            .unwrap_or_default(),
+        key_schema
+            .iter()
+            .find(|element| *element.key_type() == KeyType::Range)
+            .map(create_key),
+    )
+}
+
+/// Key names are always passed as placeholders, many common names are reserved words.
+fn create_key_condition_expression(
+    key_condition: &DynamoDbKeyCondition,
+) -> anyhow::Result<Expression> {
+    let mut names = HashMap::from([(
+        "#pk".to_string(),
+        key_condition.partition_key.attribute_name.clone(),
+    )]);
+    let mut values = HashMap::from([(
+        ":pk".to_string(),
+        create_key_value(
+            &key_condition.partition_key,
+            &key_condition.partition_key_value,
+        )?,
+    )]);
+    let mut expression = "#pk = :pk".to_string();
+
+    if let Some(sort_key_condition) = &key_condition.sort_key_condition {
+        names.insert(
+            "#sk".into(),
+            sort_key_condition.sort_key.attribute_name.clone(),
+        );
+        values.insert(
+            ":sk".into(),
+            create_key_value(&sort_key_condition.sort_key, &sort_key_condition.value)?,
+        );
+
+        let condition = match sort_key_condition.operator {
+            DynamoDbSortKeyOperator::Equal => "#sk = :sk",
+            DynamoDbSortKeyOperator::Less => "#sk < :sk",
+            DynamoDbSortKeyOperator::LessOrEqual => "#sk <= :sk",
+            DynamoDbSortKeyOperator::Greater => "#sk > :sk",
+            DynamoDbSortKeyOperator::GreaterOrEqual => "#sk >= :sk",
+            DynamoDbSortKeyOperator::BeginsWith => "begins_with(#sk, :sk)",
+            DynamoDbSortKeyOperator::Between => {
+                values.insert(
+                    ":sk2".into(),
+                    create_key_value(
+                        &sort_key_condition.sort_key,
+                        &sort_key_condition.second_value,
+                    )?,
+                );
+                "#sk BETWEEN :sk AND :sk2"
+            }
+        };
+        expression = format!("{} AND {}", expression, condition);
+    }
+
+    Ok((expression, names, values))
+}
+
+/// The update of the non-key attributes from the original to the edited item, none if
+/// nothing changed.
+fn create_update_expression(
+    key: &Value,
+    original_item: &Value,
+    item: &Value,
+) -> anyhow::Result<Option<Expression>> {
+    let empty_attributes = serde_json::Map::new();
+    let original_attributes = original_item.as_object().unwrap_or(&empty_attributes);
+    let attributes = item.as_object().unwrap_or(&empty_attributes);
+    let is_key = |name: &String| key.get(name).is_some();
+
+    let mut names = HashMap::new();
+    let mut values = HashMap::new();
+    let mut set_actions = vec![];
+    for (name, value) in attributes {
+        if is_key(name) || original_attributes.get(name) == Some(value) {
+            continue;
+        }
+        let position = names.len();
+        names.insert(format!("#a{}", position), name.clone());
+        values.insert(format!(":v{}", position), json_to_attribute_value(value)?);
+        set_actions.push(format!("#a{} = :v{}", position, position));
+    }
+
+    let mut remove_actions = vec![];
+    for name in original_attributes.keys() {
+        if is_key(name) || attributes.contains_key(name) {
+            continue;
+        }
+        let position = names.len();
+        names.insert(format!("#a{}", position), name.clone());
+        remove_actions.push(format!("#a{}", position));
+    }
+
+    let mut clauses = vec![];
+    if !set_actions.is_empty() {
+        clauses.push(format!("SET {}", set_actions.join(", ")));
+    }
+    if !remove_actions.is_empty() {
+        clauses.push(format!("REMOVE {}", remove_actions.join(", ")));
+    }
+
+    Ok((!clauses.is_empty()).then(|| (clauses.join(" "), names, values)))
+}
+
+fn create_key_value(key: &DynamoDbKey, value: &str) -> anyhow::Result<AttributeValue> {
+    let mut typed_value = serde_json::Map::new();
+    typed_value.insert(key.attribute_type.clone(), Value::String(value.into()));
+    json_to_attribute_value(&Value::Object(typed_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::state::dynamodb_state::DynamoDbSortKeyCondition;
+
+    use super::*;
+
+    #[test]
+    fn test_create_key_condition_with_placeholders() {
+        let key_condition = DynamoDbKeyCondition {
+            partition_key: DynamoDbKey {
+                attribute_name: "user".into(),
+                attribute_type: "S".into(),
+            },
+            partition_key_value: "alice".into(),
+            sort_key_condition: Some(DynamoDbSortKeyCondition {
+                sort_key: DynamoDbKey {
+                    attribute_name: "timestamp".into(),
+                    attribute_type: "N".into(),
+                },
+                operator: DynamoDbSortKeyOperator::Between,
+                value: "100".into(),
+                second_value: "200".into(),
+            }),
+        };
+
+        let (expression, names, values) = create_key_condition_expression(&key_condition).unwrap();
+        assert_eq!(expression, "#pk = :pk AND #sk BETWEEN :sk AND :sk2");
+        assert_eq!(names["#sk"], "timestamp");
+        assert_eq!(values[":pk"], AttributeValue::S("alice".into()));
+        assert_eq!(values[":sk2"], AttributeValue::N("200".into()));
+
+        let invalid_condition = DynamoDbKeyCondition {
+            partition_key_value: "not a number".into(),
+            partition_key: DynamoDbKey {
+                attribute_name: "id".into(),
+                attribute_type: "N".into(),
+            },
+            sort_key_condition: None,
+        };
+        assert!(create_key_condition_expression(&invalid_condition).is_err());
+    }
+
+    #[test]
+    fn test_set_changed_and_remove_deleted_attributes() {
+        let key = json!({ "id": { "S": "1" } });
+        let original_item = json!({
+            "id": { "S": "1" },
+            "name": { "S": "a" },
+            "age": { "N": "1" },
+            "old": { "BOOL": true },
+        });
+        let item = json!({
+            "id": { "S": "1" },
+            "name": { "S": "a" },
+            "age": { "N": "2" },
+            "new": { "NULL": true },
+        });
+
+        let (expression, names, values) = create_update_expression(&key, &original_item, &item)
+            .unwrap()
+            .unwrap();
+        assert_eq!(expression, "SET #a0 = :v0, #a1 = :v1 REMOVE #a2");
+        assert_eq!(names["#a0"], "age");
+        assert_eq!(names["#a2"], "old");
+        assert_eq!(values[":v1"], AttributeValue::Null(true));
+
+        assert!(create_update_expression(&key, &item, &item)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::BTreeSet,
+    env,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use crate::state::dynamodb_state::DynamoDbExportFormat;
+
+use super::{
+    dynamodb_json::{format_cell_value, item_to_json, json_to_item},
+    log_event_export::escape_csv,
+};
+
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_millis(50);
+const MAX_REQUEST_INTERVAL: Duration = Duration::from_secs(10);
+
+static NEXT_SPILL_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Writes items as DynamoDB JSON, one item per line, or as CSV with a column per
+/// attribute. As every item may have attributes of its own, CSV items are kept in a
+/// temporary file and written on finish, when the columns of all items are known.
+pub struct ItemExport<W: Write> {
+    writer: W,
+    format: DynamoDbExportFormat,
+    key_names: Vec<String>,
+    attribute_names: BTreeSet<String>,
+    spill_file: Option<SpillFile>,
+}
+
+/// A temporary file of JSON Lines that is removed once dropped.
+struct SpillFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl SpillFile {
+    /// The name in the shared temp directory is predictable, so an existing file is never
+    /// opened but skipped for the next name.
+    fn create() -> Result<Self> {
+        loop {
+            let path = env::temp_dir().join(format!(
+                "aws-console-tui-export-{}-{}.jsonl",
+                process::id(),
+                NEXT_SPILL_FILE_ID.fetch_add(1, Ordering::Relaxed)
+            ));
+
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => {
+                    return Ok(SpillFile {
+                        path,
+                        writer: BufWriter::new(file),
+                    })
+                }
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+                Err(err) => {
+                    return Err(err).context(format!("Could not create file {:?}", path));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+impl ItemExport<BufWriter<File>> {
+    pub fn create(
+        path: &Path,
+        format: DynamoDbExportFormat,
+        key_names: Vec<String>,
+    ) -> Result<Self> {
+        let file =
+            File::create(path).context(format!("Could not create export file {:?}", path))?;
+        ItemExport::new(BufWriter::new(file), format, key_names)
+    }
+}
+
+impl<W: Write> ItemExport<W> {
+    pub fn new(writer: W, format: DynamoDbExportFormat, key_names: Vec<String>) -> Result<Self> {
+        let spill_file = match format {
+            DynamoDbExportFormat::JsonLines => None,
+            DynamoDbExportFormat::Csv => Some(SpillFile::create()?),
+        };
+
+        Ok(ItemExport {
+            writer,
+            format,
+            key_names,
+            attribute_names: BTreeSet::new(),
+            spill_file,
+        })
+    }
+
+    pub fn write_items(&mut self, items: &[Value]) -> Result<()> {
+        let writer: &mut dyn Write = match &mut self.spill_file {
+            Some(spill_file) => &mut spill_file.writer,
+            None => &mut self.writer,
+        };
+        for item in items {
+            writeln!(writer, "{}", item).context("Could not write items to the export file")?;
+        }
+
+        // the columns of the CSV are the key attributes followed by all others in
+        // alphabetical order, like the columns of the item table
+        if self.format == DynamoDbExportFormat::Csv {
+            self.attribute_names.extend(
+                items
+                    .iter()
+                    .filter_map(|item| item.as_object())
+                    .flat_map(|item| item.keys())
+                    .filter(|name| !self.key_names.contains(name))
+                    .cloned(),
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        if let Some(mut spill_file) = self.spill_file.take() {
+            spill_file
+                .writer
+                .flush()
+                .context("Could not write items to the export file")?;
+            let columns = self
+                .key_names
+                .iter()
+                .chain(self.attribute_names.iter())
+                .collect::<Vec<&String>>();
+
+            writeln!(
+                self.writer,
+                "{}",
+                columns
+                    .iter()
+                    .map(|column| escape_csv(column))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            )
+            .context("Could not write export header")?;
+
+            let file = File::open(&spill_file.path)
+                .context(format!("Could not open file {:?}", spill_file.path))?;
+            for line in BufReader::new(file).lines() {
+                let item = line
+                    .map_err(anyhow::Error::from)
+                    .and_then(|line| Ok(serde_json::from_str::<Value>(&line)?))
+                    .context("Could not read items of the export")?;
+                writeln!(
+                    self.writer,
+                    "{}",
+                    columns
+                        .iter()
+                        .map(|column| {
+                            item.get(column.as_str())
+                                .map(|value| escape_csv(&format_cell_value(value)))
+                                .unwrap_or_default()
+                        })
+                        .collect::<Vec<String>>()
+                        .join(",")
+                )
+                .context("Could not write items to the export file")?;
+            }
+        }
+
+        self.writer
+            .flush()
+            .context("Could not flush the export file")?;
+        Ok(self.writer)
+    }
+}
+
+pub fn read_items(path: &Path) -> Result<Vec<Value>> {
+    let file = File::open(path).context(format!("Could not open import file {:?}", path))?;
+    parse_items(BufReader::new(file))
+}
+
+/// Parses JSON Lines of DynamoDB JSON items, either bare like the lines of an export
+/// or wrapped in `{"Item": ...}` like the lines of a DynamoDB export to S3.
+pub fn parse_items(reader: impl BufRead) -> Result<Vec<Value>> {
+    let mut items = vec![];
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.context("Could not read the import file")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let item = serde_json::from_str::<Value>(&line)
+            .map_err(anyhow::Error::from)
+            .and_then(|value| {
+                let value = match value.get("Item") {
+                    Some(item) if value.as_object().map(|value| value.len()) == Some(1) => {
+                        item.clone()
+                    }
+                    _ => value,
+                };
+                json_to_item(&value)
+            })
+            .context(format!("Invalid item in line {}", index + 1))?;
+        items.push(item_to_json(&item));
+    }
+
+    Ok(items)
+}
+
+/// The pause between the requests of an export or import, which doubles while the
+/// table is throttled and shrinks again once requests succeed.
+#[derive(Debug, PartialEq)]
+pub struct RequestInterval {
+    interval: Duration,
+}
+
+impl Default for RequestInterval {
+    fn default() -> Self {
+        RequestInterval {
+            interval: MIN_REQUEST_INTERVAL,
+        }
+    }
+}
+
+impl RequestInterval {
+    pub fn on_success(&mut self) {
+        self.interval = (self.interval / 2).max(MIN_REQUEST_INTERVAL);
+    }
+
+    /// Backs off, false if the table is still throttled at the longest interval.
+    pub fn on_throttled(&mut self) -> bool {
+        if self.interval >= MAX_REQUEST_INTERVAL {
+            return false;
+        }
+        self.interval = (self.interval * 2).min(MAX_REQUEST_INTERVAL);
+        true
+    }
+
+    pub async fn wait(&self) {
+        tokio::time::sleep(self.interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn create_items() -> Vec<Value> {
+        vec![
+            json!({ "id": { "N": "1" }, "name": { "S": "a, b" } }),
+            json!({ "id": { "N": "2" }, "tags": { "SS": ["x"] } }),
+        ]
+    }
+
+    fn export(format: DynamoDbExportFormat) -> String {
+        let mut item_export = ItemExport::new(vec![], format, vec!["id".into()]).unwrap();
+        item_export.write_items(&create_items()).unwrap();
+        String::from_utf8(item_export.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_export_json_lines_and_import_them() {
+        let json_lines = export(DynamoDbExportFormat::JsonLines);
+        assert_eq!(
+            json_lines,
+            concat!(
+                "{\"id\":{\"N\":\"1\"},\"name\":{\"S\":\"a, b\"}}\n",
+                "{\"id\":{\"N\":\"2\"},\"tags\":{\"SS\":[\"x\"]}}\n"
+            )
+        );
+        assert_eq!(parse_items(json_lines.as_bytes()).unwrap(), create_items());
+    }
+
+    #[test]
+    fn test_export_csv_with_columns_of_all_items() {
+        assert_eq!(
+            export(DynamoDbExportFormat::Csv),
+            concat!("id,name,tags\n", "1,\"a, b\",\n", "2,,\"[\"\"x\"\"]\"\n")
+        );
+    }
+
+    #[test]
+    fn test_remove_temporary_file_of_csv_export() {
+        let item_export =
+            ItemExport::new(vec![], DynamoDbExportFormat::Csv, vec!["id".into()]).unwrap();
+        let path = item_export.spill_file.as_ref().unwrap().path.clone();
+        assert!(path.exists());
+
+        item_export.finish().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_skip_existing_temporary_file() {
+        let next_id = NEXT_SPILL_FILE_ID.load(Ordering::Relaxed);
+        let existing_path = env::temp_dir().join(format!(
+            "aws-console-tui-export-{}-{}.jsonl",
+            process::id(),
+            next_id
+        ));
+        fs::write(&existing_path, "foreign").unwrap();
+
+        let spill_file = SpillFile::create().unwrap();
+        assert_ne!(spill_file.path, existing_path);
+        assert_eq!(fs::read_to_string(&existing_path).unwrap(), "foreign");
+
+        let _ = fs::remove_file(existing_path);
+    }
+
+    #[test]
+    fn test_import_wrapped_items_and_report_invalid_lines() {
+        let json_lines = "{\"Item\":{\"id\":{\"S\":\"a\"}}}\n\n{\"id\":{\"X\":\"b\"}}\n";
+
+        let err = parse_items(json_lines.as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid item in line 3");
+        assert_eq!(
+            parse_items(json_lines.lines().next().unwrap().as_bytes()).unwrap(),
+            vec![json!({ "id": { "S": "a" } })]
+        );
+    }
+
+    #[test]
+    fn test_request_interval_backs_off_while_throttled() {
+        let mut request_interval = RequestInterval::default();
+        request_interval.on_success();
+        assert_eq!(request_interval.interval, MIN_REQUEST_INTERVAL);
+
+        assert!(request_interval.on_throttled());
+        assert_eq!(request_interval.interval, MIN_REQUEST_INTERVAL * 2);
+        while request_interval.on_throttled() {}
+        assert_eq!(request_interval.interval, MAX_REQUEST_INTERVAL);
+
+        request_interval.on_success();
+        assert_eq!(request_interval.interval, MAX_REQUEST_INTERVAL / 2);
+    }
+}
@@ -0,0 +1,394 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{anyhow, bail, Context};
+use aws_sdk_dynamodb::{primitives::Blob, types::AttributeValue};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{Map, Number, Value};
+
+/// An item in DynamoDB JSON, where every value is an object of its type and value,
+/// e.g. `{"id": {"S": "1"}}`. Attributes are sorted by name.
+pub fn item_to_json(item: &HashMap<String, AttributeValue>) -> Value {
+    let mut attributes = item.iter().collect::<Vec<(&String, &AttributeValue)>>();
+    attributes.sort_by_key(|(name, _)| *name);
+
+    Value::Object(
+        attributes
+            .into_iter()
+            .map(|(name, value)| (name.clone(), attribute_value_to_json(value)))
+            .collect::<Map<String, Value>>(),
+    )
+}
+
+pub fn attribute_value_to_json(value: &AttributeValue) -> Value {
+    let (attribute_type, value) = match value {
+        AttributeValue::S(text) => ("S", Value::String(text.clone())),
+        AttributeValue::N(number) => ("N", Value::String(number.clone())),
+        AttributeValue::B(blob) => ("B", Value::String(STANDARD.encode(blob.as_ref()))),
+        AttributeValue::Bool(flag) => ("BOOL", Value::Bool(*flag)),
+        AttributeValue::Null(flag) => ("NULL", Value::Bool(*flag)),
+        AttributeValue::Ss(texts) => ("SS", texts.iter().cloned().map(Value::String).collect()),
+        AttributeValue::Ns(numbers) => ("NS", numbers.iter().cloned().map(Value::String).collect()),
+        AttributeValue::Bs(blobs) => (
+            "BS",
+            blobs
+                .iter()
+                .map(|blob| Value::String(STANDARD.encode(blob.as_ref())))
+                .collect(),
+        ),
+        AttributeValue::L(values) => ("L", values.iter().map(attribute_value_to_json).collect()),
+        AttributeValue::M(values) => ("M", item_to_json(values)),
+        _ => ("NULL", Value::Bool(true)),
+    };
+
+    let mut object = Map::new();
+    object.insert(attribute_type.into(), value);
+    Value::Object(object)
+}
+
+pub fn json_to_item(value: &Value) -> anyhow::Result<HashMap<String, AttributeValue>> {
+    let Value::Object(attributes) = value else {
+        bail!("An item must be a JSON object");
+    };
+
+    attributes
+        .iter()
+        .map(|(name, value)| {
+            json_to_attribute_value(value)
+                .with_context(|| format!("Invalid value of attribute {}", name))
+                .map(|value| (name.clone(), value))
+        })
+        .collect()
+}
+
+pub fn json_to_attribute_value(value: &Value) -> anyhow::Result<AttributeValue> {
+    let (attribute_type, value) = match value {
+        Value::Object(object) if object.len() == 1 => object.iter().next().unwrap(),
+        _ => bail!("A value must be an object with its type as only key, e.g. {{\"S\": \"text\"}}"),
+    };
+
+    let attribute_value = match (attribute_type.as_str(), value) {
+        ("S", Value::String(text)) => AttributeValue::S(text.clone()),
+        ("N", Value::String(number)) => AttributeValue::N(parse_number(number)?),
+        ("N", Value::Number(number)) => AttributeValue::N(number.to_string()),
+        ("B", Value::String(text)) => AttributeValue::B(decode_blob(text)?),
+        ("BOOL", Value::Bool(flag)) => AttributeValue::Bool(*flag),
+        ("NULL", Value::Bool(flag)) => AttributeValue::Null(*flag),
+        ("SS", Value::Array(values)) => AttributeValue::Ss(
+            values
+                .iter()
+                .map(|value| expect_string(value).map(|text| text.to_string()))
+                .collect::<anyhow::Result<Vec<String>>>()?,
+        ),
+        ("NS", Value::Array(values)) => AttributeValue::Ns(
+            values
+                .iter()
+                .map(|value| match value {
+                    Value::Number(number) => Ok(number.to_string()),
+                    value => parse_number(expect_string(value)?),
+                })
+                .collect::<anyhow::Result<Vec<String>>>()?,
+        ),
+        ("BS", Value::Array(values)) => AttributeValue::Bs(
+            values
+                .iter()
+                .map(|value| decode_blob(expect_string(value)?))
+                .collect::<anyhow::Result<Vec<Blob>>>()?,
+        ),
+        ("L", Value::Array(values)) => AttributeValue::L(
+            values
+                .iter()
+                .map(json_to_attribute_value)
+                .collect::<anyhow::Result<Vec<AttributeValue>>>()?,
+        ),
+        ("M", value) => AttributeValue::M(json_to_item(value)?),
+        (attribute_type, _) => bail!("Invalid value of type {}", attribute_type),
+    };
+
+    Ok(attribute_value)
+}
+
+/// The item without attribute types as it is usually written by hand. Numbers keep
+/// their precision where JSON can hold them, sets become arrays and binaries base64.
+pub fn to_plain_json(value: &Value) -> Value {
+    match value {
+        Value::Object(object) if object.len() == 1 => {
+            let (attribute_type, value) = object.iter().next().unwrap();
+            match (attribute_type.as_str(), value) {
+                ("N", Value::String(number)) => plain_number(number),
+                ("NS", Value::Array(numbers)) => numbers
+                    .iter()
+                    .map(|number| plain_number(number.as_str().unwrap_or_default()))
+                    .collect(),
+                ("NULL", _) => Value::Null,
+                ("L", Value::Array(values)) => values.iter().map(to_plain_json).collect(),
+                ("M", Value::Object(_)) => item_to_plain_json(value),
+                (_, value) => value.clone(),
+            }
+        }
+        value => value.clone(),
+    }
+}
+
+pub fn item_to_plain_json(item: &Value) -> Value {
+    match item {
+        Value::Object(attributes) => Value::Object(
+            attributes
+                .iter()
+                .map(|(name, value)| (name.clone(), to_plain_json(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+        item => item.clone(),
+    }
+}
+
+/// The DynamoDB JSON of a hand written value. Strings, numbers, booleans, arrays and
+/// objects have no set or binary type of their own, these are taken from the type hint,
+/// the value before it was edited.
+pub fn plain_to_json(plain: &Value, type_hint: Option<&Value>) -> Value {
+    let hint = type_hint
+        .and_then(|hint| hint.as_object())
+        .filter(|hint| hint.len() == 1)
+        .and_then(|hint| hint.iter().next());
+    let all_strings = |values: &Vec<Value>| values.iter().all(|value| value.is_string());
+
+    let (attribute_type, value) = match (plain, hint) {
+        (Value::String(_), Some((hint_type, _))) if hint_type == "B" => ("B", plain.clone()),
+        (Value::Array(values), Some((hint_type, _)))
+            if hint_type == "SS" && all_strings(values) =>
+        {
+            ("SS", plain.clone())
+        }
+        (Value::Array(values), Some((hint_type, _)))
+            if hint_type == "BS" && all_strings(values) =>
+        {
+            ("BS", plain.clone())
+        }
+        (Value::Array(values), Some((hint_type, _)))
+            if hint_type == "NS" && values.iter().all(|value| value.is_number()) =>
+        {
+            (
+                "NS",
+                values
+                    .iter()
+                    .map(|value| Value::String(value.to_string()))
+                    .collect(),
+            )
+        }
+        (Value::String(_), _) => ("S", plain.clone()),
+        (Value::Number(number), _) => ("N", Value::String(number.to_string())),
+        (Value::Bool(_), _) => ("BOOL", plain.clone()),
+        (Value::Null, _) => ("NULL", Value::Bool(true)),
+        (Value::Array(values), hint) => {
+            let hints = match hint {
+                Some((hint_type, Value::Array(hints))) if hint_type == "L" => hints.as_slice(),
+                _ => &[],
+            };
+            (
+                "L",
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| plain_to_json(value, hints.get(index)))
+                    .collect(),
+            )
+        }
+        (Value::Object(_), hint) => {
+            let hints = match hint {
+                Some((hint_type, hints)) if hint_type == "M" => Some(hints),
+                _ => None,
+            };
+            ("M", plain_item_to_json(plain, hints))
+        }
+    };
+
+    let mut object = Map::new();
+    object.insert(attribute_type.into(), value);
+    Value::Object(object)
+}
+
+/// The DynamoDB JSON of a hand written item, typed like the original item where the
+/// plain JSON is ambiguous.
+pub fn plain_item_to_json(plain: &Value, original: Option<&Value>) -> Value {
+    match plain {
+        Value::Object(attributes) => Value::Object(
+            attributes
+                .iter()
+                .map(|(name, value)| {
+                    let type_hint = original.and_then(|original| original.get(name));
+                    (name.clone(), plain_to_json(value, type_hint))
+                })
+                .collect::<Map<String, Value>>(),
+        ),
+        plain => plain.clone(),
+    }
+}
+
+/// The key attributes of the item, which identify it for updates and deletes.
+pub fn item_key(item: &Value, key_names: &[String]) -> Value {
+    Value::Object(
+        key_names
+            .iter()
+            .filter_map(|name| item.get(name).map(|value| (name.clone(), value.clone())))
+            .collect::<Map<String, Value>>(),
+    )
+}
+
+/// The columns are the given key attributes followed by all other attributes of the
+/// items in alphabetical order, as every item may have attributes of its own.
+pub fn infer_columns(items: &[Value], key_attributes: &[&str]) -> Vec<String> {
+    let other_attributes = items
+        .iter()
+        .filter_map(|item| item.as_object())
+        .flat_map(|item| item.keys())
+        .filter(|name| !key_attributes.contains(&name.as_str()))
+        .collect::<BTreeSet<&String>>();
+
+    key_attributes
+        .iter()
+        .map(|name| name.to_string())
+        .chain(other_attributes.into_iter().cloned())
+        .collect()
+}
+
+/// A table cell shows strings and numbers as they are and everything else as JSON.
+pub fn format_cell_value(value: &Value) -> String {
+    match to_plain_json(value) {
+        Value::String(text) => text,
+        value => value.to_string(),
+    }
+}
+
+fn plain_number(number: &str) -> Value {
+    serde_json::from_str::<Number>(number)
+        .map(Value::Number)
+        .unwrap_or_else(|_| Value::String(number.into()))
+}
+
+fn parse_number(number: &str) -> anyhow::Result<String> {
+    number
+        .trim()
+        .parse::<f64>()
+        .map(|_| number.trim().to_string())
+        .map_err(|_| anyhow!("{} is no number", number))
+}
+
+fn expect_string(value: &Value) -> anyhow::Result<&str> {
+    value
+        .as_str()
+        .ok_or_else(|| anyhow!("{} is no string", value))
+}
+
+fn decode_blob(text: &str) -> anyhow::Result<Blob> {
+    STANDARD
+        .decode(text)
+        .map(Blob::new)
+        .with_context(|| format!("{} is no base64", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn create_item() -> HashMap<String, AttributeValue> {
+        HashMap::from([
+            ("id".to_string(), AttributeValue::S("1".into())),
+            ("count".into(), AttributeValue::N("12.50".into())),
+            ("data".into(), AttributeValue::B(Blob::new("abc"))),
+            (
+                "tags".into(),
+                AttributeValue::L(vec![
+                    AttributeValue::Bool(true),
+                    AttributeValue::Null(true),
+                    AttributeValue::Ss(vec!["a".into()]),
+                ]),
+            ),
+            (
+                "nested".into(),
+                AttributeValue::M(HashMap::from([(
+                    "numbers".to_string(),
+                    AttributeValue::Ns(vec!["1".into(), "2".into()]),
+                )])),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_round_trip_items_through_dynamodb_json() {
+        let item = create_item();
+        let value = item_to_json(&item);
+        assert_eq!(
+            value,
+            json!({
+                "count": { "N": "12.50" },
+                "data": { "B": "YWJj" },
+                "id": { "S": "1" },
+                "nested": { "M": { "numbers": { "NS": ["1", "2"] } } },
+                "tags": { "L": [{ "BOOL": true }, { "NULL": true }, { "SS": ["a"] }] },
+            })
+        );
+        assert_eq!(json_to_item(&value).unwrap(), item);
+    }
+
+    #[test]
+    fn test_convert_items_to_plain_json() {
+        assert_eq!(
+            item_to_plain_json(&item_to_json(&create_item())),
+            json!({
+                "count": 12.50,
+                "data": "YWJj",
+                "id": "1",
+                "nested": { "numbers": [1, 2] },
+                "tags": [true, null, ["a"]],
+            })
+        );
+    }
+
+    #[test]
+    fn test_type_plain_json_like_the_original_item() {
+        let original = json!({
+            "id": { "S": "1" },
+            "data": { "B": "YWJj" },
+            "tags": { "SS": ["a"] },
+            "scores": { "NS": ["1"] },
+            "nested": { "M": { "codes": { "SS": ["x"] } } },
+        });
+        let plain = json!({
+            "id": "1",
+            "data": "ZGVm",
+            "tags": ["a", "b"],
+            "scores": [1, 2.5],
+            "nested": { "codes": ["y"], "count": 3 },
+            "list": [null, true],
+        });
+
+        assert_eq!(
+            plain_item_to_json(&plain, Some(&original)),
+            json!({
+                "id": { "S": "1" },
+                "data": { "B": "ZGVm" },
+                "tags": { "SS": ["a", "b"] },
+                "scores": { "NS": ["1", "2.5"] },
+                "nested": { "M": { "codes": { "SS": ["y"] }, "count": { "N": "3" } } },
+                "list": { "L": [{ "NULL": true }, { "BOOL": true }] },
+            })
+        );
+        assert_eq!(
+            item_key(&original, &["id".into(), "sort".into()]),
+            json!({ "id": { "S": "1" } })
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid_dynamodb_json() {
+        assert!(json_to_item(&json!([])).is_err());
+        assert!(json_to_item(&json!({ "id": "1" })).is_err());
+        assert!(json_to_item(&json!({ "id": { "N": "one" } })).is_err());
+        assert!(json_to_item(&json!({ "id": { "X": "1" } })).is_err());
+        assert_eq!(
+            json_to_item(&json!({ "id": { "N": 1 } })).unwrap()["id"],
+            AttributeValue::N("1".into())
+        );
+    }
+}
@@ -1,7 +1,30 @@
+use std::cmp::Reverse;
+
+use anyhow::anyhow;
 use aws_config::SdkConfig;
-use aws_sdk_ec2::{config, Client};
+use aws_sdk_ec2::{
+    config::{self, Region},
+    types::{
+        InternetGateway, NatGateway, RouteTable, Subnet, Vpc, VpcEndpoint, VpcPeeringConnection,
+    },
+    Client,
+};
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::AWSConfig,
+    state::ec2_state::{
+        Ec2Image, Ec2Instance, Ec2InstanceCommand, Ec2NetworkAcl, Ec2NetworkInterface,
+        Ec2SecurityGroup, Ec2Snapshot, Ec2Volume, Ec2Vpc,
+    },
+};
 
-use crate::config::app_config::AWSConfig;
+use super::{
+    ec2_instance::create_instance,
+    ec2_network::{create_network_acl, create_network_interface, create_security_group},
+    ec2_storage::{create_image, create_snapshot, create_volume},
+    ec2_vpc::create_vpcs,
+};
 
 pub struct EC2Repository;
 
@@ -10,7 +33,7 @@ impl EC2Repository {
         aws_config: &AWSConfig,
         config: &SdkConfig,
     ) -> anyhow::Result<Vec<String>> {
-        let client = &EC2Repository::create_client(aws_config, config);
+        let client = &EC2Repository::create_client(aws_config, config, None);
         let regions_option = client.describe_regions().send().await?;
 
         let result: Vec<String> = regions_option
@@ -22,13 +45,622 @@ impl EC2Repository {
         Ok(result)
     }
 
-    fn create_client(aws_config: &AWSConfig, config: &SdkConfig) -> aws_sdk_ec2::Client {
+    pub async fn describe_instances(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2Instance>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut instances = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_instances()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    instances.extend(
+                        response
+                            .reservations()
+                            .iter()
+                            .flat_map(|reservation| reservation.instances())
+                            .map(create_instance),
+                    );
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        // newest instances first, they are the ones usually looked for
+        instances.sort_by_key(|instance| Reverse(instance.launch_time));
+        event!(Level::DEBUG, "{:?}", instances);
+
+        Ok(instances)
+    }
+
+    pub async fn change_instance_state(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        instance_id: &str,
+        command: Ec2InstanceCommand,
+    ) -> anyhow::Result<()> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let result = match command {
+            Ec2InstanceCommand::Start => client
+                .start_instances()
+                .instance_ids(instance_id)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+            Ec2InstanceCommand::Stop => client
+                .stop_instances()
+                .instance_ids(instance_id)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+            Ec2InstanceCommand::Reboot => client
+                .reboot_instances()
+                .instance_ids(instance_id)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+            Ec2InstanceCommand::Terminate => client
+                .terminate_instances()
+                .instance_ids(instance_id)
+                .send()
+                .await
+                .map(|_| ())
+                .map_err(|err| anyhow!(err)),
+        };
+
+        if let Err(err) = &result {
+            event!(Level::WARN, "Error EC2 Repository {:?}", err);
+        }
+
+        result
+    }
+
+    pub async fn describe_security_groups(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2SecurityGroup>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_security_groups()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(response.security_groups().iter().map(create_security_group));
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_network_interfaces(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2NetworkInterface>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_network_interfaces()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(
+                        response
+                            .network_interfaces()
+                            .iter()
+                            .map(create_network_interface),
+                    );
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_network_acls(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2NetworkAcl>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_network_acls()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(response.network_acls().iter().map(create_network_acl));
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_vpcs(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2Vpc>> {
+        let client = &EC2Repository::create_client(aws_config, config, region);
+        let (
+            vpcs,
+            subnets,
+            route_tables,
+            internet_gateways,
+            nat_gateways,
+            endpoints,
+            peering_connections,
+        ) = tokio::try_join!(
+            EC2Repository::list_vpcs(client),
+            EC2Repository::list_subnets(client),
+            EC2Repository::list_route_tables(client),
+            EC2Repository::list_internet_gateways(client),
+            EC2Repository::list_nat_gateways(client),
+            EC2Repository::list_vpc_endpoints(client),
+            EC2Repository::list_vpc_peering_connections(client),
+        )?;
+
+        let result = create_vpcs(
+            &vpcs,
+            &subnets,
+            &route_tables,
+            &internet_gateways,
+            &nat_gateways,
+            &endpoints,
+            &peering_connections,
+        );
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_volumes(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2Volume>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_volumes()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(response.volumes().iter().map(create_volume));
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        result.sort_by_key(|volume| Reverse(volume.create_time));
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_snapshots(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2Snapshot>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_snapshots()
+                .owner_ids("self")
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(response.snapshots().iter().map(create_snapshot));
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        result.sort_by_key(|snapshot| Reverse(snapshot.start_time));
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn describe_images(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<Ec2Image>> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_images()
+                .owners("self")
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend(response.images().iter().map(create_image));
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        result.sort_by(|a, b| b.creation_date.cmp(&a.creation_date));
+        event!(Level::DEBUG, "{:?}", result);
+
+        Ok(result)
+    }
+
+    pub async fn create_snapshot(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        volume_id: &str,
+    ) -> anyhow::Result<String> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+
+        match client.create_snapshot().volume_id(volume_id).send().await {
+            Ok(response) => Ok(response.snapshot_id().unwrap_or_default().to_string()),
+            Err(err) => {
+                event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn deregister_image(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        image_id: &str,
+    ) -> anyhow::Result<()> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+
+        match client.deregister_image().image_id(image_id).send().await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn delete_snapshot(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        snapshot_id: &str,
+    ) -> anyhow::Result<()> {
+        let client = EC2Repository::create_client(aws_config, config, region);
+
+        match client
+            .delete_snapshot()
+            .snapshot_id(snapshot_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    async fn list_vpcs(client: &Client) -> anyhow::Result<Vec<Vpc>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_vpcs()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.vpcs());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_subnets(client: &Client) -> anyhow::Result<Vec<Subnet>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_subnets()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.subnets());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_route_tables(client: &Client) -> anyhow::Result<Vec<RouteTable>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_route_tables()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.route_tables());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_internet_gateways(client: &Client) -> anyhow::Result<Vec<InternetGateway>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_internet_gateways()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.internet_gateways());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_nat_gateways(client: &Client) -> anyhow::Result<Vec<NatGateway>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_nat_gateways()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.nat_gateways());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_vpc_endpoints(client: &Client) -> anyhow::Result<Vec<VpcEndpoint>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_vpc_endpoints()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.vpc_endpoints());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn list_vpc_peering_connections(
+        client: &Client,
+    ) -> anyhow::Result<Vec<VpcPeeringConnection>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .describe_vpc_peering_connections()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    result.extend_from_slice(response.vpc_peering_connections());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EC2 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn create_client(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> aws_sdk_ec2::Client {
         let mut client_builder = config::Builder::from(config);
 
         if !aws_config.endpoint.is_empty() {
             client_builder = client_builder.endpoint_url(&aws_config.endpoint);
         }
 
+        if let Some(region) = region {
+            client_builder = client_builder.region(Region::new(region.to_string()));
+        }
+
         Client::from_conf(client_builder.build())
     }
 }
@@ -0,0 +1,212 @@
+use aws_sdk_ec2::types::{
+    GroupIdentifier, Instance, InstanceBlockDeviceMapping, InstanceNetworkInterface, Tag,
+};
+use serde_json::{json, Map, Value};
+
+use crate::state::ec2_state::Ec2Instance;
+
+use super::s3_bucket_config::remove_empty_fields;
+
+pub fn create_instance(instance: &Instance) -> Ec2Instance {
+    Ec2Instance {
+        instance_id: instance.instance_id().unwrap_or_default().into(),
+        name: get_tag_value(instance.tags(), "Name"),
+        state: instance
+            .state()
+            .and_then(|state| state.name())
+            .map(|name| name.as_str().into()),
+        instance_type: instance
+            .instance_type()
+            .map(|instance_type| instance_type.as_str().into()),
+        private_ip_address: instance.private_ip_address().map(|ip| ip.into()),
+        public_ip_address: instance.public_ip_address().map(|ip| ip.into()),
+        availability_zone: instance
+            .placement()
+            .and_then(|placement| placement.availability_zone())
+            .map(|zone| zone.into()),
+        launch_time: instance.launch_time().map(|date_time| date_time.secs()),
+        details: remove_empty_fields(instance_json(instance)),
+    }
+}
+
+pub fn get_tag_value(tags: &[Tag], key: &str) -> Option<String> {
+    tags.iter()
+        .find(|tag| tag.key() == Some(key))
+        .and_then(|tag| tag.value())
+        .map(|value| value.into())
+}
+
+/// The fields of an instance with the names of the DescribeInstances response.
+pub fn instance_json(instance: &Instance) -> Value {
+    json!({
+        "InstanceId": instance.instance_id(),
+        "ImageId": instance.image_id(),
+        "State": instance.state().map(|state| json!({
+            "Code": state.code(),
+            "Name": state.name().map(|name| name.as_str()),
+        })),
+        "StateTransitionReason": instance.state_transition_reason(),
+        "StateReason": instance.state_reason().map(|reason| json!({
+            "Code": reason.code(),
+            "Message": reason.message(),
+        })),
+        "InstanceType": instance.instance_type().map(|instance_type| instance_type.as_str()),
+        "Architecture": instance.architecture().map(|architecture| architecture.as_str()),
+        "Platform": instance.platform().map(|platform| platform.as_str()),
+        "PlatformDetails": instance.platform_details(),
+        "KeyName": instance.key_name(),
+        "LaunchTime": instance.launch_time().map(|date_time| date_time.to_string()),
+        "Placement": instance.placement().map(|placement| json!({
+            "AvailabilityZone": placement.availability_zone(),
+            "GroupName": placement.group_name(),
+            "Tenancy": placement.tenancy().map(|tenancy| tenancy.as_str()),
+        })),
+        "VpcId": instance.vpc_id(),
+        "SubnetId": instance.subnet_id(),
+        "PrivateDnsName": instance.private_dns_name(),
+        "PrivateIpAddress": instance.private_ip_address(),
+        "PublicDnsName": instance.public_dns_name(),
+        "PublicIpAddress": instance.public_ip_address(),
+        "SecurityGroups": instance.security_groups().iter().map(group_json).collect::<Vec<Value>>(),
+        "NetworkInterfaces": instance
+            .network_interfaces()
+            .iter()
+            .map(network_interface_json)
+            .collect::<Vec<Value>>(),
+        "IamInstanceProfile": instance.iam_instance_profile().map(|profile| json!({
+            "Arn": profile.arn(),
+            "Id": profile.id(),
+        })),
+        "RootDeviceType": instance.root_device_type().map(|device_type| device_type.as_str()),
+        "RootDeviceName": instance.root_device_name(),
+        "BlockDeviceMappings": instance
+            .block_device_mappings()
+            .iter()
+            .map(block_device_json)
+            .collect::<Vec<Value>>(),
+        "EbsOptimized": instance.ebs_optimized(),
+        "EnaSupport": instance.ena_support(),
+        "Hypervisor": instance.hypervisor().map(|hypervisor| hypervisor.as_str()),
+        "VirtualizationType": instance
+            .virtualization_type()
+            .map(|virtualization_type| virtualization_type.as_str()),
+        "CpuOptions": instance.cpu_options().map(|cpu_options| json!({
+            "CoreCount": cpu_options.core_count(),
+            "ThreadsPerCore": cpu_options.threads_per_core(),
+        })),
+        "Monitoring": instance
+            .monitoring()
+            .and_then(|monitoring| monitoring.state())
+            .map(|state| json!({ "State": state.as_str() })),
+        "MetadataOptions": instance.metadata_options().map(|options| json!({
+            "State": options.state().map(|state| state.as_str()),
+            "HttpTokens": options.http_tokens().map(|tokens| tokens.as_str()),
+            "HttpEndpoint": options.http_endpoint().map(|endpoint| endpoint.as_str()),
+            "HttpPutResponseHopLimit": options.http_put_response_hop_limit(),
+            "InstanceMetadataTags": options.instance_metadata_tags().map(|tags| tags.as_str()),
+        })),
+        "InstanceLifecycle": instance.instance_lifecycle().map(|lifecycle| lifecycle.as_str()),
+        "SpotInstanceRequestId": instance.spot_instance_request_id(),
+        "SourceDestCheck": instance.source_dest_check(),
+        "Tags": tags_json(instance.tags()),
+    })
+}
+
+pub fn tags_json(tags: &[Tag]) -> Value {
+    Value::Object(
+        tags.iter()
+            .map(|tag| {
+                (
+                    tag.key().unwrap_or_default().to_string(),
+                    Value::String(tag.value().unwrap_or_default().into()),
+                )
+            })
+            .collect::<Map<String, Value>>(),
+    )
+}
+
+fn group_json(group: &GroupIdentifier) -> Value {
+    json!({
+        "GroupId": group.group_id(),
+        "GroupName": group.group_name(),
+    })
+}
+
+fn network_interface_json(network_interface: &InstanceNetworkInterface) -> Value {
+    json!({
+        "NetworkInterfaceId": network_interface.network_interface_id(),
+        "Description": network_interface.description(),
+        "Status": network_interface.status().map(|status| status.as_str()),
+        "SubnetId": network_interface.subnet_id(),
+        "VpcId": network_interface.vpc_id(),
+        "MacAddress": network_interface.mac_address(),
+        "PrivateIpAddress": network_interface.private_ip_address(),
+        "PrivateDnsName": network_interface.private_dns_name(),
+        "Association": network_interface.association().map(|association| json!({
+            "PublicIp": association.public_ip(),
+            "PublicDnsName": association.public_dns_name(),
+            "IpOwnerId": association.ip_owner_id(),
+        })),
+        "Groups": network_interface.groups().iter().map(group_json).collect::<Vec<Value>>(),
+    })
+}
+
+fn block_device_json(mapping: &InstanceBlockDeviceMapping) -> Value {
+    json!({
+        "DeviceName": mapping.device_name(),
+        "Ebs": mapping.ebs().map(|ebs| json!({
+            "VolumeId": ebs.volume_id(),
+            "Status": ebs.status().map(|status| status.as_str()),
+            "AttachTime": ebs.attach_time().map(|date_time| date_time.to_string()),
+            "DeleteOnTermination": ebs.delete_on_termination(),
+        })),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_ec2::{
+        primitives::DateTime,
+        types::{InstanceState, InstanceStateName, InstanceType, Placement},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_create_instance_with_name_tag_and_details() {
+        let instance = Instance::builder()
+            .instance_id("i-0abc")
+            .instance_type(InstanceType::T3Micro)
+            .state(
+                InstanceState::builder()
+                    .code(16)
+                    .name(InstanceStateName::Running)
+                    .build(),
+            )
+            .placement(Placement::builder().availability_zone("eu-west-1a").build())
+            .private_ip_address("10.0.0.12")
+            .launch_time(DateTime::from_secs(1_700_000_000))
+            .tags(Tag::builder().key("Name").value("web").build())
+            .build();
+
+        let ec2_instance = create_instance(&instance);
+        assert_eq!(ec2_instance.name, Some("web".into()));
+        assert_eq!(ec2_instance.state, Some("running".into()));
+        assert_eq!(ec2_instance.instance_type, Some("t3.micro".into()));
+        assert_eq!(ec2_instance.availability_zone, Some("eu-west-1a".into()));
+        assert_eq!(ec2_instance.public_ip_address, None);
+        assert_eq!(ec2_instance.launch_time, Some(1_700_000_000));
+        assert_eq!(
+            ec2_instance.details,
+            json!({
+                "InstanceId": "i-0abc",
+                "State": { "Code": 16, "Name": "running" },
+                "InstanceType": "t3.micro",
+                "LaunchTime": "2023-11-14T22:13:20Z",
+                "Placement": { "AvailabilityZone": "eu-west-1a" },
+                "PrivateIpAddress": "10.0.0.12",
+                "Tags": { "Name": "web" },
+            })
+        );
+    }
+}
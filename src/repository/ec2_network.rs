@@ -0,0 +1,279 @@
+use aws_sdk_ec2::types::{IpPermission, NetworkAcl, NetworkInterface, SecurityGroup};
+
+use crate::state::ec2_state::{
+    Ec2NetworkAcl, Ec2NetworkAclEntry, Ec2NetworkInterface, Ec2SecurityGroup, Ec2SecurityGroupRule,
+};
+
+/// Ports of remote shells that should never be reachable from anywhere.
+const RISKY_PORTS: [i32; 2] = [22, 3389];
+
+pub fn create_security_group(security_group: &SecurityGroup) -> Ec2SecurityGroup {
+    Ec2SecurityGroup {
+        group_id: security_group.group_id().unwrap_or_default().into(),
+        group_name: security_group.group_name().unwrap_or_default().into(),
+        description: security_group.description().map(|text| text.into()),
+        vpc_id: security_group.vpc_id().map(|vpc_id| vpc_id.into()),
+        inbound_rules: security_group
+            .ip_permissions()
+            .iter()
+            .flat_map(create_security_group_rules)
+            .collect(),
+        outbound_rules: security_group
+            .ip_permissions_egress()
+            .iter()
+            .flat_map(create_security_group_rules)
+            .collect(),
+    }
+}
+
+/// A permission lists many peers, each of them becomes a rule of its own.
+fn create_security_group_rules(permission: &IpPermission) -> Vec<Ec2SecurityGroupRule> {
+    let protocol = protocol_name(permission.ip_protocol().unwrap_or("-1"));
+    let peers = permission
+        .ip_ranges()
+        .iter()
+        .map(|range| (range.cidr_ip(), range.description()))
+        .chain(
+            permission
+                .ipv6_ranges()
+                .iter()
+                .map(|range| (range.cidr_ipv6(), range.description())),
+        )
+        .chain(
+            permission
+                .prefix_list_ids()
+                .iter()
+                .map(|prefix_list| (prefix_list.prefix_list_id(), prefix_list.description())),
+        )
+        .chain(
+            permission
+                .user_id_group_pairs()
+                .iter()
+                .map(|pair| (pair.group_id(), pair.description())),
+        );
+
+    peers
+        .map(|(peer, description)| Ec2SecurityGroupRule {
+            protocol: protocol.clone(),
+            from_port: permission.from_port(),
+            to_port: permission.to_port(),
+            peer: peer.unwrap_or_default().into(),
+            description: description.map(|text| text.into()),
+        })
+        .collect()
+}
+
+pub fn create_network_interface(network_interface: &NetworkInterface) -> Ec2NetworkInterface {
+    Ec2NetworkInterface {
+        network_interface_id: network_interface
+            .network_interface_id()
+            .unwrap_or_default()
+            .into(),
+        description: network_interface
+            .description()
+            .filter(|text| !text.is_empty())
+            .map(|text| text.into()),
+        interface_type: network_interface
+            .interface_type()
+            .map(|interface_type| interface_type.as_str().into()),
+        instance_id: network_interface
+            .attachment()
+            .and_then(|attachment| attachment.instance_id())
+            .map(|instance_id| instance_id.into()),
+        private_ip_address: network_interface.private_ip_address().map(|ip| ip.into()),
+        subnet_id: network_interface
+            .subnet_id()
+            .map(|subnet_id| subnet_id.into()),
+        group_ids: network_interface
+            .groups()
+            .iter()
+            .filter_map(|group| group.group_id())
+            .map(|group_id| group_id.into())
+            .collect(),
+    }
+}
+
+pub fn create_network_acl(network_acl: &NetworkAcl) -> Ec2NetworkAcl {
+    let mut entries = network_acl
+        .entries()
+        .iter()
+        .map(|entry| Ec2NetworkAclEntry {
+            rule_number: entry.rule_number().unwrap_or_default(),
+            is_egress: entry.egress().unwrap_or_default(),
+            protocol: protocol_name(entry.protocol().unwrap_or("-1")),
+            is_allow: entry
+                .rule_action()
+                .is_some_and(|action| action.as_str() == "allow"),
+            cidr_block: entry
+                .cidr_block()
+                .or(entry.ipv6_cidr_block())
+                .unwrap_or_default()
+                .into(),
+            from_port: entry.port_range().and_then(|port_range| port_range.from()),
+            to_port: entry.port_range().and_then(|port_range| port_range.to()),
+        })
+        .collect::<Vec<Ec2NetworkAclEntry>>();
+    // entries are evaluated in order of their rule number
+    entries.sort_by_key(|entry| (entry.is_egress, entry.rule_number));
+
+    Ec2NetworkAcl {
+        network_acl_id: network_acl.network_acl_id().unwrap_or_default().into(),
+        vpc_id: network_acl.vpc_id().map(|vpc_id| vpc_id.into()),
+        is_default: network_acl.is_default().unwrap_or_default(),
+        subnet_ids: network_acl
+            .associations()
+            .iter()
+            .filter_map(|association| association.subnet_id())
+            .map(|subnet_id| subnet_id.into())
+            .collect(),
+        entries,
+    }
+}
+
+/// Security groups name common protocols, network ACLs always use their numbers.
+pub fn protocol_name(protocol: &str) -> String {
+    match protocol {
+        "-1" => "all",
+        "1" => "icmp",
+        "6" => "tcp",
+        "17" => "udp",
+        "58" => "icmpv6",
+        protocol => protocol,
+    }
+    .into()
+}
+
+/// Port ranges are unset or -1 for protocols without ports and for all traffic.
+pub fn format_port_range(from_port: Option<i32>, to_port: Option<i32>) -> String {
+    match (from_port, to_port) {
+        (Some(from_port), Some(to_port)) if from_port == to_port && from_port >= 0 => {
+            from_port.to_string()
+        }
+        (Some(0), Some(65535)) | (None, None) | (Some(-1), _) => "all".into(),
+        (Some(from_port), Some(to_port)) => format!("{}-{}", from_port, to_port),
+        (Some(port), None) | (None, Some(port)) => port.to_string(),
+    }
+}
+
+/// Inbound traffic from anywhere to SSH or RDP, also through all traffic rules.
+pub fn is_risky_rule(rule: &Ec2SecurityGroupRule) -> bool {
+    is_open_to_world(&rule.peer) && has_risky_port(&rule.protocol, rule.from_port, rule.to_port)
+}
+
+pub fn is_risky_acl_entry(entry: &Ec2NetworkAclEntry) -> bool {
+    !entry.is_egress
+        && entry.is_allow
+        && is_open_to_world(&entry.cidr_block)
+        && has_risky_port(&entry.protocol, entry.from_port, entry.to_port)
+}
+
+fn is_open_to_world(peer: &str) -> bool {
+    peer == "0.0.0.0/0" || peer == "::/0"
+}
+
+fn has_risky_port(protocol: &str, from_port: Option<i32>, to_port: Option<i32>) -> bool {
+    match protocol {
+        "all" => true,
+        "tcp" => {
+            let from_port = from_port.filter(|port| *port >= 0).unwrap_or(0);
+            let to_port = to_port.filter(|port| *port >= 0).unwrap_or(65535);
+            RISKY_PORTS
+                .iter()
+                .any(|port| (from_port..=to_port).contains(port))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_ec2::types::{IpRange, UserIdGroupPair};
+
+    use super::*;
+
+    fn create_rule(protocol: &str, ports: (i32, i32), peer: &str) -> Ec2SecurityGroupRule {
+        Ec2SecurityGroupRule {
+            protocol: protocol.into(),
+            from_port: Some(ports.0),
+            to_port: Some(ports.1),
+            peer: peer.into(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_create_rule_for_each_peer() {
+        let security_group = SecurityGroup::builder()
+            .group_id("sg-1")
+            .group_name("web")
+            .ip_permissions(
+                IpPermission::builder()
+                    .ip_protocol("tcp")
+                    .from_port(443)
+                    .to_port(443)
+                    .ip_ranges(IpRange::builder().cidr_ip("0.0.0.0/0").build())
+                    .user_id_group_pairs(
+                        UserIdGroupPair::builder()
+                            .group_id("sg-2")
+                            .description("load balancer")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        let security_group = create_security_group(&security_group);
+        assert_eq!(
+            security_group.inbound_rules,
+            vec![
+                create_rule("tcp", (443, 443), "0.0.0.0/0"),
+                Ec2SecurityGroupRule {
+                    description: Some("load balancer".into()),
+                    ..create_rule("tcp", (443, 443), "sg-2")
+                },
+            ]
+        );
+        assert!(security_group.outbound_rules.is_empty());
+    }
+
+    #[test]
+    fn test_detect_shell_ports_open_to_world() {
+        assert!(is_risky_rule(&create_rule("tcp", (22, 22), "0.0.0.0/0")));
+        assert!(is_risky_rule(&create_rule("tcp", (3000, 4000), "::/0")));
+        assert!(is_risky_rule(&create_rule("all", (-1, -1), "0.0.0.0/0")));
+        assert!(!is_risky_rule(&create_rule("tcp", (22, 22), "10.0.0.0/8")));
+        assert!(!is_risky_rule(&create_rule("tcp", (443, 443), "0.0.0.0/0")));
+        assert!(!is_risky_rule(&create_rule("udp", (22, 22), "0.0.0.0/0")));
+
+        let entry = Ec2NetworkAclEntry {
+            rule_number: 100,
+            protocol: "tcp".into(),
+            is_allow: true,
+            cidr_block: "0.0.0.0/0".into(),
+            from_port: Some(22),
+            to_port: Some(22),
+            ..Ec2NetworkAclEntry::default()
+        };
+        assert!(is_risky_acl_entry(&entry));
+        assert!(!is_risky_acl_entry(&Ec2NetworkAclEntry {
+            is_allow: false,
+            ..entry.clone()
+        }));
+        assert!(!is_risky_acl_entry(&Ec2NetworkAclEntry {
+            is_egress: true,
+            ..entry
+        }));
+    }
+
+    #[test]
+    fn test_format_port_ranges_and_protocols() {
+        assert_eq!(format_port_range(Some(22), Some(22)), "22");
+        assert_eq!(format_port_range(Some(1024), Some(2048)), "1024-2048");
+        assert_eq!(format_port_range(Some(0), Some(65535)), "all");
+        assert_eq!(format_port_range(Some(-1), Some(-1)), "all");
+        assert_eq!(format_port_range(None, None), "all");
+        assert_eq!(protocol_name("6"), "tcp");
+        assert_eq!(protocol_name("-1"), "all");
+        assert_eq!(protocol_name("tcp"), "tcp");
+    }
+}
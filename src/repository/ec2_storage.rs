@@ -0,0 +1,161 @@
+use aws_sdk_ec2::types::{Image, Snapshot, Volume};
+
+use crate::state::ec2_state::{Ec2Image, Ec2Snapshot, Ec2Volume};
+
+use super::ec2_instance::get_tag_value;
+
+pub fn create_volume(volume: &Volume) -> Ec2Volume {
+    let attachment = volume.attachments().first();
+
+    Ec2Volume {
+        volume_id: volume.volume_id().unwrap_or_default().into(),
+        name: get_tag_value(volume.tags(), "Name"),
+        size: volume.size(),
+        volume_type: volume
+            .volume_type()
+            .map(|volume_type| volume_type.as_str().into()),
+        state: volume.state().map(|state| state.as_str().into()),
+        availability_zone: volume.availability_zone().map(|zone| zone.into()),
+        instance_id: attachment
+            .and_then(|attachment| attachment.instance_id())
+            .map(|instance_id| instance_id.into()),
+        device: attachment
+            .and_then(|attachment| attachment.device())
+            .map(|device| device.into()),
+        create_time: volume.create_time().map(|date_time| date_time.secs()),
+    }
+}
+
+pub fn create_snapshot(snapshot: &Snapshot) -> Ec2Snapshot {
+    Ec2Snapshot {
+        snapshot_id: snapshot.snapshot_id().unwrap_or_default().into(),
+        volume_id: snapshot.volume_id().map(|volume_id| volume_id.into()),
+        volume_size: snapshot.volume_size(),
+        state: snapshot.state().map(|state| state.as_str().into()),
+        description: snapshot
+            .description()
+            .filter(|description| !description.is_empty())
+            .map(|description| description.into()),
+        start_time: snapshot.start_time().map(|date_time| date_time.secs()),
+    }
+}
+
+pub fn create_image(image: &Image) -> Ec2Image {
+    Ec2Image {
+        image_id: image.image_id().unwrap_or_default().into(),
+        name: image.name().map(|name| name.into()),
+        state: image.state().map(|state| state.as_str().into()),
+        creation_date: image.creation_date().map(|date| date.into()),
+        snapshot_ids: image
+            .block_device_mappings()
+            .iter()
+            .filter_map(|mapping| mapping.ebs())
+            .filter_map(|ebs| ebs.snapshot_id())
+            .map(|snapshot_id| snapshot_id.into())
+            .collect(),
+    }
+}
+
+/// Available volumes are attached to no instance and only cost money.
+pub fn is_unattached_volume(volume: &Ec2Volume) -> bool {
+    volume.state.as_deref() == Some("available")
+}
+
+/// A snapshot is orphaned when its volume is gone and no AMI is based on it.
+pub fn is_orphaned_snapshot(
+    snapshot: &Ec2Snapshot,
+    volumes: &[Ec2Volume],
+    images: &[Ec2Image],
+) -> bool {
+    let has_volume = snapshot
+        .volume_id
+        .as_ref()
+        .is_some_and(|volume_id| volumes.iter().any(|volume| &volume.volume_id == volume_id));
+    let has_image = images
+        .iter()
+        .any(|image| image.snapshot_ids.contains(&snapshot.snapshot_id));
+
+    !has_volume && !has_image
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_ec2::types::{BlockDeviceMapping, EbsBlockDevice, VolumeAttachment, VolumeState};
+
+    use super::*;
+
+    #[test]
+    fn test_create_volume_with_attachment() {
+        let volume = Volume::builder()
+            .volume_id("vol-1")
+            .size(8)
+            .state(VolumeState::InUse)
+            .attachments(
+                VolumeAttachment::builder()
+                    .instance_id("i-1")
+                    .device("/dev/xvda")
+                    .build(),
+            )
+            .build();
+        let volume = create_volume(&volume);
+        assert_eq!(volume.instance_id, Some("i-1".into()));
+        assert_eq!(volume.device, Some("/dev/xvda".into()));
+        assert_eq!(volume.state, Some("in-use".into()));
+        assert!(!is_unattached_volume(&volume));
+    }
+
+    #[test]
+    fn test_create_image_with_snapshots() {
+        let image = Image::builder()
+            .image_id("ami-1")
+            .block_device_mappings(
+                BlockDeviceMapping::builder()
+                    .ebs(EbsBlockDevice::builder().snapshot_id("snap-1").build())
+                    .build(),
+            )
+            .block_device_mappings(
+                BlockDeviceMapping::builder()
+                    .virtual_name("ephemeral0")
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            create_image(&image).snapshot_ids,
+            vec!["snap-1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_detect_orphaned_snapshots() {
+        let volumes = vec![Ec2Volume {
+            volume_id: "vol-1".into(),
+            ..Ec2Volume::default()
+        }];
+        let images = vec![Ec2Image {
+            image_id: "ami-1".into(),
+            snapshot_ids: vec!["snap-ami".into()],
+            ..Ec2Image::default()
+        }];
+        let snapshot = |snapshot_id: &str, volume_id: &str| Ec2Snapshot {
+            snapshot_id: snapshot_id.into(),
+            volume_id: Some(volume_id.into()),
+            ..Ec2Snapshot::default()
+        };
+
+        assert!(!is_orphaned_snapshot(
+            &snapshot("snap-1", "vol-1"),
+            &volumes,
+            &images
+        ));
+        assert!(!is_orphaned_snapshot(
+            &snapshot("snap-ami", "vol-gone"),
+            &volumes,
+            &images
+        ));
+        assert!(is_orphaned_snapshot(
+            &snapshot("snap-2", "vol-gone"),
+            &volumes,
+            &images
+        ));
+    }
+}
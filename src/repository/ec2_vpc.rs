@@ -0,0 +1,325 @@
+use aws_sdk_ec2::types::{
+    InternetGateway, NatGateway, Route, RouteTable, Subnet, Vpc, VpcEndpoint, VpcPeeringConnection,
+    VpcPeeringConnectionVpcInfo,
+};
+
+use crate::state::ec2_state::{
+    Ec2InternetGateway, Ec2NatGateway, Ec2Route, Ec2RouteTable, Ec2Subnet, Ec2Vpc, Ec2VpcEndpoint,
+    Ec2VpcPeeringConnection,
+};
+
+use super::ec2_instance::get_tag_value;
+
+/// The resources of all VPCs are described at once and put under the VPC they belong to.
+pub fn create_vpcs(
+    vpcs: &[Vpc],
+    subnets: &[Subnet],
+    route_tables: &[RouteTable],
+    internet_gateways: &[InternetGateway],
+    nat_gateways: &[NatGateway],
+    endpoints: &[VpcEndpoint],
+    peering_connections: &[VpcPeeringConnection],
+) -> Vec<Ec2Vpc> {
+    vpcs.iter()
+        .map(|vpc| {
+            let vpc_id = vpc.vpc_id().unwrap_or_default();
+            let route_tables = route_tables
+                .iter()
+                .filter(|route_table| route_table.vpc_id() == Some(vpc_id))
+                .map(create_route_table)
+                .collect::<Vec<Ec2RouteTable>>();
+
+            Ec2Vpc {
+                vpc_id: vpc_id.into(),
+                name: get_tag_value(vpc.tags(), "Name"),
+                state: vpc.state().map(|state| state.as_str().into()),
+                is_default: vpc.is_default().unwrap_or_default(),
+                cidr_blocks: vpc
+                    .cidr_block_association_set()
+                    .iter()
+                    .filter_map(|association| association.cidr_block())
+                    .chain(
+                        vpc.ipv6_cidr_block_association_set()
+                            .iter()
+                            .filter_map(|association| association.ipv6_cidr_block()),
+                    )
+                    .map(|cidr_block| cidr_block.into())
+                    .collect(),
+                subnets: subnets
+                    .iter()
+                    .filter(|subnet| subnet.vpc_id() == Some(vpc_id))
+                    .map(|subnet| create_subnet(subnet, &route_tables))
+                    .collect(),
+                internet_gateways: internet_gateways
+                    .iter()
+                    .filter_map(|internet_gateway| {
+                        internet_gateway
+                            .attachments()
+                            .iter()
+                            .find(|attachment| attachment.vpc_id() == Some(vpc_id))
+                            .map(|attachment| Ec2InternetGateway {
+                                internet_gateway_id: internet_gateway
+                                    .internet_gateway_id()
+                                    .unwrap_or_default()
+                                    .into(),
+                                name: get_tag_value(internet_gateway.tags(), "Name"),
+                                state: attachment.state().map(|state| state.as_str().into()),
+                            })
+                    })
+                    .collect(),
+                nat_gateways: nat_gateways
+                    .iter()
+                    .filter(|nat_gateway| nat_gateway.vpc_id() == Some(vpc_id))
+                    .map(create_nat_gateway)
+                    .collect(),
+                endpoints: endpoints
+                    .iter()
+                    .filter(|endpoint| endpoint.vpc_id() == Some(vpc_id))
+                    .map(|endpoint| Ec2VpcEndpoint {
+                        vpc_endpoint_id: endpoint.vpc_endpoint_id().unwrap_or_default().into(),
+                        service_name: endpoint.service_name().map(|name| name.into()),
+                        endpoint_type: endpoint
+                            .vpc_endpoint_type()
+                            .map(|endpoint_type| endpoint_type.as_str().into()),
+                        state: endpoint.state().map(|state| state.as_str().into()),
+                    })
+                    .collect(),
+                // a peering connection belongs to both VPCs it connects
+                peering_connections: peering_connections
+                    .iter()
+                    .filter(|peering_connection| {
+                        [
+                            peering_connection.requester_vpc_info(),
+                            peering_connection.accepter_vpc_info(),
+                        ]
+                        .iter()
+                        .flatten()
+                        .any(|vpc_info| vpc_info.vpc_id() == Some(vpc_id))
+                    })
+                    .map(create_peering_connection)
+                    .collect(),
+                route_tables,
+            }
+        })
+        .collect()
+}
+
+fn create_subnet(subnet: &Subnet, route_tables: &[Ec2RouteTable]) -> Ec2Subnet {
+    let subnet_id = subnet.subnet_id().unwrap_or_default();
+    // subnets without an explicit association use the main route table
+    let route_table = route_tables
+        .iter()
+        .find(|route_table| route_table.subnet_ids.iter().any(|id| id == subnet_id))
+        .or_else(|| route_tables.iter().find(|route_table| route_table.is_main));
+
+    Ec2Subnet {
+        subnet_id: subnet_id.into(),
+        name: get_tag_value(subnet.tags(), "Name"),
+        cidr_block: subnet.cidr_block().map(|cidr_block| cidr_block.into()),
+        availability_zone: subnet.availability_zone().map(|zone| zone.into()),
+        available_ip_address_count: subnet.available_ip_address_count(),
+        route_table_id: route_table.map(|route_table| route_table.route_table_id.clone()),
+        is_public: route_table.is_some_and(|route_table| {
+            route_table
+                .routes
+                .iter()
+                .any(|route| route.target.starts_with("igw-"))
+        }),
+    }
+}
+
+fn create_route_table(route_table: &RouteTable) -> Ec2RouteTable {
+    Ec2RouteTable {
+        route_table_id: route_table.route_table_id().unwrap_or_default().into(),
+        name: get_tag_value(route_table.tags(), "Name"),
+        is_main: route_table
+            .associations()
+            .iter()
+            .any(|association| association.main().unwrap_or_default()),
+        subnet_ids: route_table
+            .associations()
+            .iter()
+            .filter_map(|association| association.subnet_id())
+            .map(|subnet_id| subnet_id.into())
+            .collect(),
+        routes: route_table.routes().iter().map(create_route).collect(),
+    }
+}
+
+/// A route has exactly one of the many target fields set.
+fn create_route(route: &Route) -> Ec2Route {
+    Ec2Route {
+        destination: route
+            .destination_cidr_block()
+            .or(route.destination_ipv6_cidr_block())
+            .or(route.destination_prefix_list_id())
+            .unwrap_or_default()
+            .into(),
+        target: route
+            .gateway_id()
+            .or(route.nat_gateway_id())
+            .or(route.vpc_peering_connection_id())
+            .or(route.transit_gateway_id())
+            .or(route.egress_only_internet_gateway_id())
+            .or(route.network_interface_id())
+            .or(route.instance_id())
+            .or(route.local_gateway_id())
+            .or(route.carrier_gateway_id())
+            .or(route.core_network_arn())
+            .unwrap_or_default()
+            .into(),
+        state: route.state().map(|state| state.as_str().into()),
+    }
+}
+
+fn create_nat_gateway(nat_gateway: &NatGateway) -> Ec2NatGateway {
+    Ec2NatGateway {
+        nat_gateway_id: nat_gateway.nat_gateway_id().unwrap_or_default().into(),
+        subnet_id: nat_gateway.subnet_id().map(|subnet_id| subnet_id.into()),
+        public_ip: nat_gateway
+            .nat_gateway_addresses()
+            .iter()
+            .find_map(|address| address.public_ip())
+            .map(|public_ip| public_ip.into()),
+        connectivity_type: nat_gateway
+            .connectivity_type()
+            .map(|connectivity_type| connectivity_type.as_str().into()),
+        state: nat_gateway.state().map(|state| state.as_str().into()),
+    }
+}
+
+fn create_peering_connection(peering_connection: &VpcPeeringConnection) -> Ec2VpcPeeringConnection {
+    Ec2VpcPeeringConnection {
+        peering_connection_id: peering_connection
+            .vpc_peering_connection_id()
+            .unwrap_or_default()
+            .into(),
+        requester: format_vpc_info(peering_connection.requester_vpc_info()),
+        accepter: format_vpc_info(peering_connection.accepter_vpc_info()),
+        status: peering_connection
+            .status()
+            .and_then(|status| status.code())
+            .map(|code| code.as_str().into()),
+    }
+}
+
+fn format_vpc_info(vpc_info: Option<&VpcPeeringConnectionVpcInfo>) -> String {
+    vpc_info
+        .map(|vpc_info| {
+            format!(
+                "{} ({})",
+                vpc_info.vpc_id().unwrap_or_default(),
+                vpc_info.cidr_block().unwrap_or_default()
+            )
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_ec2::types::{
+        InternetGatewayAttachment, RouteTableAssociation, VpcCidrBlockAssociation,
+    };
+
+    use super::*;
+
+    fn create_route_table(
+        id: &str,
+        association: RouteTableAssociation,
+        target: &str,
+    ) -> RouteTable {
+        RouteTable::builder()
+            .route_table_id(id)
+            .vpc_id("vpc-1")
+            .associations(association)
+            .routes(
+                Route::builder()
+                    .destination_cidr_block("10.0.0.0/16")
+                    .gateway_id("local")
+                    .build(),
+            )
+            .routes(
+                Route::builder()
+                    .destination_cidr_block("0.0.0.0/0")
+                    .set_gateway_id(target.starts_with("igw-").then(|| target.into()))
+                    .set_nat_gateway_id(target.starts_with("nat-").then(|| target.into()))
+                    .build(),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_group_resources_under_vpc_and_resolve_route_tables() {
+        let vpcs = vec![
+            Vpc::builder()
+                .vpc_id("vpc-1")
+                .cidr_block_association_set(
+                    VpcCidrBlockAssociation::builder()
+                        .cidr_block("10.0.0.0/16")
+                        .build(),
+                )
+                .build(),
+            Vpc::builder().vpc_id("vpc-2").build(),
+        ];
+        let subnets = vec![
+            Subnet::builder()
+                .subnet_id("subnet-public")
+                .vpc_id("vpc-1")
+                .build(),
+            Subnet::builder()
+                .subnet_id("subnet-private")
+                .vpc_id("vpc-1")
+                .build(),
+        ];
+        let route_tables = vec![
+            create_route_table(
+                "rtb-public",
+                RouteTableAssociation::builder()
+                    .subnet_id("subnet-public")
+                    .build(),
+                "igw-1",
+            ),
+            create_route_table(
+                "rtb-main",
+                RouteTableAssociation::builder().main(true).build(),
+                "nat-1",
+            ),
+        ];
+        let internet_gateways = vec![InternetGateway::builder()
+            .internet_gateway_id("igw-1")
+            .attachments(InternetGatewayAttachment::builder().vpc_id("vpc-1").build())
+            .build()];
+
+        let vpcs = create_vpcs(
+            &vpcs,
+            &subnets,
+            &route_tables,
+            &internet_gateways,
+            &[],
+            &[],
+            &[],
+        );
+        assert_eq!(vpcs.len(), 2);
+        assert_eq!(vpcs[0].cidr_blocks, vec!["10.0.0.0/16".to_string()]);
+        assert_eq!(
+            vpcs[0].subnets,
+            vec![
+                Ec2Subnet {
+                    subnet_id: "subnet-public".into(),
+                    route_table_id: Some("rtb-public".into()),
+                    is_public: true,
+                    ..Ec2Subnet::default()
+                },
+                Ec2Subnet {
+                    subnet_id: "subnet-private".into(),
+                    route_table_id: Some("rtb-main".into()),
+                    is_public: false,
+                    ..Ec2Subnet::default()
+                },
+            ]
+        );
+        assert_eq!(vpcs[0].route_tables[1].routes[1].target, "nat-1");
+        assert_eq!(vpcs[0].internet_gateways[0].internet_gateway_id, "igw-1");
+        assert!(vpcs[1].subnets.is_empty() && vpcs[1].internet_gateways.is_empty());
+    }
+}
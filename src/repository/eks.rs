@@ -0,0 +1,487 @@
+use std::cmp::Reverse;
+
+use anyhow::anyhow;
+use aws_config::SdkConfig;
+use aws_sdk_eks::{
+    config::{self, Region},
+    types::{Addon, Cluster, FargateProfile, Nodegroup},
+    Client,
+};
+use futures::future::try_join_all;
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::AWSConfig,
+    state::eks_state::{EksAddon, EksCluster, EksFargateProfile, EksNodeGroup},
+};
+
+pub struct EKSRepository;
+
+impl EKSRepository {
+    pub async fn describe_clusters(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+    ) -> anyhow::Result<Vec<EksCluster>> {
+        let client = &EKSRepository::create_client(aws_config, config, region);
+        let mut cluster_names = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .list_clusters()
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    cluster_names.extend_from_slice(response.clusters());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EKS Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        // every cluster is an extra request, they run concurrently
+        let mut clusters = try_join_all(
+            cluster_names
+                .iter()
+                .map(|cluster_name| EKSRepository::describe_cluster(client, cluster_name)),
+        )
+        .await?;
+        clusters.sort_by_key(|cluster| Reverse(cluster.created_at));
+        event!(Level::DEBUG, "{:?}", clusters);
+
+        Ok(clusters)
+    }
+
+    /// The node groups, Fargate profiles and add-ons of the cluster, the updates of the
+    /// add-ons are looked up for the Kubernetes version of the cluster.
+    pub async fn describe_cluster_resources(
+        aws_config: &AWSConfig,
+        config: &SdkConfig,
+        region: Option<&str>,
+        cluster: &EksCluster,
+    ) -> anyhow::Result<(Vec<EksNodeGroup>, Vec<EksFargateProfile>, Vec<EksAddon>)> {
+        let client = &EKSRepository::create_client(aws_config, config, region);
+        let cluster_name = cluster.name.as_str();
+
+        let result = tokio::try_join!(
+            try_join_all(cluster.node_group_names.iter().map(|node_group_name| {
+                EKSRepository::describe_node_group(client, cluster_name, node_group_name)
+            })),
+            try_join_all(
+                cluster
+                    .fargate_profile_names
+                    .iter()
+                    .map(|fargate_profile_name| {
+                        EKSRepository::describe_fargate_profile(
+                            client,
+                            cluster_name,
+                            fargate_profile_name,
+                        )
+                    })
+            ),
+            EKSRepository::describe_addons(client, cluster_name, cluster.version.as_deref()),
+        );
+        event!(Level::DEBUG, "{:?}", result);
+
+        result
+    }
+
+    async fn describe_cluster(client: &Client, cluster_name: &str) -> anyhow::Result<EksCluster> {
+        let result = tokio::try_join!(
+            async {
+                client
+                    .describe_cluster()
+                    .name(cluster_name)
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!(err))
+            },
+            EKSRepository::list_node_group_names(client, cluster_name),
+            EKSRepository::list_fargate_profile_names(client, cluster_name),
+        );
+
+        match result {
+            Ok((response, node_group_names, fargate_profile_names)) => {
+                let cluster = response
+                    .cluster()
+                    .ok_or_else(|| anyhow!("Cluster {} has no description", cluster_name))?;
+                Ok(EksCluster {
+                    node_group_names,
+                    fargate_profile_names,
+                    ..create_cluster(cluster)
+                })
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error EKS Repository {:?}", err);
+                Err(err)
+            }
+        }
+    }
+
+    async fn list_node_group_names(
+        client: &Client,
+        cluster_name: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .list_nodegroups()
+                .cluster_name(cluster_name)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))?;
+            result.extend_from_slice(response.nodegroups());
+            next_token = response.next_token().map(|token| token.to_string());
+            if next_token.is_none() {
+                return Ok(result);
+            }
+        }
+    }
+
+    async fn list_fargate_profile_names(
+        client: &Client,
+        cluster_name: &str,
+    ) -> anyhow::Result<Vec<String>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .list_fargate_profiles()
+                .cluster_name(cluster_name)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))?;
+            result.extend_from_slice(response.fargate_profile_names());
+            next_token = response.next_token().map(|token| token.to_string());
+            if next_token.is_none() {
+                return Ok(result);
+            }
+        }
+    }
+
+    async fn describe_node_group(
+        client: &Client,
+        cluster_name: &str,
+        node_group_name: &str,
+    ) -> anyhow::Result<EksNodeGroup> {
+        match client
+            .describe_nodegroup()
+            .cluster_name(cluster_name)
+            .nodegroup_name(node_group_name)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .nodegroup()
+                .map(create_node_group)
+                .unwrap_or_else(|| EksNodeGroup {
+                    name: node_group_name.into(),
+                    ..EksNodeGroup::default()
+                })),
+            Err(err) => {
+                event!(Level::WARN, "Error EKS Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    async fn describe_fargate_profile(
+        client: &Client,
+        cluster_name: &str,
+        fargate_profile_name: &str,
+    ) -> anyhow::Result<EksFargateProfile> {
+        match client
+            .describe_fargate_profile()
+            .cluster_name(cluster_name)
+            .fargate_profile_name(fargate_profile_name)
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .fargate_profile()
+                .map(create_fargate_profile)
+                .unwrap_or_else(|| EksFargateProfile {
+                    name: fargate_profile_name.into(),
+                    ..EksFargateProfile::default()
+                })),
+            Err(err) => {
+                event!(Level::WARN, "Error EKS Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    async fn describe_addons(
+        client: &Client,
+        cluster_name: &str,
+        kubernetes_version: Option<&str>,
+    ) -> anyhow::Result<Vec<EksAddon>> {
+        let mut addon_names = vec![];
+        let mut next_token = None;
+
+        loop {
+            match client
+                .list_addons()
+                .cluster_name(cluster_name)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => {
+                    addon_names.extend_from_slice(response.addons());
+                    next_token = response.next_token().map(|token| token.to_string());
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::WARN, "Error EKS Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            }
+        }
+
+        try_join_all(addon_names.iter().map(|addon_name| {
+            EKSRepository::describe_addon(client, cluster_name, addon_name, kubernetes_version)
+        }))
+        .await
+    }
+
+    async fn describe_addon(
+        client: &Client,
+        cluster_name: &str,
+        addon_name: &str,
+        kubernetes_version: Option<&str>,
+    ) -> anyhow::Result<EksAddon> {
+        let result = tokio::try_join!(
+            async {
+                client
+                    .describe_addon()
+                    .cluster_name(cluster_name)
+                    .addon_name(addon_name)
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!(err))
+            },
+            EKSRepository::list_addon_versions(client, addon_name, kubernetes_version),
+        );
+
+        match result {
+            Ok((response, addon_versions)) => {
+                let mut addon = response
+                    .addon()
+                    .map(create_addon)
+                    .unwrap_or_else(|| EksAddon {
+                        name: addon_name.into(),
+                        ..EksAddon::default()
+                    });
+                addon.available_updates =
+                    get_addon_updates(addon.version.as_deref(), &addon_versions);
+                Ok(addon)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error EKS Repository {:?}", err);
+                Err(err)
+            }
+        }
+    }
+
+    /// The versions of the add-on that are compatible with the Kubernetes version.
+    async fn list_addon_versions(
+        client: &Client,
+        addon_name: &str,
+        kubernetes_version: Option<&str>,
+    ) -> anyhow::Result<Vec<String>> {
+        let mut result = vec![];
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .describe_addon_versions()
+                .addon_name(addon_name)
+                .set_kubernetes_version(kubernetes_version.map(|version| version.into()))
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))?;
+            result.extend(
+                response
+                    .addons()
+                    .iter()
+                    .flat_map(|addon_info| addon_info.addon_versions())
+                    .filter_map(|addon_version| addon_version.addon_version())
+                    .map(|addon_version| addon_version.to_string()),
+            );
+            next_token = response.next_token().map(|token| token.to_string());
+            if next_token.is_none() {
+                return Ok(result);
+            }
+        }
+    }
+
+    fn create_client(aws_config: &AWSConfig, config: &SdkConfig, region: Option<&str>) -> Client {
+        let mut client_builder = config::Builder::from(config);
+
+        if !aws_config.endpoint.is_empty() {
+            client_builder = client_builder.endpoint_url(&aws_config.endpoint);
+        }
+
+        if let Some(region) = region {
+            client_builder = client_builder.region(Region::new(region.to_string()));
+        }
+
+        Client::from_conf(client_builder.build())
+    }
+}
+
+fn create_cluster(cluster: &Cluster) -> EksCluster {
+    EksCluster {
+        name: cluster.name().unwrap_or_default().into(),
+        arn: cluster.arn().map(|arn| arn.into()),
+        version: cluster.version().map(|version| version.into()),
+        status: cluster.status().map(|status| status.as_str().into()),
+        endpoint: cluster.endpoint().map(|endpoint| endpoint.into()),
+        platform_version: cluster
+            .platform_version()
+            .map(|platform_version| platform_version.into()),
+        certificate_authority_data: cluster
+            .certificate_authority()
+            .and_then(|certificate| certificate.data())
+            .map(|data| data.into()),
+        created_at: cluster.created_at().map(|date_time| date_time.secs()),
+        ..EksCluster::default()
+    }
+}
+
+fn create_node_group(node_group: &Nodegroup) -> EksNodeGroup {
+    let scaling_config = node_group.scaling_config();
+
+    EksNodeGroup {
+        name: node_group.nodegroup_name().unwrap_or_default().into(),
+        status: node_group.status().map(|status| status.as_str().into()),
+        version: node_group.version().map(|version| version.into()),
+        release_version: node_group
+            .release_version()
+            .map(|release_version| release_version.into()),
+        capacity_type: node_group
+            .capacity_type()
+            .map(|capacity_type| capacity_type.as_str().into()),
+        ami_type: node_group
+            .ami_type()
+            .map(|ami_type| ami_type.as_str().into()),
+        instance_types: node_group.instance_types().to_vec(),
+        min_size: scaling_config.and_then(|scaling_config| scaling_config.min_size()),
+        desired_size: scaling_config.and_then(|scaling_config| scaling_config.desired_size()),
+        max_size: scaling_config.and_then(|scaling_config| scaling_config.max_size()),
+    }
+}
+
+fn create_fargate_profile(fargate_profile: &FargateProfile) -> EksFargateProfile {
+    EksFargateProfile {
+        name: fargate_profile
+            .fargate_profile_name()
+            .unwrap_or_default()
+            .into(),
+        status: fargate_profile
+            .status()
+            .map(|status| status.as_str().into()),
+        pod_execution_role_arn: fargate_profile
+            .pod_execution_role_arn()
+            .map(|role_arn| role_arn.into()),
+        selectors: fargate_profile
+            .selectors()
+            .iter()
+            .map(|selector| {
+                let mut labels = selector
+                    .labels()
+                    .map(|labels| {
+                        labels
+                            .iter()
+                            .map(|(key, value)| format!("{}={}", key, value))
+                            .collect::<Vec<String>>()
+                    })
+                    .unwrap_or_default();
+                labels.sort();
+
+                match (selector.namespace().unwrap_or("*"), labels.is_empty()) {
+                    (namespace, true) => namespace.into(),
+                    (namespace, false) => format!("{} {{{}}}", namespace, labels.join(", ")),
+                }
+            })
+            .collect(),
+        subnets: fargate_profile.subnets().to_vec(),
+    }
+}
+
+fn create_addon(addon: &Addon) -> EksAddon {
+    EksAddon {
+        name: addon.addon_name().unwrap_or_default().into(),
+        version: addon.addon_version().map(|version| version.into()),
+        status: addon.status().map(|status| status.as_str().into()),
+        available_updates: vec![],
+    }
+}
+
+/// Add-on versions like `v1.15.1-eksbuild.2` are compared by their numbers.
+fn parse_addon_version(version: &str) -> Vec<u64> {
+    version
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|number| number.parse().ok())
+        .collect()
+}
+
+fn get_addon_updates(version: Option<&str>, addon_versions: &[String]) -> Vec<String> {
+    let Some(version) = version.map(parse_addon_version) else {
+        return vec![];
+    };
+
+    let mut updates = addon_versions
+        .iter()
+        .filter(|addon_version| parse_addon_version(addon_version) > version)
+        .cloned()
+        .collect::<Vec<String>>();
+    updates.sort_by_key(|addon_version| Reverse(parse_addon_version(addon_version)));
+    updates.dedup();
+    updates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_newer_addon_versions_newest_first() {
+        let addon_versions = vec![
+            "v1.15.1-eksbuild.1".to_string(),
+            "v1.16.0-eksbuild.1".to_string(),
+            "v1.15.10-eksbuild.1".to_string(),
+            "v1.15.1-eksbuild.3".to_string(),
+            "v1.14.0-eksbuild.2".to_string(),
+        ];
+
+        assert_eq!(
+            get_addon_updates(Some("v1.15.1-eksbuild.1"), &addon_versions),
+            vec![
+                "v1.16.0-eksbuild.1",
+                "v1.15.10-eksbuild.1",
+                "v1.15.1-eksbuild.3"
+            ]
+        );
+        assert!(get_addon_updates(Some("v1.16.0-eksbuild.1"), &addon_versions).is_empty());
+        assert!(get_addon_updates(None, &addon_versions).is_empty());
+    }
+}
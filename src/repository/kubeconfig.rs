@@ -0,0 +1,278 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use anyhow::{anyhow, Context, Result};
+use serde_json::json;
+use serde_yaml::{Mapping, Value};
+
+use crate::state::eks_state::EksCluster;
+
+use super::s3_transfer::get_partial_path;
+
+/// The entries `aws eks update-kubeconfig` writes for a cluster, all named by the
+/// cluster ARN. The user fetches its token with the AWS CLI of the selected profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KubeconfigEntry {
+    pub name: String,
+    pub cluster_name: String,
+    pub endpoint: String,
+    pub certificate_authority_data: String,
+    pub region: String,
+    /// Unset for credentials from the environment, they are no named profile.
+    pub profile_name: Option<String>,
+}
+
+impl KubeconfigEntry {
+    pub fn new(
+        cluster: &EksCluster,
+        region: &str,
+        profile_name: Option<&str>,
+    ) -> Result<KubeconfigEntry> {
+        let (Some(endpoint), Some(certificate_authority_data)) =
+            (&cluster.endpoint, &cluster.certificate_authority_data)
+        else {
+            return Err(anyhow!(
+                "Cluster {} has no endpoint yet, it is {}",
+                cluster.name,
+                cluster.status.as_deref().unwrap_or("unknown")
+            ));
+        };
+
+        Ok(KubeconfigEntry {
+            name: cluster.arn.clone().unwrap_or_else(|| cluster.name.clone()),
+            cluster_name: cluster.name.clone(),
+            endpoint: endpoint.clone(),
+            certificate_authority_data: certificate_authority_data.clone(),
+            region: region.into(),
+            profile_name: profile_name.map(|profile_name| profile_name.into()),
+        })
+    }
+}
+
+/// Merges the entry into the kubeconfig at `path` and makes it the current context. The
+/// file is replaced only once the merged kubeconfig is written completely.
+pub fn write_kubeconfig(path: &Path, entry: &KubeconfigEntry) -> Result<()> {
+    let kubeconfig = match fs::read_to_string(path) {
+        Ok(kubeconfig) => Some(kubeconfig),
+        Err(err) if err.kind() == ErrorKind::NotFound => None,
+        Err(err) => return Err(err).context(format!("Could not read kubeconfig {:?}", path)),
+    };
+    let merged_kubeconfig = merge_kubeconfig(kubeconfig.as_deref(), entry)
+        .context(format!("Could not merge kubeconfig {:?}", path))?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!("Could not create directory {:?}", parent))?;
+    }
+    let partial_path = get_partial_path(path);
+    let result = fs::write(&partial_path, merged_kubeconfig)
+        .context(format!("Could not write file {:?}", partial_path))
+        .and_then(|_| {
+            // the kubeconfig may be readable by its owner only, the merged one stays so
+            if let Ok(metadata) = fs::metadata(path) {
+                fs::set_permissions(&partial_path, metadata.permissions())
+                    .context(format!("Could not write file {:?}", partial_path))?;
+            }
+            fs::rename(&partial_path, path)
+                .context(format!("Could not replace kubeconfig {:?}", path))
+        });
+
+    if result.is_err() {
+        let _ = fs::remove_file(&partial_path);
+    }
+    result
+}
+
+pub fn merge_kubeconfig(kubeconfig: Option<&str>, entry: &KubeconfigEntry) -> Result<String> {
+    let mut kubeconfig = match kubeconfig.map(serde_yaml::from_str::<Value>).transpose()? {
+        None | Some(Value::Null) => serde_yaml::to_value(json!({
+            "apiVersion": "v1",
+            "kind": "Config",
+            "preferences": {},
+            "clusters": [],
+            "contexts": [],
+            "users": [],
+        }))?,
+        Some(kubeconfig) => kubeconfig,
+    };
+    let Value::Mapping(mapping) = &mut kubeconfig else {
+        return Err(anyhow!("The kubeconfig is no YAML mapping"));
+    };
+
+    let mut exec = json!({
+        "apiVersion": "client.authentication.k8s.io/v1beta1",
+        "command": "aws",
+        "args": [
+            "--region",
+            entry.region,
+            "eks",
+            "get-token",
+            "--cluster-name",
+            entry.cluster_name,
+            "--output",
+            "json",
+        ],
+    });
+    if let Some(profile_name) = &entry.profile_name {
+        exec["env"] = json!([{ "name": "AWS_PROFILE", "value": profile_name }]);
+    }
+
+    upsert_named_entry(
+        mapping,
+        "clusters",
+        json!({
+            "name": entry.name,
+            "cluster": {
+                "server": entry.endpoint,
+                "certificate-authority-data": entry.certificate_authority_data,
+            },
+        }),
+    )?;
+    upsert_named_entry(
+        mapping,
+        "contexts",
+        json!({
+            "name": entry.name,
+            "context": { "cluster": entry.name, "user": entry.name },
+        }),
+    )?;
+    upsert_named_entry(
+        mapping,
+        "users",
+        json!({ "name": entry.name, "user": { "exec": exec } }),
+    )?;
+    mapping.insert("current-context".into(), entry.name.clone().into());
+
+    Ok(serde_yaml::to_string(&kubeconfig)?)
+}
+
+/// Replaces the entry of the same name in the list or else appends it.
+fn upsert_named_entry(
+    mapping: &mut Mapping,
+    list_name: &str,
+    entry: serde_json::Value,
+) -> Result<()> {
+    let entry = serde_yaml::to_value(entry)?;
+    let list = mapping
+        .entry(list_name.into())
+        .or_insert_with(|| Value::Sequence(vec![]));
+    if list.is_null() {
+        *list = Value::Sequence(vec![]);
+    }
+    let Value::Sequence(list) = list else {
+        return Err(anyhow!("The {} of the kubeconfig are no list", list_name));
+    };
+
+    match list
+        .iter_mut()
+        .find(|list_entry| list_entry.get("name") == entry.get("name"))
+    {
+        Some(list_entry) => *list_entry = entry,
+        None => list.push(entry),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARN: &str = "arn:aws:eks:eu-west-1:123456789012:cluster/prod";
+
+    fn create_entry() -> KubeconfigEntry {
+        KubeconfigEntry {
+            name: ARN.into(),
+            cluster_name: "prod".into(),
+            endpoint: "https://ABC.gr7.eu-west-1.eks.amazonaws.com".into(),
+            certificate_authority_data: "LS0tLS1CRUdJTg==".into(),
+            region: "eu-west-1".into(),
+            profile_name: Some("dev".into()),
+        }
+    }
+
+    fn get_names(kubeconfig: &Value, list_name: &str) -> Vec<String> {
+        kubeconfig[list_name]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_write_new_kubeconfig() {
+        let kubeconfig: Value =
+            serde_yaml::from_str(&merge_kubeconfig(None, &create_entry()).unwrap()).unwrap();
+
+        assert_eq!(kubeconfig["kind"], "Config");
+        assert_eq!(kubeconfig["current-context"], ARN);
+        assert_eq!(
+            kubeconfig["clusters"][0]["cluster"]["server"],
+            "https://ABC.gr7.eu-west-1.eks.amazonaws.com"
+        );
+        assert_eq!(kubeconfig["contexts"][0]["context"]["user"], ARN);
+
+        let exec = &kubeconfig["users"][0]["user"]["exec"];
+        assert_eq!(exec["command"], "aws");
+        assert_eq!(exec["args"][1], "eu-west-1");
+        assert_eq!(exec["args"][5], "prod");
+        assert_eq!(exec["env"][0]["value"], "dev");
+    }
+
+    #[test]
+    fn test_merge_into_existing_kubeconfig() {
+        let existing_kubeconfig = format!(
+            concat!(
+                "apiVersion: v1\n",
+                "kind: Config\n",
+                "clusters:\n",
+                "- name: minikube\n",
+                "  cluster:\n",
+                "    server: https://127.0.0.1:8443\n",
+                "- name: {arn}\n",
+                "  cluster:\n",
+                "    server: https://old.eks.amazonaws.com\n",
+                "contexts:\n",
+                "- name: minikube\n",
+                "  context:\n",
+                "    cluster: minikube\n",
+                "    user: minikube\n",
+                "current-context: minikube\n",
+                "users: null\n",
+            ),
+            arn = ARN
+        );
+        let entry = KubeconfigEntry {
+            profile_name: None,
+            ..create_entry()
+        };
+
+        let kubeconfig: Value =
+            serde_yaml::from_str(&merge_kubeconfig(Some(&existing_kubeconfig), &entry).unwrap())
+                .unwrap();
+
+        assert_eq!(get_names(&kubeconfig, "clusters"), vec!["minikube", ARN]);
+        assert_eq!(
+            kubeconfig["clusters"][1]["cluster"]["server"],
+            "https://ABC.gr7.eu-west-1.eks.amazonaws.com"
+        );
+        assert_eq!(get_names(&kubeconfig, "contexts"), vec!["minikube", ARN]);
+        assert_eq!(get_names(&kubeconfig, "users"), vec![ARN]);
+        assert!(kubeconfig["users"][0]["user"]["exec"].get("env").is_none());
+        assert_eq!(kubeconfig["current-context"], ARN);
+    }
+
+    #[test]
+    fn test_reject_kubeconfig_without_mapping() {
+        assert!(merge_kubeconfig(Some("- a\n- b\n"), &create_entry()).is_err());
+    }
+
+    #[test]
+    fn test_require_endpoint_of_cluster() {
+        let cluster = EksCluster {
+            name: "prod".into(),
+            status: Some("CREATING".into()),
+            ..EksCluster::default()
+        };
+        assert!(KubeconfigEntry::new(&cluster, "eu-west-1", None).is_err());
+    }
+}
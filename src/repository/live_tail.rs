@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::state::cloud_watch_logs_state::CloudWatchLogEvent;
+
+/// FilterLogEvents is queried from the last seen timestamp on, so events sharing this
+/// timestamp are returned again and have to be skipped.
+#[derive(Debug)]
+pub struct LiveTailCursor {
+    start_time: i64,
+    seen_event_ids: HashSet<String>,
+}
+
+impl LiveTailCursor {
+    /// Continues behind the shown events. Events fetched by GetLogEvents carry no id, so
+    /// the tail starts after their last timestamp instead.
+    pub fn new(start_time: i64, shown_log_events: &[CloudWatchLogEvent]) -> Self {
+        let last_log_events = shown_log_events
+            .iter()
+            .filter(|log_event| log_event.timestamp == Some(start_time));
+
+        if last_log_events
+            .clone()
+            .any(|log_event| log_event.event_id.is_none())
+        {
+            LiveTailCursor {
+                start_time: start_time + 1,
+                seen_event_ids: HashSet::new(),
+            }
+        } else {
+            LiveTailCursor {
+                start_time,
+                seen_event_ids: last_log_events
+                    .filter_map(|log_event| log_event.event_id.clone())
+                    .collect(),
+            }
+        }
+    }
+
+    pub fn start_time(&self) -> i64 {
+        self.start_time
+    }
+
+    /// Drops the events seen before and moves the cursor behind the new ones.
+    pub fn take_new_events(
+        &mut self,
+        log_events: Vec<CloudWatchLogEvent>,
+    ) -> Vec<CloudWatchLogEvent> {
+        let log_events = log_events
+            .into_iter()
+            .filter(|log_event| match &log_event.event_id {
+                Some(event_id) => !self.seen_event_ids.contains(event_id),
+                None => true,
+            })
+            .collect::<Vec<CloudWatchLogEvent>>();
+
+        if let Some(last_timestamp) = log_events
+            .iter()
+            .filter_map(|log_event| log_event.timestamp)
+            .max()
+        {
+            if last_timestamp > self.start_time {
+                self.start_time = last_timestamp;
+                self.seen_event_ids.clear();
+            }
+        }
+        self.seen_event_ids.extend(
+            log_events
+                .iter()
+                .filter(|log_event| log_event.timestamp == Some(self.start_time))
+                .filter_map(|log_event| log_event.event_id.clone()),
+        );
+
+        log_events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_log_event(event_id: Option<&str>, timestamp: i64) -> CloudWatchLogEvent {
+        CloudWatchLogEvent {
+            event_id: event_id.map(|event_id| event_id.into()),
+            timestamp: Some(timestamp),
+            ..CloudWatchLogEvent::default()
+        }
+    }
+
+    fn get_event_ids(log_events: &[CloudWatchLogEvent]) -> Vec<&str> {
+        log_events
+            .iter()
+            .filter_map(|log_event| log_event.event_id.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_take_only_events_not_seen_before() {
+        let mut cursor = LiveTailCursor::new(100, &[create_log_event(Some("a"), 100)]);
+
+        let log_events = cursor.take_new_events(vec![
+            create_log_event(Some("a"), 100),
+            create_log_event(Some("b"), 100),
+            create_log_event(Some("c"), 200),
+        ]);
+        assert_eq!(get_event_ids(&log_events), vec!["b", "c"]);
+        assert_eq!(cursor.start_time(), 200);
+
+        let log_events = cursor.take_new_events(vec![
+            create_log_event(Some("c"), 200),
+            create_log_event(Some("d"), 200),
+        ]);
+        assert_eq!(get_event_ids(&log_events), vec!["d"]);
+        assert!(cursor
+            .take_new_events(vec![create_log_event(Some("d"), 200)])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_start_behind_shown_events_without_id() {
+        let cursor = LiveTailCursor::new(
+            100,
+            &[create_log_event(None, 50), create_log_event(None, 100)],
+        );
+        assert_eq!(cursor.start_time(), 101);
+
+        let cursor = LiveTailCursor::new(100, &[]);
+        assert_eq!(cursor.start_time(), 100);
+    }
+}
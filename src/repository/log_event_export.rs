@@ -0,0 +1,200 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat};
+use serde_json::json;
+
+use crate::state::cloud_watch_logs_state::{CloudWatchExportFormat, CloudWatchLogEvent};
+
+use super::s3_transfer::get_partial_path;
+
+pub struct LogEventExport<W: Write> {
+    writer: W,
+    format: CloudWatchExportFormat,
+}
+
+impl LogEventExport<BufWriter<File>> {
+    /// Writes to the partial file of `path`, which `persist` renames to `path`.
+    pub fn create(path: &Path, format: CloudWatchExportFormat) -> Result<Self> {
+        let partial_path = get_partial_path(path);
+        let file = File::create(&partial_path)
+            .context(format!("Could not create export file {:?}", partial_path))?;
+        LogEventExport::new(BufWriter::new(file), format).inspect_err(|_| remove_partial_file(path))
+    }
+
+    pub fn persist(self, path: &Path) -> Result<()> {
+        drop(self.finish()?);
+        fs::rename(get_partial_path(path), path)
+            .context(format!("Could not replace export file {:?}", path))
+    }
+}
+
+pub fn remove_partial_file(path: &Path) {
+    let _ = fs::remove_file(get_partial_path(path));
+}
+
+impl<W: Write> LogEventExport<W> {
+    pub fn new(mut writer: W, format: CloudWatchExportFormat) -> Result<Self> {
+        if format == CloudWatchExportFormat::Csv {
+            writeln!(
+                writer,
+                "timestamp,ingestion_time,log_stream_name,event_id,message"
+            )
+            .context("Could not write export header")?;
+        }
+
+        Ok(LogEventExport { writer, format })
+    }
+
+    pub fn write_log_events(&mut self, log_events: &[CloudWatchLogEvent]) -> Result<()> {
+        for log_event in log_events {
+            let timestamp = format_timestamp(log_event.timestamp);
+            let ingestion_time = format_timestamp(log_event.ingestion_time);
+            let log_stream_name = log_event.log_stream_name.as_deref().unwrap_or_default();
+            let event_id = log_event.event_id.as_deref().unwrap_or_default();
+            let message = log_event.message.as_deref().unwrap_or_default();
+
+            match self.format {
+                CloudWatchExportFormat::Text => writeln!(
+                    self.writer,
+                    "{} {} {}",
+                    timestamp,
+                    log_stream_name,
+                    message.trim_end()
+                ),
+                CloudWatchExportFormat::JsonLines => writeln!(
+                    self.writer,
+                    "{}",
+                    json!({
+                        "timestamp": timestamp,
+                        "ingestionTime": ingestion_time,
+                        "logStreamName": log_stream_name,
+                        "eventId": event_id,
+                        "message": message,
+                    })
+                ),
+                CloudWatchExportFormat::Csv => writeln!(
+                    self.writer,
+                    "{},{},{},{},{}",
+                    timestamp,
+                    ingestion_time,
+                    escape_csv(log_stream_name),
+                    escape_csv(event_id),
+                    escape_csv(message)
+                ),
+            }
+            .context("Could not write log events to the export file")?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        self.writer
+            .flush()
+            .context("Could not flush the export file")?;
+        Ok(self.writer)
+    }
+}
+
+fn format_timestamp(timestamp: Option<i64>) -> String {
+    timestamp
+        .and_then(|millis| {
+            DateTime::from_timestamp(
+                millis.div_euclid(1000),
+                (millis.rem_euclid(1000) * 1_000_000) as u32,
+            )
+        })
+        .map(|date_time| date_time.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .unwrap_or_default()
+}
+
+pub fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    fn create_log_events() -> Vec<CloudWatchLogEvent> {
+        vec![CloudWatchLogEvent {
+            event_id: Some("1".into()),
+            log_stream_name: Some("stream".into()),
+            timestamp: Some(1_700_000_000_123),
+            ingestion_time: Some(1_700_000_001_000),
+            message: Some("{\"level\":\"error\",\"text\":\"a, b\"}\n".into()),
+        }]
+    }
+
+    fn export(format: CloudWatchExportFormat) -> String {
+        let mut log_event_export = LogEventExport::new(vec![], format).unwrap();
+        log_event_export
+            .write_log_events(&create_log_events())
+            .unwrap();
+        String::from_utf8(log_event_export.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_export_plain_text() {
+        assert_eq!(
+            export(CloudWatchExportFormat::Text),
+            "2023-11-14T22:13:20.123Z stream {\"level\":\"error\",\"text\":\"a, b\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_export_json_lines() {
+        assert_eq!(
+            export(CloudWatchExportFormat::JsonLines),
+            concat!(
+                "{\"timestamp\":\"2023-11-14T22:13:20.123Z\",",
+                "\"ingestionTime\":\"2023-11-14T22:13:21.000Z\",",
+                "\"logStreamName\":\"stream\",\"eventId\":\"1\",",
+                "\"message\":\"{\\\"level\\\":\\\"error\\\",\\\"text\\\":\\\"a, b\\\"}\\n\"}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_export_csv_escapes_values() {
+        assert_eq!(
+            export(CloudWatchExportFormat::Csv),
+            concat!(
+                "timestamp,ingestion_time,log_stream_name,event_id,message\n",
+                "2023-11-14T22:13:20.123Z,2023-11-14T22:13:21.000Z,stream,1,",
+                "\"{\"\"level\"\":\"\"error\"\",\"\"text\"\":\"\"a, b\"\"}\n\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_existing_file_only_on_persist() {
+        let path =
+            env::temp_dir().join(format!("aws-console-tui-log-events-{}.txt", process::id()));
+        fs::write(&path, "previous export").unwrap();
+
+        let mut log_event_export =
+            LogEventExport::create(&path, CloudWatchExportFormat::Text).unwrap();
+        log_event_export
+            .write_log_events(&create_log_events())
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "previous export");
+
+        log_event_export.persist(&path).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("stream"));
+        assert!(!get_partial_path(&path).exists());
+
+        let _ = fs::remove_file(path);
+    }
+}
@@ -1,8 +1,5 @@
 use anyhow::anyhow;
-use aws_config::{
-    meta::region::RegionProviderChain, profile::profile_file::ProfileFileKind, BehaviorVersion,
-    SdkConfig,
-};
+use aws_config::{profile::profile_file::ProfileFileKind, BehaviorVersion, SdkConfig};
 
 use tracing::{event, Level};
 
@@ -11,6 +8,7 @@ use crate::{config::app_config::AWSConfig, state::appstate::ProfileSource};
 #[derive(Debug)]
 pub struct AwsCallerIdentity {
     pub account: String,
+    #[allow(dead_code)]
     pub arn: String,
     pub user_id: String,
 }
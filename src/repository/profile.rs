@@ -83,7 +83,10 @@ fn join_full_path(aws_config_path: PathBuf, file_name: &str) -> PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use std::env;
+    use std::{
+        env,
+        sync::{Mutex, MutexGuard},
+    };
 
     use assert_fs::prelude::{FileWriteStr, PathChild};
 
@@ -96,8 +99,20 @@ mod tests {
         state::appstate::ProfileSource,
     };
 
+    // the profile lookup reads process-wide environment variables, so tests must not interleave
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_clean_env() -> MutexGuard<'static, ()> {
+        let guard = ENV_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        env::remove_var(AWS_ACCESS_KEY_ID);
+        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        env::remove_var(AWS_DEFAULT_REGION);
+        guard
+    }
+
     #[test]
     fn test_complete_env_config() {
+        let _guard = lock_clean_env();
         let id = "test_id";
         env::set_var(AWS_ACCESS_KEY_ID, id);
         env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
@@ -119,6 +134,7 @@ mod tests {
 
     #[test]
     fn test_incomplete_env_config() {
+        let _guard = lock_clean_env();
         let id = "test_id";
         env::set_var(AWS_ACCESS_KEY_ID, id);
         env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
@@ -135,7 +151,7 @@ mod tests {
 
     #[test]
     fn test_credential_file_profile_fetch() {
-        env::remove_var(AWS_ACCESS_KEY_ID);
+        let _guard = lock_clean_env();
         let temp_dir = assert_fs::TempDir::new().unwrap();
         let credential_file = temp_dir.child(AWS_CREDENTIALS_FILE);
         credential_file
@@ -159,12 +175,13 @@ lorem ipsum",
         let profiles = get_available_profiles(&aws_config).unwrap();
 
         assert_eq!(2, profiles.len());
-        assert!(profiles.contains_key(&"default".to_string()));
-        assert!(profiles.contains_key(&"dev-test".to_string()));
+        assert!(profiles.contains_key("default"));
+        assert!(profiles.contains_key("dev-test"));
     }
 
     #[test]
     fn test_config_file_profile_fetch() {
+        let _guard = lock_clean_env();
         let temp_dir = assert_fs::TempDir::new().unwrap();
         let config_file = temp_dir.child(AWS_CONFIG_FILE);
         config_file
@@ -189,7 +206,7 @@ lorem ipsum
         let profiles = get_available_profiles(&aws_config).unwrap();
 
         assert_eq!(2, profiles.len());
-        assert!(profiles.contains_key(&"default".to_string()));
-        assert!(profiles.contains_key(&"dev".to_string()));
+        assert!(profiles.contains_key("default"));
+        assert!(profiles.contains_key("dev"));
     }
 }
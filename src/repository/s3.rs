@@ -0,0 +1,1091 @@
+use std::{future::Future, path::Path, time::Duration};
+
+use anyhow::{anyhow, Context};
+use aws_config::SdkConfig;
+use aws_sdk_s3::{
+    config::{self, Region},
+    error::ProvideErrorMetadata,
+    presigning::PresigningConfig,
+    primitives::ByteStream,
+    types::{BucketLocationConstraint, CompletedMultipartUpload, CompletedPart},
+    Client,
+};
+use futures::future::join_all;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde_json::{json, Value};
+use tokio::{
+    fs::{self, File},
+    io::{AsyncReadExt, AsyncWriteExt},
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::AWSConfig,
+    repository::{
+        s3_bucket_config::{
+            cors_rule_json, encryption_rule_json, is_not_configured, lifecycle_rule_json,
+            policy_json, remove_empty_fields, replication_json, tags_json,
+        },
+        s3_transfer::get_partial_path,
+    },
+    state::s3_state::{
+        S3Bucket, S3BucketConfigSection, S3Object, S3ObjectMetadata, S3ObjectVersion,
+        S3PresignMethod,
+    },
+};
+
+const PREFIX_DELIMITER: &str = "/";
+/// Files above this size are uploaded in parts of at least this size.
+const MULTIPART_PART_SIZE: u64 = 8 * 1024 * 1024;
+/// A multipart upload consists of at most this many parts.
+const MAX_MULTIPART_PARTS: u64 = 10_000;
+/// CopyObject copies objects of up to this size, larger ones are copied in parts.
+const MAX_COPY_OBJECT_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+/// Listing the versions of a prefix stops after this many versions and delete markers.
+const MAX_OBJECT_VERSIONS: usize = 1000;
+/// The key of a copy source is URL encoded, the path separators stay readable.
+const COPY_SOURCE_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+pub struct S3Repository;
+
+impl S3Repository {
+    pub async fn list_buckets(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+    ) -> anyhow::Result<Vec<S3Bucket>> {
+        let client = S3Repository::get_client(aws_config, sdk_config, None);
+
+        match client.list_buckets().send().await {
+            Ok(response) => {
+                let bucket_names = response
+                    .buckets()
+                    .iter()
+                    .filter_map(|bucket| bucket.name().map(|name| name.to_string()))
+                    .collect::<Vec<String>>();
+                let creation_dates = response
+                    .buckets()
+                    .iter()
+                    .map(|bucket| bucket.creation_date().map(|date_time| date_time.secs()));
+
+                // the location of a bucket is an extra request, they run concurrently
+                let regions = join_all(
+                    bucket_names
+                        .iter()
+                        .map(|bucket_name| S3Repository::get_bucket_region(&client, bucket_name)),
+                )
+                .await;
+
+                let buckets = bucket_names
+                    .into_iter()
+                    .zip(creation_dates)
+                    .zip(regions)
+                    .map(|((name, creation_date), region)| S3Bucket {
+                        name,
+                        creation_date,
+                        region,
+                    })
+                    .collect::<Vec<S3Bucket>>();
+
+                event!(Level::DEBUG, "{:?}", buckets);
+
+                Ok(buckets)
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn list_objects(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        prefix: &str,
+        next_token: Option<String>,
+    ) -> anyhow::Result<(Vec<S3Object>, Option<String>)> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .list_objects_v2()
+            .bucket(&bucket.name)
+            .prefix(prefix)
+            .delimiter(PREFIX_DELIMITER)
+            .set_continuation_token(next_token)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let objects = response
+                    .common_prefixes()
+                    .iter()
+                    .filter_map(|common_prefix| common_prefix.prefix())
+                    .map(|key| S3Object {
+                        key: key.into(),
+                        is_prefix: true,
+                        ..S3Object::default()
+                    })
+                    .chain(response.contents().iter().map(|object| {
+                        S3Object {
+                            key: object.key().unwrap_or_default().into(),
+                            is_prefix: false,
+                            size: object.size(),
+                            last_modified: object.last_modified().map(|date_time| date_time.secs()),
+                            storage_class: object
+                                .storage_class()
+                                .map(|storage_class| storage_class.as_str().into()),
+                            e_tag: object.e_tag().map(|e_tag| e_tag.into()),
+                        }
+                    }))
+                    // the folder placeholder object of the prefix itself is not listed
+                    .filter(|object| object.key != prefix)
+                    .collect::<Vec<S3Object>>();
+
+                event!(Level::DEBUG, "{:?}", objects);
+
+                Ok((
+                    objects,
+                    response.next_continuation_token().map(|token| token.into()),
+                ))
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn head_object(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        (key, version_id): (&str, Option<&str>),
+    ) -> anyhow::Result<S3ObjectMetadata> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .head_object()
+            .bucket(&bucket.name)
+            .key(key)
+            .set_version_id(version_id.map(|version_id| version_id.into()))
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let mut user_metadata = response
+                    .metadata()
+                    .map(|metadata| {
+                        metadata
+                            .iter()
+                            .map(|(key, value)| (key.clone(), value.clone()))
+                            .collect::<Vec<(String, String)>>()
+                    })
+                    .unwrap_or_default();
+                user_metadata.sort();
+
+                Ok(S3ObjectMetadata {
+                    content_length: response.content_length(),
+                    content_type: response.content_type().map(|value| value.into()),
+                    content_encoding: response.content_encoding().map(|value| value.into()),
+                    e_tag: response.e_tag().map(|value| value.into()),
+                    storage_class: response
+                        .storage_class()
+                        .map(|storage_class| storage_class.as_str().into()),
+                    last_modified: response.last_modified().map(|date_time| date_time.secs()),
+                    server_side_encryption: response
+                        .server_side_encryption()
+                        .map(|encryption| encryption.as_str().into()),
+                    sse_kms_key_id: response.ssekms_key_id().map(|value| value.into()),
+                    user_metadata,
+                })
+            }
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Reads the first `max_bytes` bytes of the object.
+    pub async fn get_object_head_bytes(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        (key, version_id): (&str, Option<&str>),
+        max_bytes: u64,
+    ) -> anyhow::Result<Vec<u8>> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .get_object()
+            .bucket(&bucket.name)
+            .key(key)
+            .set_version_id(version_id.map(|version_id| version_id.into()))
+            .range(format!("bytes=0-{}", max_bytes.saturating_sub(1)))
+            .send()
+            .await
+        {
+            Ok(response) => Ok(response
+                .body
+                .collect()
+                .await
+                .context("Could not read the object content")?
+                .to_vec()),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Lists all objects below the prefix, including those in nested folders.
+    pub async fn list_all_objects(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        prefix: &str,
+    ) -> anyhow::Result<Vec<S3Object>> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let mut objects = vec![];
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .list_objects_v2()
+                .bucket(&bucket.name)
+                .prefix(prefix)
+                .set_continuation_token(next_token)
+                .send()
+                .await
+                .map_err(|err| {
+                    event!(Level::WARN, "Error S3 Repository {:?}", err);
+                    anyhow!(err)
+                })?;
+
+            objects.extend(
+                response
+                    .contents()
+                    .iter()
+                    // folder placeholder objects have no content to transfer
+                    .filter(|object| !object.key().unwrap_or_default().ends_with('/'))
+                    .map(|object| S3Object {
+                        key: object.key().unwrap_or_default().into(),
+                        is_prefix: false,
+                        size: object.size(),
+                        last_modified: object.last_modified().map(|date_time| date_time.secs()),
+                        storage_class: object
+                            .storage_class()
+                            .map(|storage_class| storage_class.as_str().into()),
+                        e_tag: object.e_tag().map(|e_tag| e_tag.into()),
+                    }),
+            );
+
+            match response.next_continuation_token() {
+                Some(token) => next_token = Some(token.to_string()),
+                None => break,
+            }
+        }
+
+        Ok(objects)
+    }
+
+    /// Streams the object, or one of its versions, into the file.
+    /// `on_progress` receives the number of written bytes.
+    pub async fn download_object(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        (bucket, key, version_id): (&S3Bucket, &str, Option<&str>),
+        path: &Path,
+        cancellation_token: &CancellationToken,
+        on_progress: &mut impl FnMut(u64),
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let response = run_cancellable(cancellation_token, async {
+            client
+                .get_object()
+                .bucket(&bucket.name)
+                .key(key)
+                .set_version_id(version_id.map(|version_id| version_id.into()))
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))
+        })
+        .await?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .context(format!("Could not create directory {:?}", parent))?;
+        }
+        let partial_path = get_partial_path(path);
+        let mut file = File::create(&partial_path)
+            .await
+            .context(format!("Could not create file {:?}", partial_path))?;
+
+        let mut body = response.body;
+        let result = async {
+            while let Some(bytes) = run_cancellable(cancellation_token, async {
+                body.try_next().await.map_err(|err| anyhow!(err))
+            })
+            .await?
+            {
+                file.write_all(&bytes)
+                    .await
+                    .context(format!("Could not write file {:?}", partial_path))?;
+                on_progress(bytes.len() as u64);
+            }
+            file.flush()
+                .await
+                .context(format!("Could not write file {:?}", partial_path))?;
+            drop(file);
+            fs::rename(&partial_path, path)
+                .await
+                .context(format!("Could not replace file {:?}", path))
+        }
+        .await;
+
+        // an incomplete download is not left behind, the replaced file stays untouched
+        if let Err(err) = &result {
+            event!(Level::WARN, "Error S3 Repository {:?}", err);
+            let _ = fs::remove_file(&partial_path).await;
+        }
+
+        result
+    }
+
+    /// Uploads the file, large files as multipart upload that is aborted on errors.
+    pub async fn upload_object(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        (bucket, key): (&S3Bucket, &str),
+        path: &Path,
+        cancellation_token: &CancellationToken,
+        on_progress: &mut impl FnMut(u64),
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let mut file = File::open(path)
+            .await
+            .context(format!("Could not open file {:?}", path))?;
+        let size = file
+            .metadata()
+            .await
+            .context(format!("Could not read file {:?}", path))?
+            .len();
+
+        if size <= MULTIPART_PART_SIZE {
+            let mut body = Vec::with_capacity(size as usize);
+            file.read_to_end(&mut body)
+                .await
+                .context(format!("Could not read file {:?}", path))?;
+            run_cancellable(cancellation_token, async {
+                client
+                    .put_object()
+                    .bucket(&bucket.name)
+                    .key(key)
+                    .body(ByteStream::from(body))
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!(err))
+            })
+            .await?;
+            on_progress(size);
+
+            return Ok(());
+        }
+
+        let upload_id = client
+            .create_multipart_upload()
+            .bucket(&bucket.name)
+            .key(key)
+            .send()
+            .await
+            .map_err(|err| anyhow!(err))?
+            .upload_id()
+            .context("Multipart upload has no upload id")?
+            .to_string();
+
+        let multipart_part_size = get_multipart_part_size(size);
+        let result = async {
+            let mut completed_parts = vec![];
+            let mut part_number = 1;
+            loop {
+                let mut part = Vec::with_capacity(multipart_part_size as usize);
+                (&mut file)
+                    .take(multipart_part_size)
+                    .read_to_end(&mut part)
+                    .await
+                    .context(format!("Could not read file {:?}", path))?;
+                if part.is_empty() {
+                    break;
+                }
+
+                let part_size = part.len() as u64;
+                let response = run_cancellable(cancellation_token, async {
+                    client
+                        .upload_part()
+                        .bucket(&bucket.name)
+                        .key(key)
+                        .upload_id(&upload_id)
+                        .part_number(part_number)
+                        .body(ByteStream::from(part))
+                        .send()
+                        .await
+                        .map_err(|err| anyhow!(err))
+                })
+                .await?;
+                completed_parts.push(
+                    CompletedPart::builder()
+                        .set_e_tag(response.e_tag().map(|e_tag| e_tag.into()))
+                        .part_number(part_number)
+                        .build(),
+                );
+                on_progress(part_size);
+                part_number += 1;
+            }
+
+            client
+                .complete_multipart_upload()
+                .bucket(&bucket.name)
+                .key(key)
+                .upload_id(&upload_id)
+                .multipart_upload(
+                    CompletedMultipartUpload::builder()
+                        .set_parts(Some(completed_parts))
+                        .build(),
+                )
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))
+        }
+        .await;
+
+        if let Err(err) = result {
+            event!(Level::WARN, "Error S3 Repository {:?}", err);
+            // already uploaded parts are billed until the upload is aborted
+            let _ = client
+                .abort_multipart_upload()
+                .bucket(&bucket.name)
+                .key(key)
+                .upload_id(&upload_id)
+                .send()
+                .await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Signs a GET or PUT request of the object with the credentials of the profile,
+    /// S3 accepts an expiry of up to seven days.
+    pub async fn create_presigned_url(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        (bucket, key): (&S3Bucket, &str),
+        method: S3PresignMethod,
+        expires_in: Duration,
+    ) -> anyhow::Result<String> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let presigning_config = PresigningConfig::expires_in(expires_in)?;
+
+        let result = match method {
+            S3PresignMethod::Get => client
+                .get_object()
+                .bucket(&bucket.name)
+                .key(key)
+                .presigned(presigning_config)
+                .await
+                .map_err(|err| anyhow!(err)),
+            S3PresignMethod::Put => client
+                .put_object()
+                .bucket(&bucket.name)
+                .key(key)
+                .presigned(presigning_config)
+                .await
+                .map_err(|err| anyhow!(err)),
+        };
+
+        match result {
+            Ok(presigned_request) => Ok(presigned_request.uri().into()),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(err)
+            }
+        }
+    }
+
+    /// Lists the versions and delete markers of `key`, or of all objects directly below
+    /// `prefix` without a key, ordered by key and newest first.
+    pub async fn list_object_versions(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        prefix: &str,
+        key: Option<&str>,
+    ) -> anyhow::Result<Vec<S3ObjectVersion>> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let mut object_versions = vec![];
+        let mut key_marker = None;
+        let mut version_id_marker = None;
+
+        loop {
+            let response = match client
+                .list_object_versions()
+                .bucket(&bucket.name)
+                .prefix(key.unwrap_or(prefix))
+                .set_delimiter(key.is_none().then(|| PREFIX_DELIMITER.into()))
+                .set_key_marker(key_marker)
+                .set_version_id_marker(version_id_marker)
+                .send()
+                .await
+            {
+                Ok(response) => response,
+                Err(err) => {
+                    event!(Level::WARN, "Error S3 Repository {:?}", err);
+                    return Err(anyhow!(err));
+                }
+            };
+
+            object_versions.extend(
+                response
+                    .versions()
+                    .iter()
+                    .map(|version| S3ObjectVersion {
+                        key: version.key().unwrap_or_default().into(),
+                        version_id: version.version_id().map(|version_id| version_id.into()),
+                        is_latest: version.is_latest().unwrap_or_default(),
+                        is_delete_marker: false,
+                        size: version.size(),
+                        last_modified: version.last_modified().map(|date_time| date_time.secs()),
+                        storage_class: version
+                            .storage_class()
+                            .map(|storage_class| storage_class.as_str().into()),
+                        e_tag: version.e_tag().map(|e_tag| e_tag.into()),
+                    })
+                    .chain(response.delete_markers().iter().map(|delete_marker| {
+                        S3ObjectVersion {
+                            key: delete_marker.key().unwrap_or_default().into(),
+                            version_id: delete_marker
+                                .version_id()
+                                .map(|version_id| version_id.into()),
+                            is_latest: delete_marker.is_latest().unwrap_or_default(),
+                            is_delete_marker: true,
+                            last_modified: delete_marker
+                                .last_modified()
+                                .map(|date_time| date_time.secs()),
+                            ..S3ObjectVersion::default()
+                        }
+                    }))
+                    // a key lists the versions of keys it is a prefix of as well
+                    .filter(|version| key.is_none_or(|key| version.key == key))
+                    .filter(|version| version.key != prefix),
+            );
+
+            if !response.is_truncated().unwrap_or_default()
+                || object_versions.len() >= MAX_OBJECT_VERSIONS
+            {
+                break;
+            }
+            key_marker = response.next_key_marker().map(|marker| marker.into());
+            version_id_marker = response
+                .next_version_id_marker()
+                .map(|marker| marker.into());
+        }
+
+        // versions and delete markers are listed separately, each newest first
+        object_versions.sort_by(|first, second| {
+            first
+                .key
+                .cmp(&second.key)
+                .then(second.is_latest.cmp(&first.is_latest))
+                .then(second.last_modified.cmp(&first.last_modified))
+        });
+        object_versions.truncate(MAX_OBJECT_VERSIONS);
+
+        event!(Level::DEBUG, "{:?}", object_versions);
+
+        Ok(object_versions)
+    }
+
+    /// Makes a previous version the current one by copying it over the object.
+    pub async fn restore_object_version(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        key: &str,
+        version_id: &str,
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let copy_source = create_copy_source(&bucket.name, key, Some(version_id));
+
+        let result = async {
+            let version = client
+                .head_object()
+                .bucket(&bucket.name)
+                .key(key)
+                .version_id(version_id)
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))?;
+            let size = version.content_length().unwrap_or_default().max(0) as u64;
+
+            if size <= MAX_COPY_OBJECT_SIZE {
+                client
+                    .copy_object()
+                    .bucket(&bucket.name)
+                    .key(key)
+                    .copy_source(&copy_source)
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                return Ok(());
+            }
+
+            // a multipart upload does not take over the metadata of the copy source
+            let upload_id = client
+                .create_multipart_upload()
+                .bucket(&bucket.name)
+                .key(key)
+                .set_content_type(version.content_type().map(|value| value.into()))
+                .set_content_encoding(version.content_encoding().map(|value| value.into()))
+                .set_metadata(version.metadata().cloned())
+                .send()
+                .await
+                .map_err(|err| anyhow!(err))?
+                .upload_id()
+                .context("Multipart upload has no upload id")?
+                .to_string();
+
+            let result = async {
+                let mut completed_parts = vec![];
+                for (part_number, copy_source_range) in (1..).zip(get_copy_source_ranges(size)) {
+                    let response = client
+                        .upload_part_copy()
+                        .bucket(&bucket.name)
+                        .key(key)
+                        .upload_id(&upload_id)
+                        .part_number(part_number)
+                        .copy_source(&copy_source)
+                        .copy_source_range(copy_source_range)
+                        .send()
+                        .await
+                        .map_err(|err| anyhow!(err))?;
+                    completed_parts.push(
+                        CompletedPart::builder()
+                            .set_e_tag(
+                                response
+                                    .copy_part_result()
+                                    .and_then(|copy_part_result| copy_part_result.e_tag())
+                                    .map(|e_tag| e_tag.into()),
+                            )
+                            .part_number(part_number)
+                            .build(),
+                    );
+                }
+
+                client
+                    .complete_multipart_upload()
+                    .bucket(&bucket.name)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .multipart_upload(
+                        CompletedMultipartUpload::builder()
+                            .set_parts(Some(completed_parts))
+                            .build(),
+                    )
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!(err))
+            }
+            .await;
+
+            if result.is_err() {
+                // already copied parts are billed until the upload is aborted
+                let _ = client
+                    .abort_multipart_upload()
+                    .bucket(&bucket.name)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await;
+            }
+            result.map(|_| ())
+        }
+        .await;
+
+        if let Err(err) = &result {
+            event!(Level::WARN, "Error S3 Repository {:?}", err);
+        }
+        result
+    }
+
+    /// Copies the object server side, both buckets have to be reachable with the profile.
+    pub async fn copy_object(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        (source_bucket, source_key): (&S3Bucket, &str),
+        (bucket, key): (&S3Bucket, &str),
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .copy_object()
+            .bucket(&bucket.name)
+            .key(key)
+            .copy_source(create_copy_source(&source_bucket.name, source_key, None))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    pub async fn delete_object(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        key: &str,
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .delete_object()
+            .bucket(&bucket.name)
+            .key(key)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// A bucket that is not in the bucket list, like one of another profile, with its region.
+    pub async fn get_bucket(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket_name: &str,
+    ) -> S3Bucket {
+        let client = S3Repository::get_client(aws_config, sdk_config, None);
+
+        S3Bucket {
+            name: bucket_name.into(),
+            creation_date: None,
+            region: S3Repository::get_bucket_region(&client, bucket_name).await,
+        }
+    }
+
+    /// Deletes a single version permanently, removing a delete marker restores the object.
+    pub async fn delete_object_version(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        key: &str,
+        version_id: &str,
+    ) -> anyhow::Result<()> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+
+        match client
+            .delete_object()
+            .bucket(&bucket.name)
+            .key(key)
+            .version_id(version_id)
+            .send()
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(anyhow!(err))
+            }
+        }
+    }
+
+    /// Fetches one configuration section of the bucket, `Value::Null` if it is not configured.
+    pub async fn get_bucket_config(
+        aws_config: &AWSConfig,
+        sdk_config: &SdkConfig,
+        bucket: &S3Bucket,
+        section: S3BucketConfigSection,
+    ) -> anyhow::Result<Value> {
+        let client = S3Repository::get_client(aws_config, sdk_config, bucket.region.as_deref());
+        let bucket_name = bucket.name.as_str();
+
+        let result = match section {
+            S3BucketConfigSection::Versioning => client
+                .get_bucket_versioning()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!({
+                        // buckets that never had versioning enabled have no status
+                        "Status": response.status().map_or("Disabled", |status| status.as_str()),
+                        "MFADelete": response.mfa_delete().map(|mfa_delete| mfa_delete.as_str()),
+                    })
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::Encryption => client
+                .get_bucket_encryption()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!(response
+                        .server_side_encryption_configuration()
+                        .map(|configuration| configuration
+                            .rules()
+                            .iter()
+                            .map(encryption_rule_json)
+                            .collect::<Vec<Value>>()))
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::PublicAccessBlock => client
+                .get_public_access_block()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!(response
+                        .public_access_block_configuration()
+                        .map(|configuration| json!({
+                            "BlockPublicAcls": configuration.block_public_acls(),
+                            "IgnorePublicAcls": configuration.ignore_public_acls(),
+                            "BlockPublicPolicy": configuration.block_public_policy(),
+                            "RestrictPublicBuckets": configuration.restrict_public_buckets(),
+                        })))
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::Policy => client
+                .get_bucket_policy()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| json!(response.policy().map(policy_json)))
+                .map_err(map_config_error),
+            S3BucketConfigSection::Cors => client
+                .get_bucket_cors()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!(response
+                        .cors_rules()
+                        .iter()
+                        .map(cors_rule_json)
+                        .collect::<Vec<Value>>())
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::Lifecycle => client
+                .get_bucket_lifecycle_configuration()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!(response
+                        .rules()
+                        .iter()
+                        .map(lifecycle_rule_json)
+                        .collect::<Vec<Value>>())
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::Tags => client
+                .get_bucket_tagging()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| tags_json(response.tag_set()))
+                .map_err(map_config_error),
+            S3BucketConfigSection::Logging => client
+                .get_bucket_logging()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| {
+                    json!(response.logging_enabled().map(|logging| json!({
+                        "TargetBucket": logging.target_bucket(),
+                        "TargetPrefix": logging.target_prefix(),
+                    })))
+                })
+                .map_err(map_config_error),
+            S3BucketConfigSection::Replication => client
+                .get_bucket_replication()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .map(|response| json!(response.replication_configuration().map(replication_json)))
+                .map_err(map_config_error),
+        };
+
+        match result {
+            Ok(value) => Ok(remove_empty_fields(value)),
+            Err(None) => Ok(Value::Null),
+            Err(Some(err)) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                Err(err)
+            }
+        }
+    }
+
+    async fn get_bucket_region(client: &Client, bucket_name: &str) -> Option<String> {
+        match client
+            .get_bucket_location()
+            .bucket(bucket_name)
+            .send()
+            .await
+        {
+            Ok(response) => Some(
+                match response.location_constraint() {
+                    // buckets in us-east-1 have no location constraint, EU is the legacy name
+                    None => "us-east-1",
+                    Some(BucketLocationConstraint::Eu) => "eu-west-1",
+                    Some(location_constraint) if location_constraint.as_str().is_empty() => {
+                        "us-east-1"
+                    }
+                    Some(location_constraint) => location_constraint.as_str(),
+                }
+                .into(),
+            ),
+            Err(err) => {
+                event!(Level::WARN, "Error S3 Repository {:?}", err);
+                None
+            }
+        }
+    }
+
+    fn get_client(aws_config: &AWSConfig, sdk_config: &SdkConfig, region: Option<&str>) -> Client {
+        let mut client_builder = config::Builder::from(sdk_config);
+
+        if !aws_config.endpoint.is_empty() {
+            // local endpoints like LocalStack do not resolve virtual hosted buckets
+            client_builder = client_builder
+                .endpoint_url(&aws_config.endpoint)
+                .force_path_style(true);
+        }
+
+        if let Some(region) = region {
+            client_builder = client_builder.region(Region::new(region.to_string()));
+        }
+
+        Client::from_conf(client_builder.build())
+    }
+}
+
+/// A missing configuration is no error, it maps to `None`.
+fn map_config_error<E>(err: E) -> Option<anyhow::Error>
+where
+    E: ProvideErrorMetadata + std::error::Error + Send + Sync + 'static,
+{
+    if is_not_configured(err.code()) {
+        None
+    } else {
+        Some(anyhow!(err))
+    }
+}
+
+fn create_copy_source(bucket_name: &str, key: &str, version_id: Option<&str>) -> String {
+    let copy_source = format!(
+        "{}/{}",
+        bucket_name,
+        utf8_percent_encode(key, COPY_SOURCE_ENCODE_SET)
+    );
+
+    match version_id {
+        Some(version_id) => format!(
+            "{}?versionId={}",
+            copy_source,
+            utf8_percent_encode(version_id, COPY_SOURCE_ENCODE_SET)
+        ),
+        None => copy_source,
+    }
+}
+
+/// Grows the parts of large files so that they fit into the allowed number of parts.
+fn get_multipart_part_size(size: u64) -> u64 {
+    MULTIPART_PART_SIZE.max(size.div_ceil(MAX_MULTIPART_PARTS))
+}
+
+/// The inclusive byte ranges of the parts UploadPartCopy copies an object of `size` with.
+fn get_copy_source_ranges(size: u64) -> Vec<String> {
+    let part_size = get_multipart_part_size(size);
+
+    (0..size)
+        .step_by(part_size as usize)
+        .map(|start| format!("bytes={}-{}", start, (start + part_size).min(size) - 1))
+        .collect()
+}
+
+async fn run_cancellable<T>(
+    cancellation_token: &CancellationToken,
+    future: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    tokio::select! {
+        _ = cancellation_token.cancelled() => Err(anyhow!("Transfer cancelled")),
+        result = future => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_key_of_copy_source() {
+        assert_eq!(
+            create_copy_source(
+                "my-bucket",
+                "logs/2023/app log+1.txt",
+                Some("3/L4kqtJlcpX.Xb")
+            ),
+            "my-bucket/logs/2023/app%20log%2B1.txt?versionId=3/L4kqtJlcpX.Xb"
+        );
+        assert_eq!(
+            create_copy_source("my-bucket", "a b.txt", None),
+            "my-bucket/a%20b.txt"
+        );
+    }
+
+    #[test]
+    fn test_grow_multipart_part_size_with_file_size() {
+        assert_eq!(
+            get_multipart_part_size(20 * 1024 * 1024),
+            MULTIPART_PART_SIZE
+        );
+        assert_eq!(
+            get_multipart_part_size(MULTIPART_PART_SIZE * MAX_MULTIPART_PARTS),
+            MULTIPART_PART_SIZE
+        );
+        assert_eq!(
+            get_multipart_part_size(MULTIPART_PART_SIZE * MAX_MULTIPART_PARTS + 1),
+            MULTIPART_PART_SIZE + 1
+        );
+
+        let size: u64 = 200 * 1024 * 1024 * 1024;
+        assert!(size.div_ceil(get_multipart_part_size(size)) <= MAX_MULTIPART_PARTS);
+    }
+
+    #[test]
+    fn test_cover_object_with_copy_source_ranges() {
+        assert_eq!(
+            get_copy_source_ranges(2 * MULTIPART_PART_SIZE + 1),
+            vec![
+                "bytes=0-8388607",
+                "bytes=8388608-16777215",
+                "bytes=16777216-16777216"
+            ]
+        );
+        assert_eq!(get_copy_source_ranges(MAX_COPY_OBJECT_SIZE + 1).len(), 641);
+    }
+}
@@ -0,0 +1,217 @@
+use aws_sdk_s3::types::{
+    CorsRule, LifecycleRule, LifecycleRuleFilter, ReplicationConfiguration, ReplicationRuleFilter,
+    ServerSideEncryptionRule, Tag,
+};
+use serde_json::{json, Map, Value};
+
+/// Error codes S3 answers with when a bucket has no such configuration.
+const NOT_CONFIGURED_CODES: [&str; 7] = [
+    "NoSuchBucketPolicy",
+    "NoSuchCORSConfiguration",
+    "NoSuchLifecycleConfiguration",
+    "NoSuchPublicAccessBlockConfiguration",
+    "NoSuchTagSet",
+    "ReplicationConfigurationNotFoundError",
+    "ServerSideEncryptionConfigurationNotFoundError",
+];
+
+pub fn is_not_configured(error_code: Option<&str>) -> bool {
+    error_code.is_some_and(|error_code| NOT_CONFIGURED_CODES.contains(&error_code))
+}
+
+/// Drops unset fields and empty lists so that only the configured values are shown.
+pub fn remove_empty_fields(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| {
+                    !value.is_null() && value.as_array().is_none_or(|values| !values.is_empty())
+                })
+                .map(|(key, value)| (key, remove_empty_fields(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(remove_empty_fields).collect()),
+        value => value,
+    }
+}
+
+/// A bucket policy is a JSON document, it is shown as text if it cannot be parsed.
+pub fn policy_json(policy: &str) -> Value {
+    serde_json::from_str(policy).unwrap_or_else(|_| Value::String(policy.into()))
+}
+
+pub fn encryption_rule_json(rule: &ServerSideEncryptionRule) -> Value {
+    let default_encryption = rule.apply_server_side_encryption_by_default();
+
+    json!({
+        "SSEAlgorithm": default_encryption.map(|encryption| encryption.sse_algorithm().as_str()),
+        "KMSMasterKeyID": default_encryption.and_then(|encryption| encryption.kms_master_key_id()),
+        "BucketKeyEnabled": rule.bucket_key_enabled(),
+    })
+}
+
+pub fn cors_rule_json(rule: &CorsRule) -> Value {
+    json!({
+        "ID": rule.id(),
+        "AllowedOrigins": rule.allowed_origins(),
+        "AllowedMethods": rule.allowed_methods(),
+        "AllowedHeaders": rule.allowed_headers(),
+        "ExposeHeaders": rule.expose_headers(),
+        "MaxAgeSeconds": rule.max_age_seconds(),
+    })
+}
+
+// rules in the legacy format have a prefix instead of a filter
+#[allow(deprecated)]
+pub fn lifecycle_rule_json(rule: &LifecycleRule) -> Value {
+    let expiration = rule.expiration();
+    let noncurrent_version_expiration = rule.noncurrent_version_expiration();
+
+    json!({
+        "ID": rule.id(),
+        "Status": rule.status().as_str(),
+        "Prefix": rule.prefix(),
+        "Filter": rule.filter().map(lifecycle_filter_json),
+        "Expiration": expiration.map(|expiration| json!({
+            "Days": expiration.days(),
+            "Date": expiration.date().map(|date| date.to_string()),
+            "ExpiredObjectDeleteMarker": expiration.expired_object_delete_marker(),
+        })),
+        "Transitions": rule.transitions().iter().map(|transition| json!({
+            "Days": transition.days(),
+            "Date": transition.date().map(|date| date.to_string()),
+            "StorageClass": transition.storage_class().map(|storage_class| storage_class.as_str()),
+        })).collect::<Vec<Value>>(),
+        "NoncurrentVersionTransitions": rule.noncurrent_version_transitions().iter().map(|transition| json!({
+            "NoncurrentDays": transition.noncurrent_days(),
+            "NewerNoncurrentVersions": transition.newer_noncurrent_versions(),
+            "StorageClass": transition.storage_class().map(|storage_class| storage_class.as_str()),
+        })).collect::<Vec<Value>>(),
+        "NoncurrentVersionExpiration": noncurrent_version_expiration.map(|expiration| json!({
+            "NoncurrentDays": expiration.noncurrent_days(),
+            "NewerNoncurrentVersions": expiration.newer_noncurrent_versions(),
+        })),
+        "AbortIncompleteMultipartUpload": rule
+            .abort_incomplete_multipart_upload()
+            .map(|abort| json!({ "DaysAfterInitiation": abort.days_after_initiation() })),
+    })
+}
+
+#[allow(deprecated)]
+pub fn replication_json(replication: &ReplicationConfiguration) -> Value {
+    json!({
+        "Role": replication.role(),
+        "Rules": replication.rules().iter().map(|rule| {
+            let destination = rule.destination();
+            json!({
+                "ID": rule.id(),
+                "Priority": rule.priority(),
+                "Status": rule.status().as_str(),
+                "Prefix": rule.prefix(),
+                "Filter": rule.filter().map(replication_filter_json),
+                "DeleteMarkerReplication": rule
+                    .delete_marker_replication()
+                    .and_then(|replication| replication.status())
+                    .map(|status| status.as_str()),
+                "Destination": destination.map(|destination| json!({
+                    "Bucket": destination.bucket(),
+                    "Account": destination.account(),
+                    "StorageClass": destination
+                        .storage_class()
+                        .map(|storage_class| storage_class.as_str()),
+                })),
+            })
+        }).collect::<Vec<Value>>(),
+    })
+}
+
+pub fn tags_json(tags: &[Tag]) -> Value {
+    Value::Object(
+        tags.iter()
+            .map(|tag| (tag.key().to_string(), Value::String(tag.value().into())))
+            .collect::<Map<String, Value>>(),
+    )
+}
+
+fn lifecycle_filter_json(filter: &LifecycleRuleFilter) -> Value {
+    match filter {
+        LifecycleRuleFilter::Prefix(prefix) => json!({ "Prefix": prefix }),
+        LifecycleRuleFilter::Tag(tag) => json!({ "Tag": tags_json(std::slice::from_ref(tag)) }),
+        LifecycleRuleFilter::ObjectSizeGreaterThan(size) => {
+            json!({ "ObjectSizeGreaterThan": size })
+        }
+        LifecycleRuleFilter::ObjectSizeLessThan(size) => json!({ "ObjectSizeLessThan": size }),
+        LifecycleRuleFilter::And(operator) => json!({
+            "And": {
+                "Prefix": operator.prefix(),
+                "Tags": tags_json(operator.tags()),
+                "ObjectSizeGreaterThan": operator.object_size_greater_than(),
+                "ObjectSizeLessThan": operator.object_size_less_than(),
+            }
+        }),
+        _ => Value::String("unknown filter".into()),
+    }
+}
+
+fn replication_filter_json(filter: &ReplicationRuleFilter) -> Value {
+    match filter {
+        ReplicationRuleFilter::Prefix(prefix) => json!({ "Prefix": prefix }),
+        ReplicationRuleFilter::Tag(tag) => json!({ "Tag": tags_json(std::slice::from_ref(tag)) }),
+        ReplicationRuleFilter::And(operator) => json!({
+            "And": {
+                "Prefix": operator.prefix(),
+                "Tags": tags_json(operator.tags()),
+            }
+        }),
+        _ => Value::String("unknown filter".into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::types::{ExpirationStatus, LifecycleExpiration, LifecycleRuleAndOperator};
+
+    use super::*;
+
+    #[test]
+    fn test_detect_missing_configuration() {
+        assert!(is_not_configured(Some("NoSuchBucketPolicy")));
+        assert!(!is_not_configured(Some("AccessDenied")));
+        assert!(!is_not_configured(None));
+    }
+
+    #[test]
+    fn test_show_configured_lifecycle_fields_only() {
+        let rule = LifecycleRule::builder()
+            .id("expire-logs")
+            .status(ExpirationStatus::Enabled)
+            .filter(LifecycleRuleFilter::And(
+                LifecycleRuleAndOperator::builder()
+                    .prefix("logs/")
+                    .tags(Tag::builder().key("team").value("ops").build().unwrap())
+                    .build(),
+            ))
+            .expiration(LifecycleExpiration::builder().days(30).build())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            remove_empty_fields(lifecycle_rule_json(&rule)),
+            json!({
+                "ID": "expire-logs",
+                "Status": "Enabled",
+                "Filter": { "And": { "Prefix": "logs/", "Tags": { "team": "ops" } } },
+                "Expiration": { "Days": 30 },
+            })
+        );
+    }
+
+    #[test]
+    fn test_keep_unparsable_policy_as_text() {
+        assert_eq!(
+            policy_json("{\"Version\":\"2012-10-17\"}"),
+            json!({ "Version": "2012-10-17" })
+        );
+        assert_eq!(policy_json("not json"), json!("not json"));
+    }
+}
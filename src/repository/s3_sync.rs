@@ -0,0 +1,366 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use md5::{Digest, Md5};
+
+use crate::state::s3_state::{
+    S3Object, S3SyncComparison, S3SyncDirection, S3SyncEntry, S3SyncFile, S3SyncLocation,
+    S3SyncStatus, S3TransferOperation,
+};
+
+use super::s3_transfer::is_contained_path;
+
+/// The ETag of an object is its MD5 digest unless it was uploaded in parts.
+pub fn md5_from_e_tag(e_tag: &str) -> Option<String> {
+    let e_tag = e_tag.trim_matches('"');
+    (e_tag.len() == 32 && e_tag.chars().all(|char| char.is_ascii_hexdigit()))
+        .then(|| e_tag.to_lowercase())
+}
+
+/// The objects below `prefix` by their key relative to it.
+pub fn create_sync_files(objects: &[S3Object], prefix: &str) -> Vec<(String, S3SyncFile)> {
+    objects
+        .iter()
+        .filter_map(|object| {
+            let path = object.key.strip_prefix(prefix)?;
+            (!path.is_empty()).then(|| {
+                (
+                    path.to_string(),
+                    S3SyncFile {
+                        size: object.size.unwrap_or_default().max(0) as u64,
+                        md5: object.e_tag.as_deref().and_then(md5_from_e_tag),
+                    },
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn file_md5(path: &Path) -> Result<String> {
+    let mut reader =
+        BufReader::new(File::open(path).context(format!("Could not open file {:?}", path))?);
+    let mut hasher = Md5::new();
+    let mut buffer = [0; 64 * 1024];
+
+    loop {
+        let count = reader
+            .read(&mut buffer)
+            .context(format!("Could not read file {:?}", path))?;
+        if count == 0 {
+            break;
+        }
+        hasher.update(&buffer[..count]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Files differ by size or MD5 digest, files of the same size without known
+/// digest on both sides count as unchanged.
+pub fn compare_files(
+    source_files: Vec<(String, S3SyncFile)>,
+    target_files: Vec<(String, S3SyncFile)>,
+) -> Vec<S3SyncEntry> {
+    let mut files = BTreeMap::<String, (Option<S3SyncFile>, Option<S3SyncFile>)>::new();
+    for (path, file) in source_files {
+        files.entry(path).or_default().0 = Some(file);
+    }
+    for (path, file) in target_files {
+        files.entry(path).or_default().1 = Some(file);
+    }
+
+    files
+        .into_iter()
+        .map(|(path, (source, target))| {
+            let status = match (&source, &target) {
+                (Some(source), Some(target)) => {
+                    let is_changed = source.size != target.size
+                        || matches!((&source.md5, &target.md5), (Some(a), Some(b)) if a != b);
+                    if is_changed {
+                        S3SyncStatus::Changed
+                    } else {
+                        S3SyncStatus::Unchanged
+                    }
+                }
+                (Some(_), None) => S3SyncStatus::SourceOnly,
+                _ => S3SyncStatus::TargetOnly,
+            };
+
+            S3SyncEntry {
+                path,
+                source,
+                target,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// The operations that make the other side equal, files that only exist on the other
+/// side are deleted with `with_delete`. Entries of a local directory whose key would
+/// leave it, like `../file` or `/etc/file`, are skipped and returned as well.
+pub fn plan_sync(
+    comparison: &S3SyncComparison,
+    direction: S3SyncDirection,
+    with_delete: bool,
+) -> (Vec<S3TransferOperation>, Vec<String>) {
+    let source_bucket = &comparison.source_bucket;
+    let source_key = |path: &str| format!("{}{}", comparison.source_prefix, path);
+    let is_local = matches!(comparison.target, S3SyncLocation::Local { .. });
+    let (entries, skipped_entries) =
+        comparison
+            .entries
+            .iter()
+            .partition::<Vec<&S3SyncEntry>, _>(|entry| {
+                !is_local || is_contained_path(Path::new(&entry.path))
+            });
+
+    let operations = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let is_copied = match direction {
+                S3SyncDirection::SourceToTarget => entry.status == S3SyncStatus::SourceOnly,
+                S3SyncDirection::TargetToSource => entry.status == S3SyncStatus::TargetOnly,
+            } || entry.status == S3SyncStatus::Changed;
+            let is_deleted = with_delete
+                && match direction {
+                    S3SyncDirection::SourceToTarget => entry.status == S3SyncStatus::TargetOnly,
+                    S3SyncDirection::TargetToSource => entry.status == S3SyncStatus::SourceOnly,
+                };
+            let size = match direction {
+                S3SyncDirection::SourceToTarget => entry.source.as_ref(),
+                S3SyncDirection::TargetToSource => entry.target.as_ref(),
+            }
+            .map(|file| file.size)
+            .unwrap_or_default();
+
+            match (&comparison.target, &comparison.target_bucket, direction) {
+                (S3SyncLocation::Local { path }, _, S3SyncDirection::SourceToTarget) => {
+                    let local_path = path.join(&entry.path);
+                    if is_copied {
+                        Some(S3TransferOperation::Download {
+                            bucket: source_bucket.clone(),
+                            key: source_key(&entry.path),
+                            path: local_path,
+                            size,
+                        })
+                    } else {
+                        is_deleted.then_some(S3TransferOperation::DeleteFile { path: local_path })
+                    }
+                }
+                (S3SyncLocation::Local { path }, _, S3SyncDirection::TargetToSource) => {
+                    if is_copied {
+                        Some(S3TransferOperation::Upload {
+                            path: path.join(&entry.path),
+                            bucket: source_bucket.clone(),
+                            key: source_key(&entry.path),
+                            size,
+                        })
+                    } else {
+                        is_deleted.then(|| S3TransferOperation::DeleteObject {
+                            bucket: source_bucket.clone(),
+                            key: source_key(&entry.path),
+                        })
+                    }
+                }
+                (S3SyncLocation::S3 { prefix, .. }, Some(target_bucket), direction) => {
+                    let target_key = format!("{}{}", prefix, entry.path);
+                    let (from, to) = match direction {
+                        S3SyncDirection::SourceToTarget => (
+                            (source_bucket, source_key(&entry.path)),
+                            (target_bucket, target_key),
+                        ),
+                        S3SyncDirection::TargetToSource => (
+                            (target_bucket, target_key),
+                            (source_bucket, source_key(&entry.path)),
+                        ),
+                    };
+                    if is_copied {
+                        Some(S3TransferOperation::Copy {
+                            source_bucket: from.0.clone(),
+                            source_key: from.1,
+                            bucket: to.0.clone(),
+                            key: to.1,
+                            size,
+                        })
+                    } else {
+                        is_deleted.then(|| S3TransferOperation::DeleteObject {
+                            bucket: to.0.clone(),
+                            key: to.1,
+                        })
+                    }
+                }
+                (S3SyncLocation::S3 { .. }, None, _) => None,
+            }
+        })
+        .collect();
+
+    (
+        operations,
+        skipped_entries
+            .into_iter()
+            .map(|entry| entry.path.clone())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use assert_fs::prelude::*;
+
+    use crate::state::s3_state::S3Bucket;
+
+    use super::*;
+
+    fn create_file(size: u64, md5: Option<&str>) -> S3SyncFile {
+        S3SyncFile {
+            size,
+            md5: md5.map(|md5| md5.into()),
+        }
+    }
+
+    fn create_comparison() -> S3SyncComparison {
+        S3SyncComparison {
+            source_bucket: S3Bucket {
+                name: "my-bucket".into(),
+                ..S3Bucket::default()
+            },
+            source_prefix: "site/".into(),
+            target: S3SyncLocation::Local {
+                path: PathBuf::from("/data/site"),
+            },
+            target_bucket: None,
+            entries: compare_files(
+                vec![
+                    ("index.html".into(), create_file(10, Some("a"))),
+                    ("old.html".into(), create_file(5, None)),
+                ],
+                vec![
+                    ("index.html".into(), create_file(10, Some("b"))),
+                    ("new.css".into(), create_file(7, None)),
+                ],
+            ),
+        }
+    }
+
+    #[test]
+    fn test_read_md5_of_single_part_e_tags_only() {
+        assert_eq!(
+            md5_from_e_tag("\"9E107D9D372BB6826BD81D3542A419D6\""),
+            Some("9e107d9d372bb6826bd81d3542a419d6".into())
+        );
+        assert_eq!(
+            md5_from_e_tag("\"d41d8cd98f00b204e9800998ecf8427e-2\""),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compare_by_size_and_md5() {
+        let entries = compare_files(
+            vec![
+                ("a".into(), create_file(1, Some("x"))),
+                ("b".into(), create_file(1, None)),
+                ("c".into(), create_file(1, None)),
+            ],
+            vec![
+                ("a".into(), create_file(1, Some("y"))),
+                ("b".into(), create_file(1, Some("y"))),
+                ("d".into(), create_file(1, None)),
+            ],
+        );
+
+        assert_eq!(
+            entries
+                .iter()
+                .map(|entry| (entry.path.as_str(), entry.status))
+                .collect::<Vec<(&str, S3SyncStatus)>>(),
+            vec![
+                ("a", S3SyncStatus::Changed),
+                ("b", S3SyncStatus::Unchanged),
+                ("c", S3SyncStatus::SourceOnly),
+                ("d", S3SyncStatus::TargetOnly),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_download_and_delete_of_local_files() {
+        let comparison = create_comparison();
+
+        assert_eq!(
+            plan_sync(&comparison, S3SyncDirection::SourceToTarget, true).0,
+            vec![
+                S3TransferOperation::Download {
+                    bucket: comparison.source_bucket.clone(),
+                    key: "site/index.html".into(),
+                    path: PathBuf::from("/data/site/index.html"),
+                    size: 10,
+                },
+                S3TransferOperation::DeleteFile {
+                    path: PathBuf::from("/data/site/new.css"),
+                },
+                S3TransferOperation::Download {
+                    bucket: comparison.source_bucket.clone(),
+                    key: "site/old.html".into(),
+                    path: PathBuf::from("/data/site/old.html"),
+                    size: 5,
+                },
+            ]
+        );
+        assert_eq!(
+            plan_sync(&comparison, S3SyncDirection::TargetToSource, false)
+                .0
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_sync_skips_keys_outside_of_the_local_directory() {
+        let objects = ["site/../../.bashrc", "site//etc/x", "site/a.txt"]
+            .into_iter()
+            .map(|key| S3Object {
+                key: key.into(),
+                size: Some(1),
+                ..S3Object::default()
+            })
+            .collect::<Vec<S3Object>>();
+        let comparison = S3SyncComparison {
+            entries: compare_files(create_sync_files(&objects, "site/"), vec![]),
+            ..create_comparison()
+        };
+
+        let (operations, skipped_paths) =
+            plan_sync(&comparison, S3SyncDirection::SourceToTarget, true);
+        assert_eq!(
+            operations,
+            vec![S3TransferOperation::Download {
+                bucket: comparison.source_bucket.clone(),
+                key: "site/a.txt".into(),
+                path: PathBuf::from("/data/site/a.txt"),
+                size: 1,
+            }]
+        );
+        assert_eq!(skipped_paths, vec!["../../.bashrc", "/etc/x"]);
+    }
+
+    #[test]
+    fn test_hash_file_content() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let file = temp_dir.child("a.txt");
+        file.write_str("abc").unwrap();
+
+        assert_eq!(
+            file_md5(file.path()).unwrap(),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::state::s3_state::S3Object;
+
+/// One file of a download or upload, a transfer of a prefix or directory has many.
+#[derive(Clone, Debug, PartialEq)]
+pub struct S3TransferItem {
+    pub key: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// Whether the path stays below the directory it is joined to, which paths of keys
+/// like `../file` or `/etc/file` would leave.
+pub fn is_contained_path(relative_path: &Path) -> bool {
+    relative_path.components().count() > 0
+        && relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+/// A download or export is written to this hidden file next to its target and only renamed
+/// to the target once complete, so an existing file survives a failed or cancelled one.
+pub fn get_partial_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.part", file_name))
+}
+
+/// Objects are stored below `local_dir` with their key relative to `base_prefix`.
+pub fn plan_download(
+    objects: &[S3Object],
+    base_prefix: &str,
+    local_dir: &Path,
+) -> Vec<S3TransferItem> {
+    objects
+        .iter()
+        .filter_map(|object| {
+            let relative_key = object.key.strip_prefix(base_prefix).unwrap_or(&object.key);
+            let relative_path = relative_key
+                .split('/')
+                .filter(|part| !part.is_empty())
+                .collect::<PathBuf>();
+
+            is_contained_path(&relative_path).then(|| S3TransferItem {
+                key: object.key.clone(),
+                path: local_dir.join(relative_path),
+                size: object.size.unwrap_or_default().max(0) as u64,
+            })
+        })
+        .collect()
+}
+
+/// A file is uploaded into `prefix`, a directory with all nested files below `prefix/<name>/`.
+pub fn plan_upload(local_path: &Path, prefix: &str) -> Result<Vec<S3TransferItem>> {
+    let name = local_path
+        .file_name()
+        .context(format!("{:?} has no file name", local_path))?
+        .to_string_lossy();
+    let mut transfer_items = vec![];
+    collect_files(
+        local_path,
+        &format!("{}{}", prefix, name),
+        &mut transfer_items,
+    )?;
+    transfer_items.sort_by(|a, b| a.key.cmp(&b.key));
+
+    Ok(transfer_items)
+}
+
+/// The files below `local_dir` with their path relative to it as key, none if it
+/// does not exist yet.
+pub fn list_local_files(local_dir: &Path) -> Result<Vec<S3TransferItem>> {
+    if !local_dir.exists() {
+        return Ok(vec![]);
+    }
+    if !local_dir.is_dir() {
+        bail!("{:?} is no directory", local_dir);
+    }
+
+    let mut transfer_items = vec![];
+    collect_files(local_dir, "", &mut transfer_items)?;
+    for transfer_item in transfer_items.iter_mut() {
+        transfer_item.key = transfer_item.key.trim_start_matches('/').into();
+    }
+    transfer_items.sort_by(|a, b| a.key.cmp(&b.key));
+
+    Ok(transfer_items)
+}
+
+fn collect_files(path: &Path, key: &str, transfer_items: &mut Vec<S3TransferItem>) -> Result<()> {
+    let metadata = fs::metadata(path).context(format!("Could not read {:?}", path))?;
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).context(format!("Could not read directory {:?}", path))? {
+            let entry = entry.context(format!("Could not read directory {:?}", path))?;
+            collect_files(
+                &entry.path(),
+                &format!("{}/{}", key, entry.file_name().to_string_lossy()),
+                transfer_items,
+            )?;
+        }
+    } else {
+        transfer_items.push(S3TransferItem {
+            key: key.to_string(),
+            path: path.to_path_buf(),
+            size: metadata.len(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_fs::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_download_keeps_folders_below_base_prefix() {
+        let objects = vec![
+            S3Object {
+                key: "logs/2023/app.log".into(),
+                size: Some(10),
+                ..S3Object::default()
+            },
+            S3Object {
+                key: "logs/../../etc/passwd".into(),
+                size: Some(20),
+                ..S3Object::default()
+            },
+        ];
+
+        assert_eq!(
+            plan_download(&objects, "logs/", Path::new("/tmp/download")),
+            vec![S3TransferItem {
+                key: "logs/2023/app.log".into(),
+                path: PathBuf::from("/tmp/download/2023/app.log"),
+                size: 10,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_download_to_hidden_file_next_to_target() {
+        assert_eq!(
+            get_partial_path(Path::new("/tmp/download/app.log")),
+            PathBuf::from("/tmp/download/.app.log.part")
+        );
+    }
+
+    #[test]
+    fn test_upload_of_directory_includes_nested_files() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        temp_dir.child("data/a.txt").write_str("abc").unwrap();
+        temp_dir.child("data/nested/b.txt").write_str("de").unwrap();
+
+        let transfer_items = plan_upload(&temp_dir.path().join("data"), "backup/").unwrap();
+
+        assert_eq!(
+            transfer_items
+                .iter()
+                .map(|item| (item.key.as_str(), item.size))
+                .collect::<Vec<(&str, u64)>>(),
+            vec![("backup/data/a.txt", 3), ("backup/data/nested/b.txt", 2)]
+        );
+        temp_dir.close().unwrap();
+    }
+}
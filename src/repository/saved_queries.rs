@@ -0,0 +1,55 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::state::cloud_watch_logs_state::CloudWatchSavedQuery;
+
+pub fn load_saved_queries(path: &Path) -> Result<Vec<CloudWatchSavedQuery>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(path)
+        .context(format!("Could not read saved queries file {:?}", path))?;
+    serde_json::from_str(&content).context(format!("Could not parse saved queries file {:?}", path))
+}
+
+pub fn store_saved_queries(path: &Path, saved_queries: &[CloudWatchSavedQuery]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!("Could not create directory {:?}", parent))?;
+    }
+
+    let content =
+        serde_json::to_string_pretty(saved_queries).context("Could not serialize saved queries")?;
+    fs::write(path, content).context(format!("Could not write saved queries file {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file_has_no_saved_queries() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+
+        let saved_queries = load_saved_queries(&temp_dir.path().join("missing.json")).unwrap();
+
+        assert!(saved_queries.is_empty());
+    }
+
+    #[test]
+    fn test_saved_queries_round_trip() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("saved_queries.json");
+        let saved_queries = vec![CloudWatchSavedQuery {
+            name: "errors".into(),
+            query_string: "fields @timestamp, @message\n| filter @message like /ERROR/".into(),
+            log_group_names: vec!["/aws/lambda/one".into(), "/aws/lambda/two".into()],
+        }];
+
+        store_saved_queries(&path, &saved_queries).unwrap();
+
+        assert_eq!(load_saved_queries(&path).unwrap(), saved_queries);
+        temp_dir.close().unwrap();
+    }
+}
@@ -1,4 +1,8 @@
 pub mod action_handlers;
 pub mod appstate;
 pub mod cloud_watch_logs_state;
+pub mod dynamodb_state;
+pub mod ec2_state;
+pub mod eks_state;
 pub mod manager;
+pub mod s3_state;
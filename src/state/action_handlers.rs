@@ -1,5 +1,9 @@
 pub mod actions;
 pub mod cloud_watch_logs_action_handler;
+pub mod dynamodb_action_handler;
+pub mod ec2_action_handler;
+pub mod eks_action_handler;
 pub mod profile_action_handler;
 pub mod region_action_handler;
+pub mod s3_action_handler;
 pub mod service_action_handler;
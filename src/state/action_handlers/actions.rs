@@ -1,8 +1,18 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use ratatui::layout::Rect;
+use serde_json::Value;
 
-use crate::state::appstate::{AWSService, ComponentType, MenuItem, ProfileSource};
+use crate::state::{
+    appstate::{AWSService, ComponentType, MenuItem, ProfileSource, ProgressState},
+    cloud_watch_logs_state::{
+        CloudWatchExportFormat, CloudWatchInsightsResults, CloudWatchLogEvent,
+        CloudWatchLogGroupNameFilter, CloudWatchSavedQuery,
+    },
+    dynamodb_state::{DynamoDbExportFormat, DynamoDbQuery},
+    ec2_state::Ec2InstanceCommand,
+    s3_state::{S3BucketConfigSection, S3PresignMethod, S3SyncDirection, S3SyncLocation},
+};
 
 #[derive(Debug, Clone)]
 pub enum ProfileAction {
@@ -21,7 +31,222 @@ pub enum ServiceAction {
 
 #[derive(Debug, Clone)]
 pub enum CloudWatchLogsAction {
-    GetLogGroups { token: Option<String> },
+    GetLogGroups {
+        token: Option<String>,
+        name_filter: Option<CloudWatchLogGroupNameFilter>,
+    },
+    SelectLogGroup {
+        log_group_name: String,
+    },
+    GetMoreLogStreams {
+        token: String,
+    },
+    DeselectLogGroup,
+    SetLogGroupRetention {
+        log_group_name: String,
+        retention_days: Option<i32>,
+    },
+    DeleteLogGroup {
+        log_group_name: String,
+    },
+    GetLogGroupFilters {
+        log_group_name: String,
+    },
+    TestFilterPattern {
+        filter_pattern: String,
+        log_event_messages: Vec<String>,
+    },
+    SelectLogStream {
+        log_stream_name: String,
+    },
+    DeselectLogStream,
+    StartLiveTail {
+        log_stream_names: Vec<String>,
+    },
+    StopLiveTail,
+    AppendLogEvents {
+        live_tail_id: usize,
+        log_events: Vec<CloudWatchLogEvent>,
+    },
+    LiveTailError {
+        backtrace: String,
+    },
+    ExportLogEvents {
+        path: PathBuf,
+        format: CloudWatchExportFormat,
+        time_range: Option<(i64, i64)>,
+    },
+    ExportFinished {
+        path: PathBuf,
+        log_event_count: u64,
+    },
+    ExportError {
+        backtrace: String,
+    },
+    RunInsightsQuery {
+        log_group_names: Vec<String>,
+        query_string: String,
+        time_range: (i64, i64),
+    },
+    CancelInsightsQuery,
+    SetInsightsResults {
+        query_id: String,
+        results: CloudWatchInsightsResults,
+    },
+    InsightsQueryError {
+        backtrace: String,
+    },
+    LoadSavedQueries,
+    SaveQuery {
+        saved_query: CloudWatchSavedQuery,
+    },
+    DeleteSavedQuery {
+        name: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum EksAction {
+    GetClusters,
+    SelectCluster { cluster_name: String },
+    WriteKubeconfig { cluster_name: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum Ec2Action {
+    GetInstances,
+    GetNetworkSecurity,
+    GetVpcs,
+    GetStorage,
+    CreateSnapshot {
+        volume_id: String,
+    },
+    DeregisterImage {
+        image_id: String,
+    },
+    DeleteSnapshot {
+        snapshot_id: String,
+    },
+    ChangeInstanceState {
+        instance_id: String,
+        command: Ec2InstanceCommand,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum DynamoDbAction {
+    GetTables,
+    FetchItems {
+        query: Box<DynamoDbQuery>,
+    },
+    FetchNextItems,
+    SaveItem {
+        table_name: String,
+        key_names: Vec<String>,
+        original_item: Option<Value>,
+        item: Value,
+    },
+    DeleteItem {
+        table_name: String,
+        key: Value,
+    },
+    ExecuteStatement {
+        statement: String,
+    },
+    FetchNextStatementItems,
+    ExportItems {
+        path: PathBuf,
+        format: DynamoDbExportFormat,
+    },
+    ExportFinished {
+        path: PathBuf,
+        item_count: u64,
+    },
+    ExportError {
+        backtrace: String,
+    },
+    ImportItems {
+        table_name: String,
+        path: PathBuf,
+    },
+    ImportFinished {
+        table_name: String,
+        path: PathBuf,
+        item_count: u64,
+    },
+    ImportError {
+        backtrace: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum S3Action {
+    GetBuckets,
+    SelectBucket {
+        bucket_name: String,
+    },
+    DeselectBucket,
+    GetBucketConfig {
+        bucket_name: String,
+        section: S3BucketConfigSection,
+    },
+    ListObjects {
+        prefix: String,
+        token: Option<String>,
+    },
+    PreviewObject {
+        key: String,
+        version_id: Option<String>,
+    },
+    ClosePreview,
+    PresignObject {
+        key: String,
+        method: S3PresignMethod,
+        expires_in: Duration,
+    },
+    CompareSync {
+        target: S3SyncLocation,
+    },
+    ApplySync {
+        direction: S3SyncDirection,
+        with_delete: bool,
+    },
+    CloseSync,
+    GetObjectVersions {
+        key: Option<String>,
+    },
+    RestoreObjectVersion {
+        key: String,
+        version_id: String,
+    },
+    DeleteObjectVersion {
+        key: String,
+        version_id: String,
+    },
+    DownloadObject {
+        key: String,
+        version_id: Option<String>,
+        local_dir: PathBuf,
+    },
+    UploadPath {
+        local_path: PathBuf,
+    },
+    CancelTransfer {
+        transfer_id: usize,
+    },
+    ClearTransfers,
+    SetTransferProgress {
+        transfer_id: usize,
+        bytes_done: u64,
+        bytes_total: Option<u64>,
+    },
+    TransferFinished {
+        transfer_id: usize,
+    },
+    TransferError {
+        transfer_id: usize,
+        backtrace: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -31,9 +256,14 @@ pub enum Action {
     SetTerminalArea { area: Rect },
     ToggleSidePane,
     SetBreadcrumbs { breadcrumbs: Vec<String> },
+    SetProgress { progress: Option<ProgressState> },
     RenderDuration { duration: Duration },
     Profile { action: ProfileAction },
     Region { action: RegionAction },
     Service { action: ServiceAction },
     CloudWatchLogs { action: CloudWatchLogsAction },
+    S3 { action: S3Action },
+    Ec2 { action: Ec2Action },
+    Eks { action: EksAction },
+    DynamoDb { action: DynamoDbAction },
 }
@@ -1,47 +1,1216 @@
+use std::{path::PathBuf, time::Duration};
+
+use aws_config::SdkConfig;
+use chrono::Utc;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
 use tracing::{event, Level};
 
 use crate::{
-    repository::cloud_watch_logs::CloudWatchLogsRepository,
-    state::{appstate::AppState, cloud_watch_logs_state::CloudWatchState},
+    config::app_config::{get_saved_queries_path, AWSConfig},
+    repository::{
+        cloud_watch_logs::CloudWatchLogsRepository,
+        live_tail::LiveTailCursor,
+        log_event_export::{remove_partial_file, LogEventExport},
+        saved_queries::{load_saved_queries, store_saved_queries},
+    },
+    state::{
+        appstate::{AppState, ProgressState},
+        cloud_watch_logs_state::{
+            CloudWatchExportFormat, CloudWatchInsightsResults, CloudWatchInsightsState,
+            CloudWatchLiveTail, CloudWatchLogEvent, CloudWatchLogGroup, CloudWatchLogGroupFilters,
+            CloudWatchLogGroupNameFilter, CloudWatchLogStream, CloudWatchSavedQuery,
+            CloudWatchState,
+        },
+    },
     ui::tui_config::TUI_CONFIG,
 };
 
-use super::actions::CloudWatchLogsAction;
+use super::actions::{Action, CloudWatchLogsAction};
 
 pub struct CloudWatchLogsActionHandler;
 
 impl CloudWatchLogsActionHandler {
-    pub async fn handle(action: CloudWatchLogsAction, app_state: &mut AppState) {
+    pub async fn handle(
+        action: CloudWatchLogsAction,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
         match action {
-            CloudWatchLogsAction::GetLogGroups { token } => {
-                CloudWatchLogsActionHandler::handle_get_log_groups(token, app_state).await;
+            CloudWatchLogsAction::GetLogGroups {
+                token: Some(token), ..
+            } => {
+                CloudWatchLogsActionHandler::handle_get_more_log_groups(token, app_state).await;
+            }
+            CloudWatchLogsAction::GetLogGroups {
+                token: None,
+                name_filter,
+            } => {
+                CloudWatchLogsActionHandler::handle_get_log_groups(name_filter, app_state).await;
+            }
+            CloudWatchLogsAction::SetLogGroupRetention {
+                log_group_name,
+                retention_days,
+            } => {
+                CloudWatchLogsActionHandler::handle_set_log_group_retention(
+                    log_group_name,
+                    retention_days,
+                    app_state,
+                )
+                .await;
+            }
+            CloudWatchLogsAction::DeleteLogGroup { log_group_name } => {
+                CloudWatchLogsActionHandler::handle_delete_log_group(log_group_name, app_state)
+                    .await;
+            }
+            CloudWatchLogsAction::GetLogGroupFilters { log_group_name } => {
+                CloudWatchLogsActionHandler::handle_get_log_group_filters(
+                    log_group_name,
+                    app_state,
+                )
+                .await;
+            }
+            CloudWatchLogsAction::TestFilterPattern {
+                filter_pattern,
+                log_event_messages,
+            } => {
+                CloudWatchLogsActionHandler::handle_test_filter_pattern(
+                    &filter_pattern,
+                    &log_event_messages,
+                    app_state,
+                )
+                .await;
+            }
+            CloudWatchLogsAction::SelectLogGroup { log_group_name } => {
+                CloudWatchLogsActionHandler::handle_select_log_group(log_group_name, app_state)
+                    .await;
+            }
+            CloudWatchLogsAction::GetMoreLogStreams { token } => {
+                CloudWatchLogsActionHandler::handle_get_more_log_streams(token, app_state).await;
+            }
+            CloudWatchLogsAction::DeselectLogGroup => {
+                CloudWatchLogsActionHandler::stop_live_tail(app_state);
+                app_state.cloud_watch_state.live_tail = None;
+                app_state.cloud_watch_state.selected_log_group = None;
+                app_state.cloud_watch_state.selected_log_stream = None;
+                app_state.cloud_watch_state.log_events = vec![];
+            }
+            CloudWatchLogsAction::SelectLogStream { log_stream_name } => {
+                CloudWatchLogsActionHandler::handle_select_log_stream(log_stream_name, app_state)
+                    .await;
+            }
+            CloudWatchLogsAction::DeselectLogStream => {
+                CloudWatchLogsActionHandler::stop_live_tail(app_state);
+                app_state.cloud_watch_state.live_tail = None;
+                app_state.cloud_watch_state.selected_log_stream = None;
+                app_state.cloud_watch_state.log_events = vec![];
+            }
+            CloudWatchLogsAction::StartLiveTail { log_stream_names } => {
+                CloudWatchLogsActionHandler::handle_start_live_tail(
+                    log_stream_names,
+                    app_state,
+                    task_tx,
+                );
+            }
+            CloudWatchLogsAction::StopLiveTail => {
+                CloudWatchLogsActionHandler::stop_live_tail(app_state);
+            }
+            CloudWatchLogsAction::AppendLogEvents {
+                live_tail_id,
+                log_events,
+            } => {
+                CloudWatchLogsActionHandler::handle_append_log_events(
+                    live_tail_id,
+                    log_events,
+                    app_state,
+                );
+            }
+            CloudWatchLogsAction::LiveTailError { backtrace } => {
+                CloudWatchLogsActionHandler::stop_live_tail(app_state);
+                app_state.status_state.err_message = TUI_CONFIG.messages.error_live_tail.into();
+                app_state.status_state.err_message_backtrace = backtrace;
+            }
+            CloudWatchLogsAction::ExportLogEvents {
+                path,
+                format,
+                time_range,
+            } => {
+                CloudWatchLogsActionHandler::handle_export_log_events(
+                    path, format, time_range, app_state, task_tx,
+                );
+            }
+            CloudWatchLogsAction::ExportFinished {
+                path,
+                log_event_count,
+            } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.message = format!(
+                    "{} {} ({} events)",
+                    TUI_CONFIG.messages.log_events_exported,
+                    path.display(),
+                    log_event_count
+                );
+                app_state.status_state.err_message = String::default();
+            }
+            CloudWatchLogsAction::ExportError { backtrace } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.err_message =
+                    TUI_CONFIG.messages.error_export_log_events.into();
+                app_state.status_state.err_message_backtrace = backtrace;
+            }
+            CloudWatchLogsAction::RunInsightsQuery {
+                log_group_names,
+                query_string,
+                time_range,
+            } => {
+                CloudWatchLogsActionHandler::handle_run_insights_query(
+                    &log_group_names,
+                    &query_string,
+                    time_range,
+                    app_state,
+                    task_tx,
+                )
+                .await;
+            }
+            CloudWatchLogsAction::CancelInsightsQuery => {
+                CloudWatchLogsActionHandler::handle_cancel_insights_query(app_state).await;
+            }
+            CloudWatchLogsAction::SetInsightsResults { query_id, results } => {
+                CloudWatchLogsActionHandler::handle_set_insights_results(
+                    query_id, results, app_state,
+                );
+            }
+            CloudWatchLogsAction::InsightsQueryError { backtrace } => {
+                CloudWatchLogsActionHandler::stop_insights_query_poll(app_state);
+                app_state.cloud_watch_state.insights.status = "Failed".into();
+                app_state.status_state.err_message =
+                    TUI_CONFIG.messages.error_insights_query.into();
+                app_state.status_state.err_message_backtrace = backtrace;
+            }
+            CloudWatchLogsAction::LoadSavedQueries => {
+                match load_saved_queries(&get_saved_queries_path()) {
+                    Ok(saved_queries) => {
+                        app_state.cloud_watch_state.insights.saved_queries = saved_queries
+                    }
+                    Err(err) => {
+                        CloudWatchLogsActionHandler::set_saved_queries_error(err, app_state)
+                    }
+                }
+            }
+            CloudWatchLogsAction::SaveQuery { saved_query } => {
+                CloudWatchLogsActionHandler::handle_save_query(saved_query, app_state);
+            }
+            CloudWatchLogsAction::DeleteSavedQuery { name } => {
+                let saved_queries = &mut app_state.cloud_watch_state.insights.saved_queries;
+                saved_queries.retain(|saved_query| saved_query.name != name);
+                if let Err(err) = store_saved_queries(&get_saved_queries_path(), saved_queries) {
+                    CloudWatchLogsActionHandler::set_saved_queries_error(err, app_state);
+                }
             }
         }
     }
 
-    async fn handle_get_log_groups(next_token: Option<String>, app_state: &mut AppState) {
+    async fn handle_get_log_groups(
+        name_filter: Option<CloudWatchLogGroupNameFilter>,
+        app_state: &mut AppState,
+    ) {
+        CloudWatchLogsActionHandler::stop_live_tail(app_state);
+        CloudWatchLogsActionHandler::stop_insights_query_poll(app_state);
+
         if let Some(profile) = &app_state.active_profile {
             match CloudWatchLogsRepository::describe_log_groups(
                 &app_state.aws_config,
                 &profile.sdk_config,
-                next_token,
+                name_filter.as_ref(),
+                None,
             )
             .await
             {
-                Ok(log_groups) => {
+                Ok((log_groups, log_groups_next_token)) => {
                     app_state.cloud_watch_state = CloudWatchState {
                         log_groups,
-                        selected_log_group: None,
+                        log_groups_next_token,
+                        log_group_name_filter: name_filter,
+                        next_live_tail_id: app_state.cloud_watch_state.next_live_tail_id,
+                        insights: CloudWatchInsightsState {
+                            saved_queries: app_state
+                                .cloud_watch_state
+                                .insights
+                                .saved_queries
+                                .clone(),
+                            ..CloudWatchInsightsState::default()
+                        },
+                        ..CloudWatchState::default()
                     }
                 }
-                Err(_) => {
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_cloud_watch_log_groups
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.cloud_watch_state = CloudWatchState {
+                        next_live_tail_id: app_state.cloud_watch_state.next_live_tail_id,
+                        ..CloudWatchState::default()
+                    }
+                }
+            };
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_get_more_log_groups(next_token: String, app_state: &mut AppState) {
+        // a page is requested once, repeated requests for an already loaded token are dropped
+        if app_state.cloud_watch_state.log_groups_next_token.as_ref() != Some(&next_token) {
+            return;
+        }
+
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::describe_log_groups(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                app_state.cloud_watch_state.log_group_name_filter.as_ref(),
+                Some(next_token.clone()),
+            )
+            .await
+            {
+                Ok(page) => CloudWatchLogsActionHandler::append_log_groups(
+                    &mut app_state.cloud_watch_state,
+                    &next_token,
+                    page,
+                ),
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_cloud_watch_log_groups
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            };
+        }
+    }
+
+    /// A page is appended once, the name filter it was requested with stays in place.
+    fn append_log_groups(
+        cloud_watch_state: &mut CloudWatchState,
+        next_token: &str,
+        (log_groups, log_groups_next_token): (Vec<CloudWatchLogGroup>, Option<String>),
+    ) {
+        if cloud_watch_state.log_groups_next_token.as_deref() == Some(next_token) {
+            cloud_watch_state.log_groups.extend(log_groups);
+            cloud_watch_state.log_groups_next_token = log_groups_next_token;
+        }
+    }
+
+    async fn handle_set_log_group_retention(
+        log_group_name: String,
+        retention_days: Option<i32>,
+        app_state: &mut AppState,
+    ) {
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::put_retention_policy(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                &log_group_name,
+                retention_days,
+            )
+            .await
+            {
+                Ok(_) => {
+                    if let Some(log_group) = app_state
+                        .cloud_watch_state
+                        .log_groups
+                        .iter_mut()
+                        .find(|log_group| log_group.name.as_ref() == Some(&log_group_name))
+                    {
+                        log_group.retention_days = retention_days;
+                    }
+                    app_state.status_state.message =
+                        TUI_CONFIG.messages.log_group_retention_updated.into();
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_put_cloud_watch_retention_policy
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_delete_log_group(log_group_name: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::delete_log_group(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                &log_group_name,
+            )
+            .await
+            {
+                Ok(_) => {
+                    app_state
+                        .cloud_watch_state
+                        .log_groups
+                        .retain(|log_group| log_group.name.as_ref() != Some(&log_group_name));
+                    app_state.status_state.message = TUI_CONFIG.messages.log_group_deleted.into();
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_delete_cloud_watch_log_group
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_get_log_group_filters(log_group_name: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            let result = tokio::try_join!(
+                CloudWatchLogsRepository::describe_metric_filters(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    &log_group_name,
+                ),
+                CloudWatchLogsRepository::describe_subscription_filters(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    &log_group_name,
+                )
+            );
+
+            match result {
+                Ok((metric_filters, subscription_filters)) => {
+                    app_state.cloud_watch_state.log_group_filters =
+                        Some(CloudWatchLogGroupFilters {
+                            log_group_name,
+                            metric_filters,
+                            subscription_filters,
+                            test_matches: None,
+                        });
+                }
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_cloud_watch_filters
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.cloud_watch_state.log_group_filters = None;
+                }
+            }
+        }
+    }
+
+    async fn handle_test_filter_pattern(
+        filter_pattern: &str,
+        log_event_messages: &[String],
+        app_state: &mut AppState,
+    ) {
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::test_metric_filter(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                filter_pattern,
+                log_event_messages,
+            )
+            .await
+            {
+                Ok(test_matches) => {
+                    if let Some(log_group_filters) =
+                        &mut app_state.cloud_watch_state.log_group_filters
+                    {
+                        log_group_filters.test_matches = Some(test_matches);
+                    }
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_test_metric_filter.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_select_log_group(log_group_name: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            let log_group = app_state
+                .cloud_watch_state
+                .log_groups
+                .iter()
+                .find(|log_group| log_group.name.as_ref() == Some(&log_group_name))
+                .cloned()
+                .unwrap_or_default();
+
+            match CloudWatchLogsRepository::describe_log_streams(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                &log_group_name,
+                None,
+            )
+            .await
+            {
+                Ok((log_streams, log_streams_next_token)) => {
+                    app_state.cloud_watch_state.selected_log_group = Some(CloudWatchLogGroup {
+                        log_streams,
+                        log_streams_next_token,
+                        ..log_group
+                    })
+                }
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_cloud_watch_log_streams
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.cloud_watch_state.selected_log_group = None
+                }
+            };
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_get_more_log_streams(next_token: String, app_state: &mut AppState) {
+        let Some(log_group) = app_state
+            .cloud_watch_state
+            .selected_log_group
+            .as_ref()
+            .filter(|log_group| log_group.log_streams_next_token.as_ref() == Some(&next_token))
+        else {
+            return;
+        };
+
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::describe_log_streams(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                log_group.name.as_deref().unwrap_or_default(),
+                Some(next_token.clone()),
+            )
+            .await
+            {
+                Ok(page) => CloudWatchLogsActionHandler::append_log_streams(
+                    &mut app_state.cloud_watch_state,
+                    &next_token,
+                    page,
+                ),
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_cloud_watch_log_streams
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            };
+        }
+    }
+
+    /// A page is appended once, to the log group it was requested for.
+    fn append_log_streams(
+        cloud_watch_state: &mut CloudWatchState,
+        next_token: &str,
+        (log_streams, log_streams_next_token): (Vec<CloudWatchLogStream>, Option<String>),
+    ) {
+        if let Some(log_group) = cloud_watch_state
+            .selected_log_group
+            .as_mut()
+            .filter(|log_group| log_group.log_streams_next_token.as_deref() == Some(next_token))
+        {
+            log_group.log_streams.extend(log_streams);
+            log_group.log_streams_next_token = log_streams_next_token;
+        }
+    }
+
+    async fn handle_select_log_stream(log_stream_name: String, app_state: &mut AppState) {
+        if let (Some(profile), Some(log_group)) = (
+            &app_state.active_profile,
+            &app_state.cloud_watch_state.selected_log_group,
+        ) {
+            let log_group_name = log_group.name.clone().unwrap_or_default();
+            let log_stream = log_group
+                .log_streams
+                .iter()
+                .find(|log_stream| log_stream.log_stream_name.as_ref() == Some(&log_stream_name))
+                .cloned();
+
+            match CloudWatchLogsRepository::get_log_events(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                &log_group_name,
+                &log_stream_name,
+            )
+            .await
+            {
+                Ok(log_events) => {
+                    app_state.cloud_watch_state.selected_log_stream = log_stream;
+                    app_state.cloud_watch_state.log_events = log_events;
+                }
+                Err(err) => {
                     app_state.status_state.err_message =
-                        TUI_CONFIG.messages.error_caller_identity.into();
-                    app_state.cloud_watch_state = CloudWatchState::default()
+                        TUI_CONFIG.messages.error_get_cloud_watch_log_events.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.cloud_watch_state.selected_log_stream = None;
+                    app_state.cloud_watch_state.log_events = vec![];
                 }
             };
 
             event!(Level::DEBUG, "{:?}", app_state);
         }
     }
+
+    fn handle_start_live_tail(
+        log_stream_names: Vec<String>,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        CloudWatchLogsActionHandler::stop_live_tail(app_state);
+
+        if let (Some(profile), Some(log_group)) = (
+            &app_state.active_profile,
+            &app_state.cloud_watch_state.selected_log_group,
+        ) {
+            let cloud_watch_state = &app_state.cloud_watch_state;
+            let shown_log_stream_names = match (
+                &cloud_watch_state.live_tail,
+                &cloud_watch_state.selected_log_stream,
+            ) {
+                (Some(live_tail), _) => Some(live_tail.log_stream_names.clone()),
+                (None, Some(log_stream)) => {
+                    log_stream.log_stream_name.clone().map(|name| vec![name])
+                }
+                (None, None) => None,
+            };
+
+            // continue behind the events already shown, otherwise start with a fresh event list
+            let start_time = if shown_log_stream_names.as_ref() == Some(&log_stream_names) {
+                cloud_watch_state
+                    .log_events
+                    .iter()
+                    .filter_map(|log_event| log_event.timestamp)
+                    .max()
+            } else {
+                None
+            };
+            let cursor = match start_time {
+                Some(start_time) => LiveTailCursor::new(start_time, &cloud_watch_state.log_events),
+                None => {
+                    app_state.cloud_watch_state.selected_log_stream = None;
+                    app_state.cloud_watch_state.log_events = vec![];
+                    LiveTailCursor::new(
+                        Utc::now().timestamp_millis()
+                            - TUI_CONFIG.live_tail_lookback_in_min as i64 * 60 * 1000,
+                        &[],
+                    )
+                }
+            };
+
+            let live_tail = CloudWatchLiveTail {
+                id: app_state.cloud_watch_state.next_live_tail_id,
+                log_group_name: log_group.name.clone().unwrap_or_default(),
+                log_stream_names,
+                cancellation_token: CancellationToken::new(),
+            };
+
+            tokio::spawn(CloudWatchLogsActionHandler::run_live_tail(
+                app_state.aws_config.clone(),
+                profile.sdk_config.clone(),
+                live_tail.clone(),
+                cursor,
+                task_tx.clone(),
+            ));
+
+            app_state.cloud_watch_state.next_live_tail_id += 1;
+            app_state.cloud_watch_state.live_tail = Some(live_tail);
+            app_state.status_state.err_message = String::default();
+        }
+    }
+
+    async fn run_live_tail(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        live_tail: CloudWatchLiveTail,
+        mut cursor: LiveTailCursor,
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let mut poll_interval =
+            tokio::time::interval(Duration::from_secs(TUI_CONFIG.live_tail_poll_rate_in_sec));
+
+        loop {
+            tokio::select! {
+                _ = live_tail.cancellation_token.cancelled() => break,
+
+                _ = poll_interval.tick() => {
+                    let result = CloudWatchLogsRepository::filter_log_events(
+                        &aws_config,
+                        &sdk_config,
+                        &live_tail.log_group_name,
+                        &live_tail.log_stream_names,
+                        Some(cursor.start_time()),
+                        None,
+                    )
+                    .await;
+
+                    if live_tail.cancellation_token.is_cancelled() {
+                        break;
+                    }
+
+                    let action = match result {
+                        Ok(log_events) => {
+                            let log_events = cursor.take_new_events(log_events);
+                            if log_events.is_empty() {
+                                continue;
+                            }
+
+                            CloudWatchLogsAction::AppendLogEvents {
+                                live_tail_id: live_tail.id,
+                                log_events,
+                            }
+                        }
+                        Err(err) => CloudWatchLogsAction::LiveTailError {
+                            backtrace: format!("{:?}", err),
+                        },
+                    };
+
+                    let is_error = matches!(action, CloudWatchLogsAction::LiveTailError { .. });
+                    if task_tx.send(Action::CloudWatchLogs { action }).is_err() || is_error {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Events still queued from a stopped or replaced tail belong to other streams and are dropped.
+    fn handle_append_log_events(
+        live_tail_id: usize,
+        log_events: Vec<CloudWatchLogEvent>,
+        app_state: &mut AppState,
+    ) {
+        let is_tailing = app_state
+            .cloud_watch_state
+            .live_tail
+            .as_ref()
+            .is_some_and(|live_tail| {
+                live_tail.id == live_tail_id && !live_tail.cancellation_token.is_cancelled()
+            });
+
+        if is_tailing {
+            let shown_log_events = &mut app_state.cloud_watch_state.log_events;
+            shown_log_events.extend(log_events);
+
+            if shown_log_events.len() > TUI_CONFIG.live_tail_max_events {
+                let overflow = shown_log_events.len() - TUI_CONFIG.live_tail_max_events;
+                shown_log_events.drain(..overflow);
+            }
+        }
+    }
+
+    fn stop_live_tail(app_state: &mut AppState) {
+        if let Some(live_tail) = &app_state.cloud_watch_state.live_tail {
+            live_tail.cancellation_token.cancel();
+        }
+    }
+
+    fn handle_export_log_events(
+        path: PathBuf,
+        format: CloudWatchExportFormat,
+        time_range: Option<(i64, i64)>,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        let cloud_watch_state = &app_state.cloud_watch_state;
+        let log_group_name = cloud_watch_state
+            .selected_log_group
+            .as_ref()
+            .and_then(|log_group| log_group.name.clone())
+            .or_else(|| {
+                cloud_watch_state
+                    .live_tail
+                    .as_ref()
+                    .map(|live_tail| live_tail.log_group_name.clone())
+            });
+        let log_stream_names = match (
+            &cloud_watch_state.selected_log_stream,
+            &cloud_watch_state.live_tail,
+        ) {
+            (Some(log_stream), _) => log_stream.log_stream_name.clone().into_iter().collect(),
+            (None, Some(live_tail)) => live_tail.log_stream_names.clone(),
+            (None, None) => vec![],
+        };
+
+        if let (Some(profile), Some(log_group_name)) = (&app_state.active_profile, log_group_name) {
+            // a time range fetches all pages from the service, otherwise the shown events are written
+            let log_events = match time_range {
+                Some(_) => vec![],
+                None => cloud_watch_state.log_events.clone(),
+            };
+
+            tokio::spawn(CloudWatchLogsActionHandler::run_log_event_export(
+                app_state.aws_config.clone(),
+                profile.sdk_config.clone(),
+                (log_group_name, log_stream_names),
+                (path, format, time_range),
+                log_events,
+                task_tx.clone(),
+            ));
+
+            app_state.status_state.progress = Some(ProgressState {
+                title: TUI_CONFIG.messages.exporting_log_events.into(),
+                current: 0,
+                total: None,
+            });
+        }
+    }
+
+    async fn run_log_event_export(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        (log_group_name, log_stream_names): (String, Vec<String>),
+        (path, format, time_range): (PathBuf, CloudWatchExportFormat, Option<(i64, i64)>),
+        log_events: Vec<CloudWatchLogEvent>,
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let result = async {
+            let mut log_event_export = LogEventExport::create(&path, format)?;
+            let mut log_event_count = log_events.len() as u64;
+            log_event_export.write_log_events(&log_events)?;
+
+            if let Some((start_time, end_time)) = time_range {
+                let mut next_token = None;
+                loop {
+                    let (page_log_events, page_next_token) =
+                        CloudWatchLogsRepository::filter_log_events_page(
+                            &aws_config,
+                            &sdk_config,
+                            &log_group_name,
+                            &log_stream_names,
+                            (Some(start_time), Some(end_time)),
+                            next_token,
+                        )
+                        .await?;
+                    log_event_export.write_log_events(&page_log_events)?;
+                    log_event_count += page_log_events.len() as u64;
+
+                    let _ = task_tx.send(Action::SetProgress {
+                        progress: Some(ProgressState {
+                            title: TUI_CONFIG.messages.exporting_log_events.into(),
+                            current: log_event_count,
+                            total: None,
+                        }),
+                    });
+
+                    match page_next_token {
+                        Some(token) => next_token = Some(token),
+                        None => break,
+                    }
+                }
+            }
+
+            log_event_export.persist(&path)?;
+            anyhow::Ok(log_event_count)
+        }
+        .await;
+        if result.is_err() {
+            remove_partial_file(&path);
+        }
+
+        let action = match result {
+            Ok(log_event_count) => CloudWatchLogsAction::ExportFinished {
+                path,
+                log_event_count,
+            },
+            Err(err) => CloudWatchLogsAction::ExportError {
+                backtrace: format!("{:?}", err),
+            },
+        };
+        let _ = task_tx.send(Action::CloudWatchLogs { action });
+    }
+
+    async fn handle_run_insights_query(
+        log_group_names: &[String],
+        query_string: &str,
+        time_range: (i64, i64),
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        CloudWatchLogsActionHandler::stop_insights_query_poll(app_state);
+
+        if let Some(profile) = &app_state.active_profile {
+            match CloudWatchLogsRepository::start_query(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                log_group_names,
+                query_string,
+                time_range,
+            )
+            .await
+            {
+                Ok(query_id) => {
+                    let cancellation_token = CancellationToken::new();
+                    tokio::spawn(CloudWatchLogsActionHandler::run_insights_query_poll(
+                        app_state.aws_config.clone(),
+                        profile.sdk_config.clone(),
+                        query_id.clone(),
+                        cancellation_token.clone(),
+                        task_tx.clone(),
+                    ));
+
+                    app_state.cloud_watch_state.insights = CloudWatchInsightsState {
+                        query_id: Some(query_id),
+                        status: "Scheduled".into(),
+                        cancellation_token: Some(cancellation_token),
+                        saved_queries: app_state.cloud_watch_state.insights.saved_queries.clone(),
+                        ..CloudWatchInsightsState::default()
+                    };
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.cloud_watch_state.insights.status = "Failed".into();
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_insights_query.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn run_insights_query_poll(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        query_id: String,
+        cancellation_token: CancellationToken,
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let mut poll_interval =
+            tokio::time::interval(Duration::from_secs(TUI_CONFIG.insights_poll_rate_in_sec));
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+
+                _ = poll_interval.tick() => {
+                    let result = CloudWatchLogsRepository::get_query_results(
+                        &aws_config,
+                        &sdk_config,
+                        &query_id,
+                    )
+                    .await;
+
+                    if cancellation_token.is_cancelled() {
+                        break;
+                    }
+
+                    let (action, is_finished) = match result {
+                        Ok(results) => {
+                            let is_finished = results.is_finished;
+                            (
+                                CloudWatchLogsAction::SetInsightsResults {
+                                    query_id: query_id.clone(),
+                                    results,
+                                },
+                                is_finished,
+                            )
+                        }
+                        Err(err) => (
+                            CloudWatchLogsAction::InsightsQueryError {
+                                backtrace: format!("{:?}", err),
+                            },
+                            true,
+                        ),
+                    };
+
+                    if task_tx.send(Action::CloudWatchLogs { action }).is_err() || is_finished {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_set_insights_results(
+        query_id: String,
+        results: CloudWatchInsightsResults,
+        app_state: &mut AppState,
+    ) {
+        let insights = &mut app_state.cloud_watch_state.insights;
+        if insights.query_id.as_ref() != Some(&query_id) {
+            return;
+        }
+
+        // every result row lists its own fields, the table shows the union of them
+        let mut columns: Vec<String> = vec![];
+        for (field, _) in results.rows.iter().flatten() {
+            if field != "@ptr" && !columns.contains(field) {
+                columns.push(field.clone());
+            }
+        }
+
+        insights.rows = results
+            .rows
+            .iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| {
+                        row.iter()
+                            .find(|(field, _)| field == column)
+                            .map(|(_, value)| value.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        insights.columns = columns;
+        insights.status = results.status;
+        insights.records_matched = results.records_matched;
+    }
+
+    async fn handle_cancel_insights_query(app_state: &mut AppState) {
+        CloudWatchLogsActionHandler::stop_insights_query_poll(app_state);
+
+        let insights = &app_state.cloud_watch_state.insights;
+        let is_running = matches!(insights.status.as_str(), "Scheduled" | "Running");
+        if let (Some(profile), Some(query_id), true) =
+            (&app_state.active_profile, &insights.query_id, is_running)
+        {
+            match CloudWatchLogsRepository::stop_query(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                query_id,
+            )
+            .await
+            {
+                Ok(_) => app_state.cloud_watch_state.insights.status = "Cancelled".into(),
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_insights_query.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    fn stop_insights_query_poll(app_state: &mut AppState) {
+        if let Some(cancellation_token) = &app_state.cloud_watch_state.insights.cancellation_token {
+            cancellation_token.cancel();
+        }
+    }
+
+    fn handle_save_query(saved_query: CloudWatchSavedQuery, app_state: &mut AppState) {
+        let saved_queries = &mut app_state.cloud_watch_state.insights.saved_queries;
+        match saved_queries
+            .iter_mut()
+            .find(|existing_query| existing_query.name == saved_query.name)
+        {
+            Some(existing_query) => *existing_query = saved_query,
+            None => saved_queries.push(saved_query),
+        }
+
+        match store_saved_queries(&get_saved_queries_path(), saved_queries) {
+            Ok(_) => app_state.status_state.message = TUI_CONFIG.messages.query_saved.into(),
+            Err(err) => CloudWatchLogsActionHandler::set_saved_queries_error(err, app_state),
+        }
+    }
+
+    fn set_saved_queries_error(err: anyhow::Error, app_state: &mut AppState) {
+        app_state.status_state.err_message = TUI_CONFIG.messages.error_saved_queries.into();
+        app_state.status_state.err_message_backtrace = format!("{:?}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::app_config::AppConfig;
+
+    use super::*;
+
+    fn create_log_streams(names: &[&str]) -> Vec<CloudWatchLogStream> {
+        names
+            .iter()
+            .map(|name| CloudWatchLogStream {
+                log_stream_name: Some(name.to_string()),
+                ..CloudWatchLogStream::default()
+            })
+            .collect()
+    }
+
+    fn create_log_groups(names: &[&str]) -> Vec<CloudWatchLogGroup> {
+        names
+            .iter()
+            .map(|name| CloudWatchLogGroup {
+                name: Some(name.to_string()),
+                ..CloudWatchLogGroup::default()
+            })
+            .collect()
+    }
+
+    fn get_log_group_names(cloud_watch_state: &CloudWatchState) -> Vec<&str> {
+        cloud_watch_state
+            .log_groups
+            .iter()
+            .filter_map(|log_group| log_group.name.as_deref())
+            .collect()
+    }
+
+    fn get_log_stream_names(cloud_watch_state: &CloudWatchState) -> Vec<&str> {
+        cloud_watch_state
+            .selected_log_group
+            .iter()
+            .flat_map(|log_group| &log_group.log_streams)
+            .filter_map(|log_stream| log_stream.log_stream_name.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_append_log_groups_page() {
+        let mut cloud_watch_state = CloudWatchState {
+            log_groups: create_log_groups(&["/aws/a"]),
+            log_groups_next_token: Some("page-2".into()),
+            ..CloudWatchState::default()
+        };
+
+        CloudWatchLogsActionHandler::append_log_groups(
+            &mut cloud_watch_state,
+            "page-2",
+            (create_log_groups(&["/aws/b"]), Some("page-3".into())),
+        );
+        assert_eq!(
+            get_log_group_names(&cloud_watch_state),
+            vec!["/aws/a", "/aws/b"]
+        );
+        assert_eq!(
+            cloud_watch_state.log_groups_next_token,
+            Some("page-3".into())
+        );
+
+        CloudWatchLogsActionHandler::append_log_groups(
+            &mut cloud_watch_state,
+            "page-3",
+            (create_log_groups(&["/aws/c"]), None),
+        );
+        assert_eq!(
+            get_log_group_names(&cloud_watch_state),
+            vec!["/aws/a", "/aws/b", "/aws/c"]
+        );
+        assert_eq!(cloud_watch_state.log_groups_next_token, None);
+    }
+
+    #[test]
+    fn test_drop_log_groups_page_of_repeated_token() {
+        let mut cloud_watch_state = CloudWatchState {
+            log_groups: create_log_groups(&["/aws/a"]),
+            log_groups_next_token: Some("page-2".into()),
+            ..CloudWatchState::default()
+        };
+
+        for _ in 0..2 {
+            CloudWatchLogsActionHandler::append_log_groups(
+                &mut cloud_watch_state,
+                "page-2",
+                (create_log_groups(&["/aws/b"]), Some("page-3".into())),
+            );
+        }
+        assert_eq!(
+            get_log_group_names(&cloud_watch_state),
+            vec!["/aws/a", "/aws/b"]
+        );
+        assert_eq!(
+            cloud_watch_state.log_groups_next_token,
+            Some("page-3".into())
+        );
+    }
+
+    #[test]
+    fn test_keep_log_group_name_filter_across_pages() {
+        let name_filter = CloudWatchLogGroupNameFilter::Prefix("/aws/lambda".into());
+        let mut cloud_watch_state = CloudWatchState {
+            log_groups: create_log_groups(&["/aws/lambda/a"]),
+            log_groups_next_token: Some("page-2".into()),
+            log_group_name_filter: Some(name_filter.clone()),
+            ..CloudWatchState::default()
+        };
+
+        CloudWatchLogsActionHandler::append_log_groups(
+            &mut cloud_watch_state,
+            "page-2",
+            (create_log_groups(&["/aws/lambda/b"]), Some("page-3".into())),
+        );
+        assert_eq!(cloud_watch_state.log_group_name_filter, Some(name_filter));
+    }
+
+    #[test]
+    fn test_append_log_streams_of_requested_page_once() {
+        let mut cloud_watch_state = CloudWatchState {
+            selected_log_group: Some(CloudWatchLogGroup {
+                name: Some("/aws/lambda/app".into()),
+                log_streams: create_log_streams(&["a"]),
+                log_streams_next_token: Some("page-2".into()),
+                ..CloudWatchLogGroup::default()
+            }),
+            ..CloudWatchState::default()
+        };
+
+        CloudWatchLogsActionHandler::append_log_streams(
+            &mut cloud_watch_state,
+            "page-2",
+            (create_log_streams(&["b"]), Some("page-3".into())),
+        );
+        assert_eq!(get_log_stream_names(&cloud_watch_state), vec!["a", "b"]);
+
+        CloudWatchLogsActionHandler::append_log_streams(
+            &mut cloud_watch_state,
+            "page-2",
+            (create_log_streams(&["b"]), Some("page-3".into())),
+        );
+        assert_eq!(get_log_stream_names(&cloud_watch_state), vec!["a", "b"]);
+        assert_eq!(
+            cloud_watch_state
+                .selected_log_group
+                .unwrap()
+                .log_streams_next_token,
+            Some("page-3".into())
+        );
+    }
+
+    #[test]
+    fn test_drop_log_events_of_replaced_live_tail() {
+        let mut app_state = AppState::new(&AppConfig::default());
+        app_state.cloud_watch_state.live_tail = Some(CloudWatchLiveTail {
+            id: 1,
+            log_group_name: "/aws/lambda/app".into(),
+            log_stream_names: vec!["b".into()],
+            cancellation_token: CancellationToken::new(),
+        });
+        let create_log_events = |message: &str| {
+            vec![CloudWatchLogEvent {
+                message: Some(message.into()),
+                ..CloudWatchLogEvent::default()
+            }]
+        };
+
+        CloudWatchLogsActionHandler::handle_append_log_events(
+            0,
+            create_log_events("stream a"),
+            &mut app_state,
+        );
+        CloudWatchLogsActionHandler::handle_append_log_events(
+            1,
+            create_log_events("stream b"),
+            &mut app_state,
+        );
+
+        let messages = app_state
+            .cloud_watch_state
+            .log_events
+            .iter()
+            .filter_map(|log_event| log_event.message.as_deref())
+            .collect::<Vec<&str>>();
+        assert_eq!(messages, vec!["stream b"]);
+    }
 }
@@ -0,0 +1,555 @@
+use std::path::PathBuf;
+
+use aws_config::SdkConfig;
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{event, Level};
+
+use serde_json::Value;
+
+use crate::{
+    config::app_config::AWSConfig,
+    repository::{
+        dynamodb::{DynamoDBRepository, BATCH_WRITE_SIZE},
+        dynamodb_export::{read_items, ItemExport, RequestInterval},
+        dynamodb_json::{item_key, item_to_plain_json},
+    },
+    state::{
+        appstate::{AppState, ProgressState},
+        dynamodb_state::{DynamoDbExportFormat, DynamoDbQuery},
+    },
+    ui::tui_config::TUI_CONFIG,
+};
+
+use super::actions::{Action, DynamoDbAction};
+
+pub struct DynamoDbActionHandler;
+
+impl DynamoDbActionHandler {
+    pub async fn handle(
+        action: DynamoDbAction,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        match action {
+            DynamoDbAction::GetTables => DynamoDbActionHandler::handle_get_tables(app_state).await,
+            DynamoDbAction::FetchItems { query } => {
+                DynamoDbActionHandler::handle_fetch_items(query, app_state).await;
+            }
+            DynamoDbAction::FetchNextItems => {
+                DynamoDbActionHandler::handle_fetch_next_items(app_state).await;
+            }
+            DynamoDbAction::SaveItem {
+                table_name,
+                key_names,
+                original_item,
+                item,
+            } => {
+                DynamoDbActionHandler::handle_save_item(
+                    table_name,
+                    key_names,
+                    original_item,
+                    item,
+                    app_state,
+                )
+                .await;
+            }
+            DynamoDbAction::DeleteItem { table_name, key } => {
+                DynamoDbActionHandler::handle_delete_item(table_name, key, app_state).await;
+            }
+            DynamoDbAction::ExecuteStatement { statement } => {
+                DynamoDbActionHandler::handle_execute_statement(statement, app_state).await;
+            }
+            DynamoDbAction::FetchNextStatementItems => {
+                DynamoDbActionHandler::handle_fetch_next_statement_items(app_state).await;
+            }
+            DynamoDbAction::ExportItems { path, format } => {
+                DynamoDbActionHandler::handle_export_items(path, format, app_state, task_tx);
+            }
+            DynamoDbAction::ExportFinished { path, item_count } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.message = format!(
+                    "{} {} ({} items)",
+                    TUI_CONFIG.messages.dynamodb_items_exported,
+                    path.display(),
+                    item_count
+                );
+                app_state.status_state.err_message = String::default();
+            }
+            DynamoDbAction::ExportError { backtrace } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.err_message =
+                    TUI_CONFIG.messages.error_export_dynamodb_items.into();
+                app_state.status_state.err_message_backtrace = backtrace;
+            }
+            DynamoDbAction::ImportItems { table_name, path } => {
+                DynamoDbActionHandler::handle_import_items(table_name, path, app_state, task_tx);
+            }
+            DynamoDbAction::ImportFinished {
+                table_name,
+                path,
+                item_count,
+            } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.message = format!(
+                    "{} {} ({} items)",
+                    TUI_CONFIG.messages.dynamodb_items_imported,
+                    path.display(),
+                    item_count
+                );
+                app_state.status_state.err_message = String::default();
+
+                // the shown items of the table are fetched again to include the imported ones
+                if let Some(query) = app_state
+                    .dynamodb_state
+                    .query
+                    .clone()
+                    .filter(|query| query.table_name == table_name)
+                {
+                    DynamoDbActionHandler::handle_fetch_items(Box::new(query), app_state).await;
+                }
+            }
+            DynamoDbAction::ImportError { backtrace } => {
+                app_state.status_state.progress = None;
+                app_state.status_state.err_message =
+                    TUI_CONFIG.messages.error_import_dynamodb_items.into();
+                app_state.status_state.err_message_backtrace = backtrace;
+            }
+        }
+    }
+
+    async fn handle_get_tables(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match DynamoDBRepository::list_tables(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+            )
+            .await
+            {
+                Ok(tables) => {
+                    app_state.dynamodb_state.tables = tables;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_list_dynamodb_tables.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.dynamodb_state.tables = vec![];
+                }
+            }
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_fetch_items(query: Box<DynamoDbQuery>, app_state: &mut AppState) {
+        let dynamodb_state = &mut app_state.dynamodb_state;
+        dynamodb_state.items = vec![];
+        dynamodb_state.last_evaluated_key = None;
+        dynamodb_state.query = Some(*query);
+
+        DynamoDbActionHandler::handle_fetch_next_items(app_state).await;
+    }
+
+    async fn handle_fetch_next_items(app_state: &mut AppState) {
+        if let (Some(profile), Some(query)) =
+            (&app_state.active_profile, &app_state.dynamodb_state.query)
+        {
+            // the first page has no start key, the last page no last evaluated key
+            if !app_state.dynamodb_state.items.is_empty()
+                && app_state.dynamodb_state.last_evaluated_key.is_none()
+            {
+                return;
+            }
+
+            match DynamoDBRepository::fetch_items(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                query,
+                app_state.dynamodb_state.last_evaluated_key.as_ref(),
+            )
+            .await
+            {
+                Ok((items, last_evaluated_key)) => {
+                    app_state.dynamodb_state.items.extend(items);
+                    app_state.dynamodb_state.last_evaluated_key = last_evaluated_key;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_fetch_dynamodb_items.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.dynamodb_state.last_evaluated_key = None;
+                }
+            }
+        }
+    }
+
+    /// Items of an unchanged key are updated, new items and items of a changed key are
+    /// put without overwriting an existing item, the item of the old key is deleted.
+    async fn handle_save_item(
+        table_name: String,
+        key_names: Vec<String>,
+        original_item: Option<Value>,
+        item: Value,
+        app_state: &mut AppState,
+    ) {
+        if let Some(profile) = &app_state.active_profile {
+            let region = profile.selected_region.as_deref();
+            let key = item_key(&item, &key_names);
+            let original_key = original_item
+                .as_ref()
+                .map(|original_item| item_key(original_item, &key_names));
+
+            let result = match (&original_item, &original_key) {
+                (Some(original_item), Some(original_key)) if *original_key == key => {
+                    DynamoDBRepository::update_item(
+                        &app_state.aws_config,
+                        &profile.sdk_config,
+                        region,
+                        &table_name,
+                        &key,
+                        original_item,
+                        &item,
+                    )
+                    .await
+                }
+                _ => {
+                    let result = DynamoDBRepository::put_item(
+                        &app_state.aws_config,
+                        &profile.sdk_config,
+                        region,
+                        &table_name,
+                        &item,
+                        key_names.first().map(|key_name| key_name.as_str()),
+                    )
+                    .await;
+                    match (result, &original_key) {
+                        (Ok(()), Some(original_key)) => {
+                            DynamoDBRepository::delete_item(
+                                &app_state.aws_config,
+                                &profile.sdk_config,
+                                region,
+                                &table_name,
+                                original_key,
+                            )
+                            .await
+                        }
+                        (result, _) => result,
+                    }
+                }
+            };
+
+            match result {
+                Ok(()) => {
+                    let items = &mut app_state.dynamodb_state.items;
+                    match original_item.and_then(|original_item| {
+                        items
+                            .iter()
+                            .position(|listed_item| *listed_item == original_item)
+                    }) {
+                        Some(position) => items[position] = item,
+                        None => items.push(item),
+                    }
+                    app_state.status_state.message = format!(
+                        "{} {}",
+                        TUI_CONFIG.messages.dynamodb_item_saved,
+                        item_to_plain_json(&key)
+                    );
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_dynamodb_save_item.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_delete_item(table_name: String, key: Value, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match DynamoDBRepository::delete_item(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &table_name,
+                &key,
+            )
+            .await
+            {
+                Ok(()) => {
+                    let key_names = key
+                        .as_object()
+                        .map(|key| key.keys().cloned().collect::<Vec<String>>())
+                        .unwrap_or_default();
+                    app_state
+                        .dynamodb_state
+                        .items
+                        .retain(|item| item_key(item, &key_names) != key);
+                    app_state.status_state.message = format!(
+                        "{} {}",
+                        TUI_CONFIG.messages.dynamodb_item_deleted,
+                        item_to_plain_json(&key)
+                    );
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_dynamodb_delete_item.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_execute_statement(statement: String, app_state: &mut AppState) {
+        let statement_state = &mut app_state.dynamodb_state.statement_state;
+        if statement_state.history.last() != Some(&statement) {
+            statement_state.history.push(statement.clone());
+        }
+        statement_state.statement = Some(statement);
+        statement_state.items = vec![];
+        statement_state.next_token = None;
+
+        if DynamoDbActionHandler::handle_fetch_next_statement_items(app_state).await {
+            app_state.status_state.message = format!(
+                "{} ({} items)",
+                TUI_CONFIG.messages.dynamodb_statement_executed,
+                app_state.dynamodb_state.statement_state.items.len()
+            );
+            app_state.status_state.err_message = String::default();
+        }
+    }
+
+    /// Fetches the next page of the statement, false if it failed.
+    async fn handle_fetch_next_statement_items(app_state: &mut AppState) -> bool {
+        let statement_state = &app_state.dynamodb_state.statement_state;
+        if let (Some(profile), Some(statement)) =
+            (&app_state.active_profile, &statement_state.statement)
+        {
+            // the first page has no token, the last page no next token
+            if !statement_state.items.is_empty() && statement_state.next_token.is_none() {
+                return true;
+            }
+
+            match DynamoDBRepository::execute_statement(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                statement,
+                statement_state.next_token.clone(),
+            )
+            .await
+            {
+                Ok((items, next_token)) => {
+                    let statement_state = &mut app_state.dynamodb_state.statement_state;
+                    statement_state.items.extend(items);
+                    statement_state.next_token = next_token;
+                    return true;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_dynamodb_execute_statement.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.dynamodb_state.statement_state.next_token = None;
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Exports all pages of the shown scan or query, not only the fetched ones.
+    fn handle_export_items(
+        path: PathBuf,
+        format: DynamoDbExportFormat,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        let dynamodb_state = &app_state.dynamodb_state;
+        if let (Some(profile), Some(query)) = (&app_state.active_profile, &dynamodb_state.query) {
+            let key_names = dynamodb_state
+                .tables
+                .iter()
+                .find(|table| table.table_name == query.table_name)
+                .map(|table| table.key_names())
+                .unwrap_or_default();
+
+            tokio::spawn(DynamoDbActionHandler::run_item_export(
+                app_state.aws_config.clone(),
+                profile.sdk_config.clone(),
+                profile.selected_region.clone(),
+                (query.clone(), key_names),
+                (path, format),
+                task_tx.clone(),
+            ));
+
+            app_state.status_state.progress = Some(ProgressState {
+                title: TUI_CONFIG.messages.exporting_dynamodb_items.into(),
+                current: 0,
+                total: None,
+            });
+        }
+    }
+
+    async fn run_item_export(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        region: Option<String>,
+        (query, key_names): (DynamoDbQuery, Vec<String>),
+        (path, format): (PathBuf, DynamoDbExportFormat),
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let result = async {
+            let mut item_export = ItemExport::create(&path, format, key_names)?;
+            let mut request_interval = RequestInterval::default();
+            let mut item_count = 0;
+            let mut exclusive_start_key = None;
+
+            loop {
+                match DynamoDBRepository::fetch_items(
+                    &aws_config,
+                    &sdk_config,
+                    region.as_deref(),
+                    &query,
+                    exclusive_start_key.as_ref(),
+                )
+                .await
+                {
+                    Ok((items, last_evaluated_key)) => {
+                        item_export.write_items(&items)?;
+                        item_count += items.len() as u64;
+                        request_interval.on_success();
+
+                        let _ = task_tx.send(Action::SetProgress {
+                            progress: Some(ProgressState {
+                                title: TUI_CONFIG.messages.exporting_dynamodb_items.into(),
+                                current: item_count,
+                                total: None,
+                            }),
+                        });
+
+                        match last_evaluated_key {
+                            Some(key) => exclusive_start_key = Some(key),
+                            None => break,
+                        }
+                    }
+                    Err(err)
+                        if DynamoDBRepository::is_throttling_error(&err)
+                            && request_interval.on_throttled() => {}
+                    Err(err) => return Err(err),
+                }
+
+                request_interval.wait().await;
+            }
+
+            item_export.finish()?;
+            anyhow::Ok(item_count)
+        }
+        .await;
+
+        let action = match result {
+            Ok(item_count) => DynamoDbAction::ExportFinished { path, item_count },
+            Err(err) => DynamoDbAction::ExportError {
+                backtrace: format!("{:?}", err),
+            },
+        };
+        let _ = task_tx.send(Action::DynamoDb { action });
+    }
+
+    fn handle_import_items(
+        table_name: String,
+        path: PathBuf,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        if let Some(profile) = &app_state.active_profile {
+            tokio::spawn(DynamoDbActionHandler::run_item_import(
+                app_state.aws_config.clone(),
+                profile.sdk_config.clone(),
+                profile.selected_region.clone(),
+                (table_name, path),
+                task_tx.clone(),
+            ));
+
+            app_state.status_state.progress = Some(ProgressState {
+                title: TUI_CONFIG.messages.importing_dynamodb_items.into(),
+                current: 0,
+                total: None,
+            });
+        }
+    }
+
+    /// Puts the items of the file in batches. Unprocessed items of a batch are written
+    /// again after a pause, which grows while DynamoDB keeps returning some of them.
+    async fn run_item_import(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        region: Option<String>,
+        (table_name, path): (String, PathBuf),
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let result = async {
+            let items = read_items(&path)?;
+            let mut request_interval = RequestInterval::default();
+            let mut item_count = 0;
+
+            for batch in items.chunks(BATCH_WRITE_SIZE) {
+                let mut pending_items = batch.to_vec();
+
+                while !pending_items.is_empty() {
+                    let is_throttled = match DynamoDBRepository::batch_write_items(
+                        &aws_config,
+                        &sdk_config,
+                        region.as_deref(),
+                        &table_name,
+                        &pending_items,
+                    )
+                    .await
+                    {
+                        Ok(unprocessed_items) => {
+                            item_count += (pending_items.len() - unprocessed_items.len()) as u64;
+                            pending_items = unprocessed_items;
+                            !pending_items.is_empty()
+                        }
+                        Err(err) if DynamoDBRepository::is_throttling_error(&err) => true,
+                        Err(err) => return Err(err),
+                    };
+
+                    if !is_throttled {
+                        request_interval.on_success();
+                    } else if !request_interval.on_throttled() {
+                        anyhow::bail!(
+                            "{} items could not be written, the table is throttled",
+                            items.len() as u64 - item_count
+                        );
+                    }
+
+                    let _ = task_tx.send(Action::SetProgress {
+                        progress: Some(ProgressState {
+                            title: TUI_CONFIG.messages.importing_dynamodb_items.into(),
+                            current: item_count,
+                            total: Some(items.len() as u64),
+                        }),
+                    });
+                    request_interval.wait().await;
+                }
+            }
+
+            anyhow::Ok(item_count)
+        }
+        .await;
+
+        let action = match result {
+            Ok(item_count) => DynamoDbAction::ImportFinished {
+                table_name,
+                path,
+                item_count,
+            },
+            Err(err) => DynamoDbAction::ImportError {
+                backtrace: format!("{:?}", err),
+            },
+        };
+        let _ = task_tx.send(Action::DynamoDb { action });
+    }
+}
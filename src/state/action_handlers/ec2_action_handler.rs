@@ -0,0 +1,281 @@
+use tracing::{event, Level};
+
+use crate::{
+    repository::ec2::EC2Repository,
+    state::{appstate::AppState, ec2_state::Ec2InstanceCommand},
+    ui::tui_config::TUI_CONFIG,
+};
+
+use super::actions::Ec2Action;
+
+pub struct Ec2ActionHandler;
+
+impl Ec2ActionHandler {
+    pub async fn handle(action: Ec2Action, app_state: &mut AppState) {
+        match action {
+            Ec2Action::GetInstances => Ec2ActionHandler::handle_get_instances(app_state).await,
+            Ec2Action::GetNetworkSecurity => {
+                Ec2ActionHandler::handle_get_network_security(app_state).await;
+            }
+            Ec2Action::GetVpcs => Ec2ActionHandler::handle_get_vpcs(app_state).await,
+            Ec2Action::GetStorage => Ec2ActionHandler::handle_get_storage(app_state).await,
+            Ec2Action::CreateSnapshot { volume_id } => {
+                Ec2ActionHandler::handle_create_snapshot(volume_id, app_state).await;
+            }
+            Ec2Action::DeregisterImage { image_id } => {
+                Ec2ActionHandler::handle_deregister_image(image_id, app_state).await;
+            }
+            Ec2Action::DeleteSnapshot { snapshot_id } => {
+                Ec2ActionHandler::handle_delete_snapshot(snapshot_id, app_state).await;
+            }
+            Ec2Action::ChangeInstanceState {
+                instance_id,
+                command,
+            } => {
+                Ec2ActionHandler::handle_change_instance_state(instance_id, command, app_state)
+                    .await;
+            }
+        }
+    }
+
+    async fn handle_get_instances(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::describe_instances(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+            )
+            .await
+            {
+                Ok(instances) => {
+                    app_state.ec2_state.instances = instances;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_describe_ec2_instances.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.ec2_state.instances = vec![];
+                }
+            }
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_get_network_security(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            let region = profile.selected_region.as_deref();
+            let result = tokio::try_join!(
+                EC2Repository::describe_security_groups(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    region
+                ),
+                EC2Repository::describe_network_interfaces(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    region
+                ),
+                EC2Repository::describe_network_acls(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    region
+                )
+            );
+
+            match result {
+                Ok((security_groups, network_interfaces, network_acls)) => {
+                    let ec2_state = &mut app_state.ec2_state;
+                    ec2_state.security_groups = security_groups;
+                    ec2_state.network_interfaces = network_interfaces;
+                    ec2_state.network_acls = network_acls;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message = TUI_CONFIG
+                        .messages
+                        .error_describe_ec2_network_security
+                        .into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_get_vpcs(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::describe_vpcs(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+            )
+            .await
+            {
+                Ok(vpcs) => {
+                    app_state.ec2_state.vpcs = vpcs;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_describe_ec2_vpcs.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.ec2_state.vpcs = vec![];
+                }
+            }
+        }
+    }
+
+    async fn handle_get_storage(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            let region = profile.selected_region.as_deref();
+            let result = tokio::try_join!(
+                EC2Repository::describe_volumes(&app_state.aws_config, &profile.sdk_config, region),
+                EC2Repository::describe_snapshots(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    region
+                ),
+                EC2Repository::describe_images(&app_state.aws_config, &profile.sdk_config, region)
+            );
+
+            match result {
+                Ok((volumes, snapshots, images)) => {
+                    let ec2_state = &mut app_state.ec2_state;
+                    ec2_state.volumes = volumes;
+                    ec2_state.snapshots = snapshots;
+                    ec2_state.images = images;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_describe_ec2_storage.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_create_snapshot(volume_id: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::create_snapshot(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &volume_id,
+            )
+            .await
+            {
+                Ok(snapshot_id) => {
+                    app_state.status_state.message = format!(
+                        "{} {} of {}",
+                        TUI_CONFIG.messages.ec2_snapshot_created, snapshot_id, volume_id
+                    );
+                    app_state.status_state.err_message = String::default();
+                    Ec2ActionHandler::handle_get_storage(app_state).await;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_ec2_create_snapshot.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_deregister_image(image_id: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::deregister_image(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &image_id,
+            )
+            .await
+            {
+                Ok(_) => {
+                    app_state.status_state.message = format!(
+                        "{} {}",
+                        TUI_CONFIG.messages.ec2_image_deregistered, image_id
+                    );
+                    app_state.status_state.err_message = String::default();
+                    // the snapshots of the image may be orphaned now
+                    Ec2ActionHandler::handle_get_storage(app_state).await;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_ec2_deregister_image.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_delete_snapshot(snapshot_id: String, app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::delete_snapshot(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &snapshot_id,
+            )
+            .await
+            {
+                Ok(_) => {
+                    app_state.status_state.message = format!(
+                        "{} {}",
+                        TUI_CONFIG.messages.ec2_snapshot_deleted, snapshot_id
+                    );
+                    app_state.status_state.err_message = String::default();
+                    app_state
+                        .ec2_state
+                        .snapshots
+                        .retain(|snapshot| snapshot.snapshot_id != snapshot_id);
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_ec2_delete_snapshot.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_change_instance_state(
+        instance_id: String,
+        command: Ec2InstanceCommand,
+        app_state: &mut AppState,
+    ) {
+        if let Some(profile) = &app_state.active_profile {
+            match EC2Repository::change_instance_state(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &instance_id,
+                command,
+            )
+            .await
+            {
+                Ok(_) => {
+                    app_state.status_state.message = format!(
+                        "{} {}",
+                        match command {
+                            Ec2InstanceCommand::Start => TUI_CONFIG.messages.ec2_instance_started,
+                            Ec2InstanceCommand::Stop => TUI_CONFIG.messages.ec2_instance_stopped,
+                            Ec2InstanceCommand::Reboot => TUI_CONFIG.messages.ec2_instance_rebooted,
+                            Ec2InstanceCommand::Terminate => {
+                                TUI_CONFIG.messages.ec2_instance_terminated
+                            }
+                        },
+                        instance_id
+                    );
+                    app_state.status_state.err_message = String::default();
+
+                    // the list shows the pending state the instance changed to
+                    Ec2ActionHandler::handle_get_instances(app_state).await;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_ec2_instance_state.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+}
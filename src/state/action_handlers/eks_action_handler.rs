@@ -0,0 +1,139 @@
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::get_default_kubeconfig_path,
+    repository::{
+        eks::EKSRepository,
+        kubeconfig::{write_kubeconfig, KubeconfigEntry},
+    },
+    state::appstate::{AppState, ProfileSource},
+    ui::tui_config::TUI_CONFIG,
+};
+
+use super::actions::EksAction;
+
+pub struct EksActionHandler;
+
+impl EksActionHandler {
+    pub async fn handle(action: EksAction, app_state: &mut AppState) {
+        match action {
+            EksAction::GetClusters => EksActionHandler::handle_get_clusters(app_state).await,
+            EksAction::SelectCluster { cluster_name } => {
+                EksActionHandler::handle_select_cluster(cluster_name, app_state).await;
+            }
+            EksAction::WriteKubeconfig { cluster_name } => {
+                EksActionHandler::handle_write_kubeconfig(cluster_name, app_state);
+            }
+        }
+    }
+
+    async fn handle_get_clusters(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match EKSRepository::describe_clusters(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+            )
+            .await
+            {
+                Ok(clusters) => {
+                    app_state.eks_state.clusters = clusters;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_describe_eks_clusters.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.eks_state.clusters = vec![];
+                }
+            }
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_select_cluster(cluster_name: String, app_state: &mut AppState) {
+        let Some(cluster) = app_state
+            .eks_state
+            .clusters
+            .iter()
+            .find(|cluster| cluster.name == cluster_name)
+            .cloned()
+        else {
+            return;
+        };
+
+        if let Some(profile) = &app_state.active_profile {
+            let result = EKSRepository::describe_cluster_resources(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                profile.selected_region.as_deref(),
+                &cluster,
+            )
+            .await;
+
+            let eks_state = &mut app_state.eks_state;
+            eks_state.selected_cluster = Some(cluster);
+            match result {
+                Ok((node_groups, fargate_profiles, addons)) => {
+                    eks_state.node_groups = node_groups;
+                    eks_state.fargate_profiles = fargate_profiles;
+                    eks_state.addons = addons;
+                }
+                Err(err) => {
+                    eks_state.node_groups = vec![];
+                    eks_state.fargate_profiles = vec![];
+                    eks_state.addons = vec![];
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_describe_eks_cluster.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    fn handle_write_kubeconfig(cluster_name: String, app_state: &mut AppState) {
+        let (Some(profile), Some(cluster)) = (
+            &app_state.active_profile,
+            app_state
+                .eks_state
+                .clusters
+                .iter()
+                .find(|cluster| cluster.name == cluster_name),
+        ) else {
+            return;
+        };
+
+        let region = profile
+            .selected_region
+            .clone()
+            .or_else(|| profile.sdk_config.region().map(|region| region.to_string()))
+            .unwrap_or_default();
+        // the name of a profile from the environment is its access key id
+        let profile_name = match profile.source {
+            ProfileSource::Environment => None,
+            ProfileSource::CredentialsFile | ProfileSource::ConfigFile => {
+                Some(profile.name.as_str())
+            }
+        };
+        let path = get_default_kubeconfig_path();
+
+        match KubeconfigEntry::new(cluster, &region, profile_name)
+            .and_then(|entry| write_kubeconfig(&path, &entry))
+        {
+            Ok(_) => {
+                app_state.status_state.message = format!(
+                    "{} {} to {}",
+                    TUI_CONFIG.messages.eks_kubeconfig_written,
+                    cluster_name,
+                    path.display()
+                );
+                app_state.status_state.err_message = String::default();
+            }
+            Err(err) => {
+                app_state.status_state.err_message =
+                    TUI_CONFIG.messages.error_eks_kubeconfig.into();
+                app_state.status_state.err_message_backtrace = format!("{:?}", err);
+            }
+        }
+    }
+}
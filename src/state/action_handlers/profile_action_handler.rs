@@ -84,7 +84,10 @@ impl ProfileActionHandler {
                         sdk_config: config,
                         account: "".into(),
                         user: "".into(),
-                        err_message: format!("Error {}. Press <CRL-m> for more information.", err),
+                        err_message: format!(
+                            "{} ({})",
+                            TUI_CONFIG.messages.error_caller_identity, err
+                        ),
                         err_message_backtrace: format!("{:?}", err),
                         regions: vec![],
                         selected_region: None,
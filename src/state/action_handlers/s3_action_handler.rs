@@ -0,0 +1,1063 @@
+use std::{collections::HashMap, env, mem, path::PathBuf, time::Duration};
+
+use anyhow::{bail, Context};
+use aws_config::SdkConfig;
+use tokio::{fs, sync::mpsc::UnboundedSender};
+use tokio_util::sync::CancellationToken;
+use tracing::{event, Level};
+
+use crate::{
+    config::app_config::AWSConfig,
+    repository::{
+        login::LoginRepository,
+        s3::S3Repository,
+        s3_sync::{compare_files, create_sync_files, file_md5, plan_sync},
+        s3_transfer::{list_local_files, plan_download, plan_upload, S3TransferItem},
+    },
+    state::{
+        appstate::AppState,
+        s3_state::{
+            S3BucketConfig, S3BucketConfigSection, S3Object, S3ObjectPreview, S3PresignMethod,
+            S3PresignedUrl, S3State, S3SyncComparison, S3SyncDirection, S3SyncFile, S3SyncLocation,
+            S3SyncPlan, S3Transfer, S3TransferDirection, S3TransferOperation, S3TransferStatus,
+        },
+    },
+    ui::tui_config::TUI_CONFIG,
+};
+
+use super::actions::{Action, S3Action};
+
+/// Progress is reported to the state after at least this many bytes.
+const PROGRESS_REPORT_BYTES: u64 = 1024 * 1024;
+
+pub struct S3ActionHandler;
+
+impl S3ActionHandler {
+    pub async fn handle(
+        action: S3Action,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        match action {
+            S3Action::GetBuckets => S3ActionHandler::handle_get_buckets(app_state).await,
+            S3Action::SelectBucket { bucket_name } => {
+                S3ActionHandler::handle_select_bucket(bucket_name, app_state).await;
+            }
+            S3Action::DeselectBucket => {
+                let s3_state = &mut app_state.s3_state;
+                s3_state.selected_bucket = None;
+                s3_state.prefix = String::default();
+                s3_state.objects = vec![];
+                s3_state.objects_next_token = None;
+            }
+            S3Action::GetBucketConfig {
+                bucket_name,
+                section,
+            } => {
+                S3ActionHandler::handle_get_bucket_config(bucket_name, section, app_state).await;
+            }
+            S3Action::ListObjects {
+                prefix,
+                token: Some(token),
+            } => {
+                S3ActionHandler::handle_list_more_objects(prefix, token, app_state).await;
+            }
+            S3Action::ListObjects {
+                prefix,
+                token: None,
+            } => {
+                S3ActionHandler::handle_list_objects(prefix, app_state).await;
+            }
+            S3Action::PreviewObject { key, version_id } => {
+                S3ActionHandler::handle_preview_object(key, version_id, app_state).await;
+            }
+            S3Action::ClosePreview => app_state.s3_state.preview = None,
+            S3Action::PresignObject {
+                key,
+                method,
+                expires_in,
+            } => {
+                S3ActionHandler::handle_presign_object(key, method, expires_in, app_state).await;
+            }
+            S3Action::CompareSync { target } => {
+                S3ActionHandler::handle_compare_sync(target, app_state).await;
+            }
+            S3Action::ApplySync {
+                direction,
+                with_delete,
+            } => {
+                S3ActionHandler::handle_apply_sync(direction, with_delete, app_state, task_tx)
+                    .await;
+            }
+            S3Action::CloseSync => app_state.s3_state.sync_comparison = None,
+            S3Action::GetObjectVersions { key } => {
+                S3ActionHandler::handle_get_object_versions(key, app_state).await;
+            }
+            S3Action::RestoreObjectVersion { key, version_id } => {
+                S3ActionHandler::handle_restore_object_version(key, version_id, false, app_state)
+                    .await;
+            }
+            S3Action::DeleteObjectVersion { key, version_id } => {
+                S3ActionHandler::handle_restore_object_version(key, version_id, true, app_state)
+                    .await;
+            }
+            S3Action::DownloadObject {
+                key,
+                version_id,
+                local_dir,
+            } => {
+                S3ActionHandler::queue_transfer(
+                    S3TransferDirection::Download,
+                    (key, version_id),
+                    local_dir,
+                    app_state,
+                    task_tx,
+                );
+            }
+            S3Action::UploadPath { local_path } => {
+                let prefix = app_state.s3_state.prefix.clone();
+                S3ActionHandler::queue_transfer(
+                    S3TransferDirection::Upload,
+                    (prefix, None),
+                    local_path,
+                    app_state,
+                    task_tx,
+                );
+            }
+            S3Action::CancelTransfer { transfer_id } => {
+                if let Some(transfer) = S3ActionHandler::get_transfer(transfer_id, app_state) {
+                    match transfer.status {
+                        S3TransferStatus::Queued => transfer.status = S3TransferStatus::Cancelled,
+                        // the running task stops and reports the cancellation as error
+                        S3TransferStatus::Running => transfer.cancellation_token.cancel(),
+                        _ => {}
+                    }
+                }
+            }
+            S3Action::ClearTransfers => {
+                app_state
+                    .s3_state
+                    .transfers
+                    .retain(|transfer| !transfer.is_done());
+            }
+            S3Action::SetTransferProgress {
+                transfer_id,
+                bytes_done,
+                bytes_total,
+            } => {
+                if let Some(transfer) = S3ActionHandler::get_transfer(transfer_id, app_state) {
+                    transfer.bytes_done = bytes_done;
+                    transfer.bytes_total = bytes_total;
+                }
+            }
+            S3Action::TransferFinished { transfer_id } => {
+                S3ActionHandler::handle_transfer_finished(transfer_id, app_state).await;
+                S3ActionHandler::start_next_transfer(app_state, task_tx);
+            }
+            S3Action::TransferError {
+                transfer_id,
+                backtrace,
+            } => {
+                if let Some(transfer) = S3ActionHandler::get_transfer(transfer_id, app_state) {
+                    if transfer.cancellation_token.is_cancelled() {
+                        transfer.status = S3TransferStatus::Cancelled;
+                    } else {
+                        transfer.status = S3TransferStatus::Failed;
+                        app_state.status_state.err_message =
+                            TUI_CONFIG.messages.error_s3_transfer.into();
+                        app_state.status_state.err_message_backtrace = backtrace;
+                    }
+                }
+                S3ActionHandler::start_next_transfer(app_state, task_tx);
+            }
+        }
+    }
+
+    async fn handle_preview_object(
+        key: String,
+        version_id: Option<String>,
+        app_state: &mut AppState,
+    ) {
+        app_state.s3_state.preview = None;
+
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            let max_bytes = TUI_CONFIG.s3_preview_size_in_kb * 1024;
+            let object = (key.as_str(), version_id.as_deref());
+            let result = tokio::try_join!(
+                S3Repository::head_object(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    bucket,
+                    object
+                ),
+                S3Repository::get_object_head_bytes(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    bucket,
+                    object,
+                    max_bytes,
+                )
+            );
+
+            match result {
+                Ok((metadata, content)) => {
+                    app_state.s3_state.preview = Some(S3ObjectPreview {
+                        key,
+                        is_truncated: metadata
+                            .content_length
+                            .is_some_and(|content_length| content_length as u64 > max_bytes),
+                        metadata,
+                        content,
+                    });
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_s3_preview.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_presign_object(
+        key: String,
+        method: S3PresignMethod,
+        expires_in: Duration,
+        app_state: &mut AppState,
+    ) {
+        app_state.s3_state.presigned_url = None;
+
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            match S3Repository::create_presigned_url(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                (bucket, &key),
+                method,
+                expires_in,
+            )
+            .await
+            {
+                Ok(url) => {
+                    app_state.s3_state.presigned_url = Some(S3PresignedUrl {
+                        key,
+                        method,
+                        expires_in,
+                        url,
+                    });
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_s3_presign.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_get_object_versions(key: Option<String>, app_state: &mut AppState) {
+        app_state.s3_state.object_versions = vec![];
+        app_state.s3_state.object_versions_key = key.clone();
+
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            match S3Repository::list_object_versions(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                bucket,
+                &app_state.s3_state.prefix,
+                key.as_deref(),
+            )
+            .await
+            {
+                Ok(object_versions) => {
+                    app_state.s3_state.object_versions = object_versions;
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_s3_versions.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    /// Copies the version over the current object or, with `is_delete_marker`, removes
+    /// the delete marker so the previous version becomes current again.
+    async fn handle_restore_object_version(
+        key: String,
+        version_id: String,
+        is_delete_marker: bool,
+        app_state: &mut AppState,
+    ) {
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            let result = if is_delete_marker {
+                S3Repository::delete_object_version(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    bucket,
+                    &key,
+                    &version_id,
+                )
+                .await
+            } else {
+                S3Repository::restore_object_version(
+                    &app_state.aws_config,
+                    &profile.sdk_config,
+                    bucket,
+                    &key,
+                    &version_id,
+                )
+                .await
+            };
+
+            match result {
+                Ok(_) => {
+                    let versions_key = app_state.s3_state.object_versions_key.clone();
+                    let prefix = app_state.s3_state.prefix.clone();
+                    S3ActionHandler::handle_get_object_versions(versions_key, app_state).await;
+                    S3ActionHandler::handle_list_objects(prefix, app_state).await;
+                    app_state.status_state.message =
+                        format!("{} {}", TUI_CONFIG.messages.s3_version_restored, key);
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_s3_restore.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    fn get_transfer(transfer_id: usize, app_state: &mut AppState) -> Option<&mut S3Transfer> {
+        app_state
+            .s3_state
+            .transfers
+            .iter_mut()
+            .find(|transfer| transfer.id == transfer_id)
+    }
+
+    fn queue_transfer(
+        direction: S3TransferDirection,
+        (key, version_id): (String, Option<String>),
+        local_path: PathBuf,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        let s3_state = &mut app_state.s3_state;
+        if let Some(bucket) = &s3_state.selected_bucket {
+            // the size of a single object is known from the listing, prefixes are listed first
+            let bytes_total = match &version_id {
+                Some(version_id) => s3_state
+                    .object_versions
+                    .iter()
+                    .find(|version| {
+                        version.key == key && version.version_id.as_ref() == Some(version_id)
+                    })
+                    .and_then(|version| version.size),
+                None => s3_state
+                    .objects
+                    .iter()
+                    .find(|object| object.key == key && !object.is_prefix)
+                    .and_then(|object| object.size),
+            }
+            .map(|size| size as u64);
+
+            s3_state.transfers.push(S3Transfer {
+                id: s3_state.next_transfer_id,
+                direction,
+                bucket: bucket.clone(),
+                key,
+                version_id,
+                base_prefix: s3_state.prefix.clone(),
+                local_path,
+                bytes_done: 0,
+                bytes_total: match direction {
+                    S3TransferDirection::Download => bytes_total,
+                    S3TransferDirection::Upload => None,
+                },
+                status: S3TransferStatus::Queued,
+                cancellation_token: CancellationToken::new(),
+                sync_plan: None,
+                target_sdk_config: None,
+            });
+            s3_state.next_transfer_id += 1;
+
+            S3ActionHandler::start_next_transfer(app_state, task_tx);
+        }
+    }
+
+    fn start_next_transfer(app_state: &mut AppState, task_tx: &UnboundedSender<Action>) {
+        let transfers = &mut app_state.s3_state.transfers;
+        if transfers
+            .iter()
+            .any(|transfer| transfer.status == S3TransferStatus::Running)
+        {
+            return;
+        }
+
+        if let (Some(profile), Some(transfer)) = (
+            &app_state.active_profile,
+            transfers
+                .iter_mut()
+                .find(|transfer| transfer.status == S3TransferStatus::Queued),
+        ) {
+            transfer.status = S3TransferStatus::Running;
+            tokio::spawn(S3ActionHandler::run_transfer(
+                app_state.aws_config.clone(),
+                profile.sdk_config.clone(),
+                transfer.clone(),
+                task_tx.clone(),
+            ));
+        }
+    }
+
+    async fn run_transfer(
+        aws_config: AWSConfig,
+        sdk_config: SdkConfig,
+        transfer: S3Transfer,
+        task_tx: UnboundedSender<Action>,
+    ) {
+        let result = async {
+            let operations = match (&transfer.sync_plan, transfer.direction) {
+                (Some(sync_plan), _) => sync_plan.operations.clone(),
+                (None, S3TransferDirection::Download) => {
+                    let objects = if transfer.key.ends_with('/') {
+                        S3Repository::list_all_objects(
+                            &aws_config,
+                            &sdk_config,
+                            &transfer.bucket,
+                            &transfer.key,
+                        )
+                        .await?
+                    } else {
+                        vec![S3Object {
+                            key: transfer.key.clone(),
+                            size: transfer.bytes_total.map(|size| size as i64),
+                            ..S3Object::default()
+                        }]
+                    };
+                    plan_download(&objects, &transfer.base_prefix, &transfer.local_path)
+                        .into_iter()
+                        .map(|item| S3TransferOperation::Download {
+                            bucket: transfer.bucket.clone(),
+                            key: item.key,
+                            path: item.path,
+                            size: item.size,
+                        })
+                        .collect()
+                }
+                (None, S3TransferDirection::Upload) => {
+                    plan_upload(&transfer.local_path, &transfer.key)?
+                        .into_iter()
+                        .map(|item| S3TransferOperation::Upload {
+                            path: item.path,
+                            bucket: transfer.bucket.clone(),
+                            key: item.key,
+                            size: item.size,
+                        })
+                        .collect()
+                }
+            };
+
+            let bytes_total = Some(operations.iter().map(get_operation_size).sum::<u64>());
+            let _ = task_tx.send(Action::S3 {
+                action: S3Action::SetTransferProgress {
+                    transfer_id: transfer.id,
+                    bytes_done: 0,
+                    bytes_total,
+                },
+            });
+            let mut bytes_done = 0;
+            let mut bytes_reported = 0;
+            let mut on_progress = |bytes: u64| {
+                bytes_done += bytes;
+                if bytes_done - bytes_reported >= PROGRESS_REPORT_BYTES {
+                    bytes_reported = bytes_done;
+                    let _ = task_tx.send(Action::S3 {
+                        action: S3Action::SetTransferProgress {
+                            transfer_id: transfer.id,
+                            bytes_done,
+                            bytes_total,
+                        },
+                    });
+                }
+            };
+
+            // copies and deletes of a sync go from the changed side to the other one
+            let (from_sdk_config, to_sdk_config) = match (
+                &transfer.target_sdk_config,
+                transfer
+                    .sync_plan
+                    .as_ref()
+                    .map(|sync_plan| sync_plan.direction),
+            ) {
+                (Some(target_sdk_config), Some(S3SyncDirection::TargetToSource)) => {
+                    (target_sdk_config, &sdk_config)
+                }
+                (Some(target_sdk_config), _) => (&sdk_config, target_sdk_config),
+                (None, _) => (&sdk_config, &sdk_config),
+            };
+
+            for operation in operations {
+                if transfer.cancellation_token.is_cancelled() {
+                    bail!("Transfer cancelled");
+                }
+
+                match operation {
+                    S3TransferOperation::Download {
+                        bucket, key, path, ..
+                    } => {
+                        S3Repository::download_object(
+                            &aws_config,
+                            &sdk_config,
+                            (&bucket, &key, transfer.version_id.as_deref()),
+                            &path,
+                            &transfer.cancellation_token,
+                            &mut on_progress,
+                        )
+                        .await?
+                    }
+                    S3TransferOperation::Upload {
+                        path, bucket, key, ..
+                    } => {
+                        S3Repository::upload_object(
+                            &aws_config,
+                            &sdk_config,
+                            (&bucket, &key),
+                            &path,
+                            &transfer.cancellation_token,
+                            &mut on_progress,
+                        )
+                        .await?
+                    }
+                    S3TransferOperation::Copy {
+                        source_bucket,
+                        source_key,
+                        bucket,
+                        key,
+                        ..
+                    } if transfer.target_sdk_config.is_some() => {
+                        let path = env::temp_dir().join(format!(
+                            "aws-console-tui-sync-{}-{}",
+                            std::process::id(),
+                            transfer.id
+                        ));
+                        let result = async {
+                            S3Repository::download_object(
+                                &aws_config,
+                                from_sdk_config,
+                                (&source_bucket, &source_key, None),
+                                &path,
+                                &transfer.cancellation_token,
+                                &mut on_progress,
+                            )
+                            .await?;
+                            S3Repository::upload_object(
+                                &aws_config,
+                                to_sdk_config,
+                                (&bucket, &key),
+                                &path,
+                                &transfer.cancellation_token,
+                                &mut |_| {},
+                            )
+                            .await
+                        }
+                        .await;
+                        let _ = fs::remove_file(&path).await;
+                        result?
+                    }
+                    S3TransferOperation::Copy {
+                        source_bucket,
+                        source_key,
+                        bucket,
+                        key,
+                        size,
+                    } => {
+                        S3Repository::copy_object(
+                            &aws_config,
+                            &sdk_config,
+                            (&source_bucket, &source_key),
+                            (&bucket, &key),
+                        )
+                        .await?;
+                        on_progress(size);
+                    }
+                    S3TransferOperation::DeleteObject { bucket, key } => {
+                        S3Repository::delete_object(&aws_config, to_sdk_config, &bucket, &key)
+                            .await?
+                    }
+                    S3TransferOperation::DeleteFile { path } => fs::remove_file(&path)
+                        .await
+                        .context(format!("Could not delete file {:?}", path))?,
+                }
+            }
+
+            anyhow::Ok((bytes_done, bytes_total))
+        }
+        .await;
+
+        let action = match result {
+            Ok((bytes_done, bytes_total)) => {
+                let _ = task_tx.send(Action::S3 {
+                    action: S3Action::SetTransferProgress {
+                        transfer_id: transfer.id,
+                        bytes_done,
+                        bytes_total,
+                    },
+                });
+                S3Action::TransferFinished {
+                    transfer_id: transfer.id,
+                }
+            }
+            Err(err) => S3Action::TransferError {
+                transfer_id: transfer.id,
+                backtrace: format!("{:?}", err),
+            },
+        };
+        let _ = task_tx.send(Action::S3 { action });
+    }
+
+    async fn handle_transfer_finished(transfer_id: usize, app_state: &mut AppState) {
+        let Some(transfer) = S3ActionHandler::get_transfer(transfer_id, app_state) else {
+            return;
+        };
+        transfer.status = S3TransferStatus::Finished;
+        let transfer = transfer.clone();
+        let message = match &transfer.sync_plan {
+            Some(sync_plan) => format!(
+                "{} synchronized {}",
+                TUI_CONFIG.messages.s3_transfer_finished, sync_plan.target
+            ),
+            None => format!(
+                "{} {} {}",
+                TUI_CONFIG.messages.s3_transfer_finished,
+                match transfer.direction {
+                    S3TransferDirection::Download => "downloaded to",
+                    S3TransferDirection::Upload => "uploaded",
+                },
+                transfer.local_path.display()
+            ),
+        };
+        let is_shown_prefix = transfer.direction == S3TransferDirection::Upload
+            && app_state.s3_state.selected_bucket.as_ref() == Some(&transfer.bucket)
+            && app_state.s3_state.prefix == transfer.key;
+
+        app_state.status_state.message = message;
+
+        // uploaded objects show up in the listing of the prefix they were uploaded to
+        if is_shown_prefix {
+            let prefix = app_state.s3_state.prefix.clone();
+            S3ActionHandler::handle_list_objects(prefix, app_state).await;
+        }
+
+        // a shown comparison is outdated after its sync
+        if let Some(sync_plan) = transfer.sync_plan {
+            let is_compared = app_state
+                .s3_state
+                .sync_comparison
+                .as_ref()
+                .is_some_and(|comparison| comparison.target == sync_plan.target);
+            if is_compared {
+                S3ActionHandler::handle_compare_sync(sync_plan.target, app_state).await;
+            }
+        }
+    }
+
+    async fn handle_compare_sync(target: S3SyncLocation, app_state: &mut AppState) {
+        app_state.s3_state.sync_comparison = None;
+
+        let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) else {
+            return;
+        };
+        let source_prefix = app_state.s3_state.prefix.clone();
+
+        let result = async {
+            let source_objects = S3Repository::list_all_objects(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                bucket,
+                &source_prefix,
+            )
+            .await?;
+            let source_files = create_sync_files(&source_objects, &source_prefix);
+
+            let (target_bucket, target_files) = match &target {
+                S3SyncLocation::Local { path } => {
+                    let local_files = list_local_files(path)?;
+                    let target_files =
+                        S3ActionHandler::hash_local_files(local_files, &source_files).await?;
+                    (None, target_files)
+                }
+                S3SyncLocation::S3 {
+                    profile_name,
+                    bucket_name,
+                    prefix,
+                } => {
+                    let sdk_config =
+                        S3ActionHandler::get_profile_sdk_config(profile_name.as_deref(), app_state)
+                            .await?;
+                    let target_bucket =
+                        S3Repository::get_bucket(&app_state.aws_config, &sdk_config, bucket_name)
+                            .await;
+                    let target_objects = S3Repository::list_all_objects(
+                        &app_state.aws_config,
+                        &sdk_config,
+                        &target_bucket,
+                        prefix,
+                    )
+                    .await?;
+                    (
+                        Some(target_bucket),
+                        create_sync_files(&target_objects, prefix),
+                    )
+                }
+            };
+
+            anyhow::Ok(S3SyncComparison {
+                source_bucket: bucket.clone(),
+                source_prefix: source_prefix.clone(),
+                target: target.clone(),
+                target_bucket,
+                entries: compare_files(source_files, target_files),
+            })
+        }
+        .await;
+
+        match result {
+            Ok(comparison) => {
+                app_state.s3_state.sync_comparison = Some(comparison);
+                app_state.status_state.err_message = String::default();
+            }
+            Err(err) => {
+                app_state.status_state.err_message = TUI_CONFIG.messages.error_s3_compare.into();
+                app_state.status_state.err_message_backtrace = format!("{:?}", err);
+            }
+        }
+    }
+
+    /// Local files are only hashed if the object of the same path has the same size and
+    /// a known digest, otherwise the size already decides.
+    async fn hash_local_files(
+        local_files: Vec<S3TransferItem>,
+        source_files: &[(String, S3SyncFile)],
+    ) -> anyhow::Result<Vec<(String, S3SyncFile)>> {
+        let source_files = source_files
+            .iter()
+            .map(|(path, file)| (path.as_str(), file))
+            .collect::<HashMap<&str, &S3SyncFile>>();
+        let mut target_files = vec![];
+
+        for local_file in local_files {
+            let is_hashed = source_files
+                .get(local_file.key.as_str())
+                .is_some_and(|file| file.size == local_file.size && file.md5.is_some());
+            let md5 = if is_hashed {
+                let path = local_file.path.clone();
+                Some(tokio::task::spawn_blocking(move || file_md5(&path)).await??)
+            } else {
+                None
+            };
+
+            target_files.push((
+                local_file.key,
+                S3SyncFile {
+                    size: local_file.size,
+                    md5,
+                },
+            ));
+        }
+
+        Ok(target_files)
+    }
+
+    /// The active profile or another one that was selected before or is logged in now.
+    async fn get_profile_sdk_config(
+        profile_name: Option<&str>,
+        app_state: &AppState,
+    ) -> anyhow::Result<SdkConfig> {
+        let active_profile = app_state
+            .active_profile
+            .as_ref()
+            .context("No active profile")?;
+
+        match profile_name {
+            None => Ok(active_profile.sdk_config.clone()),
+            Some(profile_name) if profile_name == active_profile.name => {
+                Ok(active_profile.sdk_config.clone())
+            }
+            Some(profile_name) => match app_state.profile_state.profiles.get(profile_name) {
+                Some(profile) => Ok(profile.sdk_config.clone()),
+                None => {
+                    let profile_source = app_state
+                        .profile_state
+                        .profile_names
+                        .get(profile_name)
+                        .context(format!("Unknown profile {}", profile_name))?;
+                    Ok(LoginRepository::create_aws_config(
+                        profile_name,
+                        profile_source,
+                        &app_state.aws_config,
+                    )
+                    .await)
+                }
+            },
+        }
+    }
+
+    async fn handle_apply_sync(
+        direction: S3SyncDirection,
+        with_delete: bool,
+        app_state: &mut AppState,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        let (Some(profile), Some(comparison)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.sync_comparison,
+        ) else {
+            return;
+        };
+
+        // a server side copy needs one profile with access to both buckets, objects of
+        // another profile are relayed through its own client instead
+        let target_sdk_config = match &comparison.target {
+            S3SyncLocation::S3 {
+                profile_name: Some(profile_name),
+                ..
+            } if *profile_name != profile.name => {
+                match S3ActionHandler::get_profile_sdk_config(Some(profile_name), app_state).await {
+                    Ok(sdk_config) => Some(sdk_config),
+                    Err(err) => {
+                        app_state.status_state.err_message =
+                            TUI_CONFIG.messages.error_s3_sync_profile.into();
+                        app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                        return;
+                    }
+                }
+            }
+            _ => None,
+        };
+        let Some(comparison) = &app_state.s3_state.sync_comparison else {
+            return;
+        };
+
+        let (operations, skipped_paths) = plan_sync(comparison, direction, with_delete);
+        if !skipped_paths.is_empty() {
+            app_state.status_state.err_message = TUI_CONFIG.messages.error_s3_sync_skipped.into();
+            app_state.status_state.err_message_backtrace = format!(
+                "Keys outside of the directory:\n{}",
+                skipped_paths.join("\n")
+            );
+        }
+        if operations.is_empty() {
+            app_state.status_state.message = TUI_CONFIG.messages.s3_sync_nothing.into();
+            return;
+        }
+
+        let transfer = S3Transfer {
+            id: app_state.s3_state.next_transfer_id,
+            direction: match direction {
+                S3SyncDirection::SourceToTarget => S3TransferDirection::Download,
+                S3SyncDirection::TargetToSource => S3TransferDirection::Upload,
+            },
+            bucket: comparison.source_bucket.clone(),
+            key: comparison.source_prefix.clone(),
+            version_id: None,
+            base_prefix: comparison.source_prefix.clone(),
+            local_path: match &comparison.target {
+                S3SyncLocation::Local { path } => path.clone(),
+                S3SyncLocation::S3 { .. } => PathBuf::default(),
+            },
+            bytes_done: 0,
+            bytes_total: Some(operations.iter().map(get_operation_size).sum()),
+            status: S3TransferStatus::Queued,
+            cancellation_token: CancellationToken::new(),
+            sync_plan: Some(S3SyncPlan {
+                target: comparison.target.clone(),
+                direction,
+                operations,
+            }),
+            target_sdk_config,
+        };
+        app_state.s3_state.transfers.push(transfer);
+        app_state.s3_state.next_transfer_id += 1;
+
+        S3ActionHandler::start_next_transfer(app_state, task_tx);
+    }
+
+    async fn handle_get_buckets(app_state: &mut AppState) {
+        if let Some(profile) = &app_state.active_profile {
+            match S3Repository::list_buckets(&app_state.aws_config, &profile.sdk_config).await {
+                Ok(buckets) => {
+                    app_state.s3_state = S3State {
+                        buckets,
+                        transfers: mem::take(&mut app_state.s3_state.transfers),
+                        next_transfer_id: app_state.s3_state.next_transfer_id,
+                        ..S3State::default()
+                    }
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_list_s3_buckets.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                    app_state.s3_state = S3State {
+                        transfers: mem::take(&mut app_state.s3_state.transfers),
+                        next_transfer_id: app_state.s3_state.next_transfer_id,
+                        ..S3State::default()
+                    }
+                }
+            };
+
+            event!(Level::DEBUG, "{:?}", app_state);
+        }
+    }
+
+    async fn handle_get_bucket_config(
+        bucket_name: String,
+        section: S3BucketConfigSection,
+        app_state: &mut AppState,
+    ) {
+        let s3_state = &mut app_state.s3_state;
+        if s3_state.bucket_config.bucket_name != bucket_name {
+            s3_state.bucket_config = S3BucketConfig {
+                bucket_name: bucket_name.clone(),
+                ..S3BucketConfig::default()
+            };
+        }
+        let bucket = s3_state
+            .buckets
+            .iter()
+            .find(|bucket| bucket.name == bucket_name);
+
+        if let (Some(profile), Some(bucket)) = (&app_state.active_profile, bucket) {
+            match S3Repository::get_bucket_config(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                bucket,
+                section,
+            )
+            .await
+            {
+                Ok(value) => {
+                    app_state
+                        .s3_state
+                        .bucket_config
+                        .sections
+                        .insert(section, value);
+                    app_state.status_state.err_message = String::default();
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_s3_bucket_config.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_select_bucket(bucket_name: String, app_state: &mut AppState) {
+        app_state.s3_state.selected_bucket = app_state
+            .s3_state
+            .buckets
+            .iter()
+            .find(|bucket| bucket.name == bucket_name)
+            .cloned();
+
+        S3ActionHandler::handle_list_objects(String::default(), app_state).await;
+    }
+
+    async fn handle_list_objects(prefix: String, app_state: &mut AppState) {
+        let s3_state = &mut app_state.s3_state;
+        s3_state.prefix = prefix;
+        s3_state.objects = vec![];
+        s3_state.objects_next_token = None;
+
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            match S3Repository::list_objects(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                bucket,
+                &app_state.s3_state.prefix,
+                None,
+            )
+            .await
+            {
+                Ok((objects, objects_next_token)) => {
+                    app_state.s3_state.objects = objects;
+                    app_state.s3_state.objects_next_token = objects_next_token;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_list_s3_objects.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+
+    async fn handle_list_more_objects(
+        prefix: String,
+        next_token: String,
+        app_state: &mut AppState,
+    ) {
+        // a page is requested once, requests for an old token or another prefix are dropped
+        if app_state.s3_state.objects_next_token.as_ref() != Some(&next_token)
+            || app_state.s3_state.prefix != prefix
+        {
+            return;
+        }
+
+        if let (Some(profile), Some(bucket)) = (
+            &app_state.active_profile,
+            &app_state.s3_state.selected_bucket,
+        ) {
+            match S3Repository::list_objects(
+                &app_state.aws_config,
+                &profile.sdk_config,
+                bucket,
+                &prefix,
+                Some(next_token),
+            )
+            .await
+            {
+                Ok((objects, objects_next_token)) => {
+                    app_state.s3_state.objects.extend(objects);
+                    app_state.s3_state.objects_next_token = objects_next_token;
+                }
+                Err(err) => {
+                    app_state.status_state.err_message =
+                        TUI_CONFIG.messages.error_list_s3_objects.into();
+                    app_state.status_state.err_message_backtrace = format!("{:?}", err);
+                }
+            }
+        }
+    }
+}
+
+fn get_operation_size(operation: &S3TransferOperation) -> u64 {
+    match operation {
+        S3TransferOperation::Download { size, .. }
+        | S3TransferOperation::Upload { size, .. }
+        | S3TransferOperation::Copy { size, .. } => *size,
+        S3TransferOperation::DeleteObject { .. } | S3TransferOperation::DeleteFile { .. } => 0,
+    }
+}
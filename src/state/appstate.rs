@@ -9,7 +9,10 @@ use crate::{
     ui::tui_config::MenuItemText,
 };
 
-use super::cloud_watch_logs_state::CloudWatchState;
+use super::{
+    cloud_watch_logs_state::CloudWatchState, dynamodb_state::DynamoDbState, ec2_state::Ec2State,
+    eks_state::EksState, s3_state::S3State,
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ComponentType {
@@ -52,6 +55,7 @@ pub struct ProfilesState {
 
 #[derive(Clone, Debug)]
 pub struct RegionsState {
+    #[allow(dead_code)]
     pub region_names: Vec<String>,
 }
 
@@ -61,6 +65,7 @@ pub enum AWSService {
     CloudWatchLogs,
     Eks,
     DynamoDB,
+    Ec2,
     S3,
     ServiceCatalog,
 }
@@ -99,6 +104,15 @@ pub struct StatusState {
     pub err_message: String,
     pub err_message_backtrace: String,
     pub breadcrumbs: Vec<String>,
+    pub progress: Option<ProgressState>,
+}
+
+/// Progress of a long running background task, `total` is unknown while paging.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProgressState {
+    pub title: String,
+    pub current: u64,
+    pub total: Option<u64>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -116,11 +130,16 @@ pub struct AppState {
     pub is_expanded: bool,
     pub active_profile: Option<Profile>,
     pub profile_state: ProfilesState,
+    #[allow(dead_code)]
     pub region_state: RegionsState,
     pub toolbar_state: ToolbarState,
     pub status_state: StatusState,
     pub measure_state: MeasureState,
     pub cloud_watch_state: CloudWatchState,
+    pub s3_state: S3State,
+    pub ec2_state: Ec2State,
+    pub eks_state: EksState,
+    pub dynamodb_state: DynamoDbState,
 }
 
 impl AppState {
@@ -152,12 +171,17 @@ impl AppState {
                 err_message: "".into(),
                 err_message_backtrace: "".into(),
                 breadcrumbs: vec![],
+                progress: None,
             },
             measure_state: MeasureState {
                 is_active: !app_config.performance.is_empty(),
                 ..Default::default()
             },
             cloud_watch_state: CloudWatchState::default(),
+            s3_state: S3State::default(),
+            ec2_state: Ec2State::default(),
+            eks_state: EksState::default(),
+            dynamodb_state: DynamoDbState::default(),
         }
     }
 }
@@ -1,9 +1,28 @@
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+
 #[derive(Clone, Debug, Default)]
 pub struct CloudWatchState {
     pub log_groups: Vec<CloudWatchLogGroup>,
+    pub log_groups_next_token: Option<String>,
+    pub log_group_name_filter: Option<CloudWatchLogGroupNameFilter>,
     pub selected_log_group: Option<CloudWatchLogGroup>,
+    pub selected_log_stream: Option<CloudWatchLogStream>,
+    pub log_events: Vec<CloudWatchLogEvent>,
+    pub live_tail: Option<CloudWatchLiveTail>,
+    pub next_live_tail_id: usize,
+    pub insights: CloudWatchInsightsState,
+    pub log_group_filters: Option<CloudWatchLogGroupFilters>,
+}
+
+/// Server side name filters, DescribeLogGroups accepts only one of them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CloudWatchLogGroupNameFilter {
+    Prefix(String),
+    Pattern(String),
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, Default)]
 pub struct CloudWatchLogGroup {
     pub arn: String,
@@ -12,8 +31,10 @@ pub struct CloudWatchLogGroup {
     pub retention_days: Option<i32>,
     pub stored_bytes: Option<i64>,
     pub log_streams: Vec<CloudWatchLogStream>,
+    pub log_streams_next_token: Option<String>,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, Default)]
 pub struct CloudWatchLogStream {
     pub arn: String,
@@ -24,3 +45,103 @@ pub struct CloudWatchLogStream {
     pub last_ingestion_time: Option<i64>,
     pub stored_bytes: Option<i64>,
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchLogEvent {
+    pub event_id: Option<String>,
+    pub log_stream_name: Option<String>,
+    pub timestamp: Option<i64>,
+    pub ingestion_time: Option<i64>,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchLogGroupFilters {
+    pub log_group_name: String,
+    pub metric_filters: Vec<CloudWatchMetricFilter>,
+    pub subscription_filters: Vec<CloudWatchSubscriptionFilter>,
+    pub test_matches: Option<Vec<CloudWatchFilterMatch>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchMetricFilter {
+    pub filter_name: Option<String>,
+    pub filter_pattern: Option<String>,
+    pub metric_transformations: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchSubscriptionFilter {
+    pub filter_name: Option<String>,
+    pub filter_pattern: Option<String>,
+    pub destination_arn: Option<String>,
+    pub distribution: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchFilterMatch {
+    pub event_number: i64,
+    pub event_message: Option<String>,
+    pub extracted_values: Vec<(String, String)>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CloudWatchExportFormat {
+    #[default]
+    Text,
+    JsonLines,
+    Csv,
+}
+
+impl CloudWatchExportFormat {
+    pub fn next(self) -> Self {
+        match self {
+            CloudWatchExportFormat::Text => CloudWatchExportFormat::JsonLines,
+            CloudWatchExportFormat::JsonLines => CloudWatchExportFormat::Csv,
+            CloudWatchExportFormat::Csv => CloudWatchExportFormat::Text,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            CloudWatchExportFormat::Text => "txt",
+            CloudWatchExportFormat::JsonLines => "jsonl",
+            CloudWatchExportFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CloudWatchLiveTail {
+    pub id: usize,
+    pub log_group_name: String,
+    pub log_stream_names: Vec<String>,
+    pub cancellation_token: CancellationToken,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchInsightsState {
+    pub query_id: Option<String>,
+    pub status: String,
+    pub records_matched: Option<f64>,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    pub saved_queries: Vec<CloudWatchSavedQuery>,
+    pub cancellation_token: Option<CancellationToken>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CloudWatchInsightsResults {
+    pub status: String,
+    pub is_finished: bool,
+    pub records_matched: Option<f64>,
+    pub rows: Vec<Vec<(String, String)>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CloudWatchSavedQuery {
+    pub name: String,
+    pub query_string: String,
+    pub log_group_names: Vec<String>,
+}
@@ -0,0 +1,120 @@
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct DynamoDbState {
+    pub tables: Vec<DynamoDbTable>,
+    /// The items of the last scan or query in DynamoDB JSON, each with its attribute types.
+    pub items: Vec<Value>,
+    /// The query of the items, kept to fetch their next page.
+    pub query: Option<DynamoDbQuery>,
+    /// The key to continue the scan or query with, unset on its last page.
+    pub last_evaluated_key: Option<Value>,
+    pub statement_state: DynamoDbStatementState,
+}
+
+/// The PartiQL console with the result of its last statement.
+#[derive(Clone, Debug, Default)]
+pub struct DynamoDbStatementState {
+    pub statement: Option<String>,
+    pub items: Vec<Value>,
+    pub next_token: Option<String>,
+    /// The executed statements of the session, the latest last.
+    pub history: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbTable {
+    pub table_name: String,
+    pub status: Option<String>,
+    pub item_count: Option<i64>,
+    pub size_bytes: Option<i64>,
+    pub billing_mode: Option<String>,
+    pub partition_key: DynamoDbKey,
+    pub sort_key: Option<DynamoDbKey>,
+    pub indexes: Vec<DynamoDbIndex>,
+}
+
+impl DynamoDbTable {
+    /// The names of the partition and sort key, which identify an item.
+    pub fn key_names(&self) -> Vec<String> {
+        [Some(&self.partition_key), self.sort_key.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|key| key.attribute_name.clone())
+            .collect()
+    }
+}
+
+/// A key attribute with its type `S`, `N` or `B`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbKey {
+    pub attribute_name: String,
+    pub attribute_type: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbIndex {
+    pub index_name: String,
+    pub is_global: bool,
+    pub partition_key: DynamoDbKey,
+    pub sort_key: Option<DynamoDbKey>,
+}
+
+/// A scan of the table or index, or a query when a key condition is given.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbQuery {
+    pub table_name: String,
+    pub index_name: Option<String>,
+    pub key_condition: Option<DynamoDbKeyCondition>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbKeyCondition {
+    pub partition_key: DynamoDbKey,
+    pub partition_key_value: String,
+    pub sort_key_condition: Option<DynamoDbSortKeyCondition>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DynamoDbSortKeyCondition {
+    pub sort_key: DynamoDbKey,
+    pub operator: DynamoDbSortKeyOperator,
+    pub value: String,
+    /// The upper bound of `between`.
+    pub second_value: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DynamoDbExportFormat {
+    #[default]
+    JsonLines,
+    Csv,
+}
+
+impl DynamoDbExportFormat {
+    pub fn next(self) -> Self {
+        match self {
+            DynamoDbExportFormat::JsonLines => DynamoDbExportFormat::Csv,
+            DynamoDbExportFormat::Csv => DynamoDbExportFormat::JsonLines,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            DynamoDbExportFormat::JsonLines => "jsonl",
+            DynamoDbExportFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DynamoDbSortKeyOperator {
+    #[default]
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    BeginsWith,
+    Between,
+}
@@ -0,0 +1,201 @@
+use serde_json::Value;
+
+#[derive(Clone, Debug, Default)]
+pub struct Ec2State {
+    pub instances: Vec<Ec2Instance>,
+    pub security_groups: Vec<Ec2SecurityGroup>,
+    /// The network interfaces are the users of a security group.
+    pub network_interfaces: Vec<Ec2NetworkInterface>,
+    pub network_acls: Vec<Ec2NetworkAcl>,
+    pub vpcs: Vec<Ec2Vpc>,
+    pub volumes: Vec<Ec2Volume>,
+    pub snapshots: Vec<Ec2Snapshot>,
+    pub images: Vec<Ec2Image>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Instance {
+    pub instance_id: String,
+    pub name: Option<String>,
+    pub state: Option<String>,
+    pub instance_type: Option<String>,
+    pub private_ip_address: Option<String>,
+    pub public_ip_address: Option<String>,
+    pub availability_zone: Option<String>,
+    pub launch_time: Option<i64>,
+    /// The complete DescribeInstances record for the detail pane.
+    pub details: Value,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ec2InstanceCommand {
+    Start,
+    Stop,
+    Reboot,
+    Terminate,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2SecurityGroup {
+    pub group_id: String,
+    pub group_name: String,
+    pub description: Option<String>,
+    pub vpc_id: Option<String>,
+    pub inbound_rules: Vec<Ec2SecurityGroupRule>,
+    pub outbound_rules: Vec<Ec2SecurityGroupRule>,
+}
+
+/// A permission of a security group for a single peer, a CIDR block, prefix list or group.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2SecurityGroupRule {
+    pub protocol: String,
+    pub from_port: Option<i32>,
+    pub to_port: Option<i32>,
+    pub peer: String,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2NetworkInterface {
+    pub network_interface_id: String,
+    pub description: Option<String>,
+    pub interface_type: Option<String>,
+    pub instance_id: Option<String>,
+    pub private_ip_address: Option<String>,
+    pub subnet_id: Option<String>,
+    pub group_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2NetworkAcl {
+    pub network_acl_id: String,
+    pub vpc_id: Option<String>,
+    pub is_default: bool,
+    pub subnet_ids: Vec<String>,
+    pub entries: Vec<Ec2NetworkAclEntry>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2NetworkAclEntry {
+    pub rule_number: i32,
+    pub is_egress: bool,
+    pub protocol: String,
+    pub is_allow: bool,
+    pub cidr_block: String,
+    pub from_port: Option<i32>,
+    pub to_port: Option<i32>,
+}
+
+/// A VPC with everything that decides where the traffic of its subnets goes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Vpc {
+    pub vpc_id: String,
+    pub name: Option<String>,
+    pub state: Option<String>,
+    pub is_default: bool,
+    pub cidr_blocks: Vec<String>,
+    pub subnets: Vec<Ec2Subnet>,
+    pub route_tables: Vec<Ec2RouteTable>,
+    pub internet_gateways: Vec<Ec2InternetGateway>,
+    pub nat_gateways: Vec<Ec2NatGateway>,
+    pub endpoints: Vec<Ec2VpcEndpoint>,
+    pub peering_connections: Vec<Ec2VpcPeeringConnection>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Subnet {
+    pub subnet_id: String,
+    pub name: Option<String>,
+    pub cidr_block: Option<String>,
+    pub availability_zone: Option<String>,
+    pub available_ip_address_count: Option<i32>,
+    /// The explicitly associated route table or else the main table of the VPC.
+    pub route_table_id: Option<String>,
+    /// Public subnets route to an internet gateway.
+    pub is_public: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2RouteTable {
+    pub route_table_id: String,
+    pub name: Option<String>,
+    pub is_main: bool,
+    pub subnet_ids: Vec<String>,
+    pub routes: Vec<Ec2Route>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Route {
+    pub destination: String,
+    pub target: String,
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2InternetGateway {
+    pub internet_gateway_id: String,
+    pub name: Option<String>,
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2NatGateway {
+    pub nat_gateway_id: String,
+    pub subnet_id: Option<String>,
+    pub public_ip: Option<String>,
+    pub connectivity_type: Option<String>,
+    pub state: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2VpcEndpoint {
+    pub vpc_endpoint_id: String,
+    pub service_name: Option<String>,
+    pub endpoint_type: Option<String>,
+    pub state: Option<String>,
+}
+
+/// Both sides of a peering connection as `vpc_id (cidr)`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2VpcPeeringConnection {
+    pub peering_connection_id: String,
+    pub requester: String,
+    pub accepter: String,
+    pub status: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Volume {
+    pub volume_id: String,
+    pub name: Option<String>,
+    /// The size in GiB.
+    pub size: Option<i32>,
+    pub volume_type: Option<String>,
+    pub state: Option<String>,
+    pub availability_zone: Option<String>,
+    pub instance_id: Option<String>,
+    pub device: Option<String>,
+    pub create_time: Option<i64>,
+}
+
+/// A snapshot owned by the account.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Snapshot {
+    pub snapshot_id: String,
+    pub volume_id: Option<String>,
+    /// The size in GiB.
+    pub volume_size: Option<i32>,
+    pub state: Option<String>,
+    pub description: Option<String>,
+    pub start_time: Option<i64>,
+}
+
+/// An AMI owned by the account with the snapshots of its block devices.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ec2Image {
+    pub image_id: String,
+    pub name: Option<String>,
+    pub state: Option<String>,
+    pub creation_date: Option<String>,
+    pub snapshot_ids: Vec<String>,
+}
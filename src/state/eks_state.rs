@@ -0,0 +1,58 @@
+#[derive(Clone, Debug, Default)]
+pub struct EksState {
+    pub clusters: Vec<EksCluster>,
+    /// The cluster the node groups, Fargate profiles and add-ons belong to.
+    pub selected_cluster: Option<EksCluster>,
+    pub node_groups: Vec<EksNodeGroup>,
+    pub fargate_profiles: Vec<EksFargateProfile>,
+    pub addons: Vec<EksAddon>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EksCluster {
+    pub name: String,
+    pub arn: Option<String>,
+    pub version: Option<String>,
+    pub status: Option<String>,
+    pub endpoint: Option<String>,
+    pub platform_version: Option<String>,
+    /// The base64 encoded certificate of the cluster CA, written to the kubeconfig.
+    pub certificate_authority_data: Option<String>,
+    pub created_at: Option<i64>,
+    pub node_group_names: Vec<String>,
+    pub fargate_profile_names: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EksNodeGroup {
+    pub name: String,
+    pub status: Option<String>,
+    pub version: Option<String>,
+    pub release_version: Option<String>,
+    pub capacity_type: Option<String>,
+    pub ami_type: Option<String>,
+    pub instance_types: Vec<String>,
+    pub min_size: Option<i32>,
+    pub desired_size: Option<i32>,
+    pub max_size: Option<i32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EksFargateProfile {
+    pub name: String,
+    pub status: Option<String>,
+    pub pod_execution_role_arn: Option<String>,
+    /// The namespaces with their labels as `namespace {key=value}`.
+    pub selectors: Vec<String>,
+    pub subnets: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EksAddon {
+    pub name: String,
+    pub version: Option<String>,
+    pub status: Option<String>,
+    /// The versions newer than the installed one for the Kubernetes version of the
+    /// cluster, the newest first.
+    pub available_updates: Vec<String>,
+}
@@ -14,7 +14,9 @@ use crate::{
     config::app_config::AppConfig,
     state::action_handlers::{
         cloud_watch_logs_action_handler::CloudWatchLogsActionHandler,
-        profile_action_handler::ProfileActionHandler, region_action_handler::RegionActionHandler,
+        dynamodb_action_handler::DynamoDbActionHandler, ec2_action_handler::Ec2ActionHandler,
+        eks_action_handler::EksActionHandler, profile_action_handler::ProfileActionHandler,
+        region_action_handler::RegionActionHandler, s3_action_handler::S3ActionHandler,
         service_action_handler::ServiceActionHandler,
     },
     ui::tui_config::TUI_CONFIG,
@@ -52,6 +54,9 @@ impl StateManager {
         sys_info.refresh_cpu_specifics(CpuRefreshKind::new().with_cpu_usage());
         sys_info.refresh_memory();
 
+        // background tasks report their results as actions through this channel
+        let (task_tx, mut task_rx) = mpsc::unbounded_channel::<Action>();
+
         // set the initial state once
         self.state_tx.send(app_state.clone())?;
 
@@ -71,24 +76,11 @@ impl StateManager {
                 }
 
                 Some(action) = action_rx.recv() => {
-                    let start = Instant::now();
-                    let mut mut_app_state = app_state.write().await;
-                    match action {
-                        Action::SetFocus { component_type } => {
-                            mut_app_state.focus_component = component_type;
-
-                        },
-                        Action::SetTerminalArea { area } => { mut_app_state.area = area },
-                        Action::ToggleSidePane => { mut_app_state.is_expanded = !mut_app_state.is_expanded },
-                        Action::SetBreadcrumbs { breadcrumbs } => {mut_app_state.status_state.breadcrumbs = breadcrumbs },
-                        Action::SetMenu { menu_items } => { mut_app_state.toolbar_state.menu_items = menu_items },
-                        Action::RenderDuration{ duration } => { mut_app_state.measure_state.render_duration = format!("{:?}", duration) },
-                        Action::Profile{ action } => { ProfileActionHandler::handle(action, &mut mut_app_state).await },
-                        Action::Region{action} => { RegionActionHandler::handle(action, &mut mut_app_state) },
-                        Action::Service{ action }=>{ ServiceActionHandler::handle( action, &mut mut_app_state).await },
-                        Action::CloudWatchLogs {action} =>{ CloudWatchLogsActionHandler::handle(action, &mut mut_app_state).await },
-                    }
-                    mut_app_state.measure_state.action_duration = format!("{:?}", start.elapsed());
+                    self.handle_action(action, &app_state, &task_tx).await;
+                }
+
+                Some(action) = task_rx.recv() => {
+                    self.handle_action(action, &app_state, &task_tx).await;
                 }
             }
 
@@ -96,4 +88,48 @@ impl StateManager {
         }
         Ok(())
     }
+
+    async fn handle_action(
+        &self,
+        action: Action,
+        app_state: &Arc<RwLock<AppState>>,
+        task_tx: &UnboundedSender<Action>,
+    ) {
+        let start = Instant::now();
+        let mut mut_app_state = app_state.write().await;
+        match action {
+            Action::SetFocus { component_type } => {
+                mut_app_state.focus_component = component_type;
+            }
+            Action::SetTerminalArea { area } => mut_app_state.area = area,
+            Action::ToggleSidePane => mut_app_state.is_expanded = !mut_app_state.is_expanded,
+            Action::SetBreadcrumbs { breadcrumbs } => {
+                mut_app_state.status_state.breadcrumbs = breadcrumbs
+            }
+            Action::SetMenu { menu_items } => mut_app_state.toolbar_state.menu_items = menu_items,
+            Action::SetProgress { progress } => mut_app_state.status_state.progress = progress,
+            Action::RenderDuration { duration } => {
+                mut_app_state.measure_state.render_duration = format!("{:?}", duration)
+            }
+            Action::Profile { action } => {
+                ProfileActionHandler::handle(action, &mut mut_app_state).await
+            }
+            Action::Region { action } => RegionActionHandler::handle(action, &mut mut_app_state),
+            Action::Service { action } => {
+                ServiceActionHandler::handle(action, &mut mut_app_state).await
+            }
+            Action::CloudWatchLogs { action } => {
+                CloudWatchLogsActionHandler::handle(action, &mut mut_app_state, task_tx).await
+            }
+            Action::S3 { action } => {
+                S3ActionHandler::handle(action, &mut mut_app_state, task_tx).await
+            }
+            Action::Ec2 { action } => Ec2ActionHandler::handle(action, &mut mut_app_state).await,
+            Action::Eks { action } => EksActionHandler::handle(action, &mut mut_app_state).await,
+            Action::DynamoDb { action } => {
+                DynamoDbActionHandler::handle(action, &mut mut_app_state, task_tx).await
+            }
+        }
+        mut_app_state.measure_state.action_duration = format!("{:?}", start.elapsed());
+    }
 }
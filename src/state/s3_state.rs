@@ -0,0 +1,288 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+    time::Duration,
+};
+
+use aws_config::SdkConfig;
+use serde_json::Value;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Clone, Debug, Default)]
+pub struct S3State {
+    pub buckets: Vec<S3Bucket>,
+    pub selected_bucket: Option<S3Bucket>,
+    pub prefix: String,
+    pub objects: Vec<S3Object>,
+    pub objects_next_token: Option<String>,
+    pub transfers: Vec<S3Transfer>,
+    pub next_transfer_id: usize,
+    pub preview: Option<S3ObjectPreview>,
+    /// The versions of a single key or, without a key, of the current prefix.
+    pub object_versions: Vec<S3ObjectVersion>,
+    pub object_versions_key: Option<String>,
+    pub bucket_config: S3BucketConfig,
+    pub presigned_url: Option<S3PresignedUrl>,
+    pub sync_comparison: Option<S3SyncComparison>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3Bucket {
+    pub name: String,
+    pub creation_date: Option<i64>,
+    pub region: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum S3BucketConfigSection {
+    Versioning,
+    Encryption,
+    PublicAccessBlock,
+    Policy,
+    Cors,
+    Lifecycle,
+    Tags,
+    Logging,
+    Replication,
+}
+
+impl S3BucketConfigSection {
+    pub const ALL: [S3BucketConfigSection; 9] = [
+        S3BucketConfigSection::Versioning,
+        S3BucketConfigSection::Encryption,
+        S3BucketConfigSection::PublicAccessBlock,
+        S3BucketConfigSection::Policy,
+        S3BucketConfigSection::Cors,
+        S3BucketConfigSection::Lifecycle,
+        S3BucketConfigSection::Tags,
+        S3BucketConfigSection::Logging,
+        S3BucketConfigSection::Replication,
+    ];
+}
+
+/// The configuration sections of a bucket fetched so far, `Value::Null` if not configured.
+#[derive(Clone, Debug, Default)]
+pub struct S3BucketConfig {
+    pub bucket_name: String,
+    pub sections: HashMap<S3BucketConfigSection, Value>,
+}
+
+/// An object or, with `is_prefix`, a common prefix that is shown as a folder.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3Object {
+    pub key: String,
+    pub is_prefix: bool,
+    pub size: Option<i64>,
+    pub last_modified: Option<i64>,
+    pub storage_class: Option<String>,
+    pub e_tag: Option<String>,
+}
+
+/// A version of an object in a versioned bucket, delete markers have no size.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3ObjectVersion {
+    pub key: String,
+    pub version_id: Option<String>,
+    pub is_latest: bool,
+    pub is_delete_marker: bool,
+    pub size: Option<i64>,
+    pub last_modified: Option<i64>,
+    pub storage_class: Option<String>,
+    pub e_tag: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3ObjectMetadata {
+    pub content_length: Option<i64>,
+    pub content_type: Option<String>,
+    pub content_encoding: Option<String>,
+    pub e_tag: Option<String>,
+    pub storage_class: Option<String>,
+    pub last_modified: Option<i64>,
+    pub server_side_encryption: Option<String>,
+    pub sse_kms_key_id: Option<String>,
+    pub user_metadata: Vec<(String, String)>,
+}
+
+/// The metadata and the first bytes of an object.
+#[derive(Clone, Debug, Default)]
+pub struct S3ObjectPreview {
+    pub key: String,
+    pub metadata: S3ObjectMetadata,
+    pub content: Vec<u8>,
+    pub is_truncated: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3PresignMethod {
+    Get,
+    Put,
+}
+
+/// A URL that grants access to a single object without credentials until it expires.
+#[derive(Clone, Debug, PartialEq)]
+pub struct S3PresignedUrl {
+    pub key: String,
+    pub method: S3PresignMethod,
+    pub expires_in: Duration,
+    pub url: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3TransferDirection {
+    Download,
+    Upload,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3TransferStatus {
+    Queued,
+    Running,
+    Finished,
+    Cancelled,
+    Failed,
+}
+
+/// Transfers run one after another in the order they were queued.
+#[derive(Clone, Debug)]
+pub struct S3Transfer {
+    pub id: usize,
+    pub direction: S3TransferDirection,
+    pub bucket: S3Bucket,
+    /// The downloaded object key or prefix, the target prefix of an upload.
+    pub key: String,
+    /// Downloads this version instead of the current one.
+    pub version_id: Option<String>,
+    /// Downloads keep the key path below this prefix.
+    pub base_prefix: String,
+    /// The download directory, the uploaded file or directory.
+    pub local_path: PathBuf,
+    pub bytes_done: u64,
+    pub bytes_total: Option<u64>,
+    pub status: S3TransferStatus,
+    pub cancellation_token: CancellationToken,
+    /// A sync runs its planned operations, its direction tells whether the current
+    /// prefix is changed like by an upload or the target like by a download.
+    pub sync_plan: Option<S3SyncPlan>,
+    /// A sync with a prefix of another profile relays the objects through this client.
+    pub target_sdk_config: Option<SdkConfig>,
+}
+
+impl S3Transfer {
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self.status,
+            S3TransferStatus::Finished | S3TransferStatus::Cancelled | S3TransferStatus::Failed
+        )
+    }
+}
+
+/// A single step of a transfer or sync.
+#[derive(Clone, Debug, PartialEq)]
+pub enum S3TransferOperation {
+    Download {
+        bucket: S3Bucket,
+        key: String,
+        path: PathBuf,
+        size: u64,
+    },
+    Upload {
+        path: PathBuf,
+        bucket: S3Bucket,
+        key: String,
+        size: u64,
+    },
+    Copy {
+        source_bucket: S3Bucket,
+        source_key: String,
+        bucket: S3Bucket,
+        key: String,
+        size: u64,
+    },
+    DeleteObject {
+        bucket: S3Bucket,
+        key: String,
+    },
+    DeleteFile {
+        path: PathBuf,
+    },
+}
+
+/// The other side of a comparison, prefixes of other profiles can be compared as well.
+#[derive(Clone, Debug, PartialEq)]
+pub enum S3SyncLocation {
+    Local {
+        path: PathBuf,
+    },
+    S3 {
+        profile_name: Option<String>,
+        bucket_name: String,
+        prefix: String,
+    },
+}
+
+impl Display for S3SyncLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            S3SyncLocation::Local { path } => write!(f, "{}", path.display()),
+            S3SyncLocation::S3 {
+                profile_name: Some(profile_name),
+                bucket_name,
+                prefix,
+            } => write!(f, "{}:s3://{}/{}", profile_name, bucket_name, prefix),
+            S3SyncLocation::S3 {
+                profile_name: None,
+                bucket_name,
+                prefix,
+            } => write!(f, "s3://{}/{}", bucket_name, prefix),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3SyncStatus {
+    SourceOnly,
+    TargetOnly,
+    Changed,
+    Unchanged,
+}
+
+/// The MD5 digest is only known for objects that were not uploaded in parts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct S3SyncFile {
+    pub size: u64,
+    pub md5: Option<String>,
+}
+
+/// A file path relative to the compared prefix or directory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct S3SyncEntry {
+    pub path: String,
+    pub source: Option<S3SyncFile>,
+    pub target: Option<S3SyncFile>,
+    pub status: S3SyncStatus,
+}
+
+/// The current prefix, the source, compared with a local directory or another prefix.
+#[derive(Clone, Debug)]
+pub struct S3SyncComparison {
+    pub source_bucket: S3Bucket,
+    pub source_prefix: String,
+    pub target: S3SyncLocation,
+    pub target_bucket: Option<S3Bucket>,
+    pub entries: Vec<S3SyncEntry>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3SyncDirection {
+    SourceToTarget,
+    TargetToSource,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct S3SyncPlan {
+    pub target: S3SyncLocation,
+    pub direction: S3SyncDirection,
+    pub operations: Vec<S3TransferOperation>,
+}
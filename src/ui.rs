@@ -1,3 +1,4 @@
+pub mod clipboard;
 pub mod component;
 pub mod logo;
 pub mod manager;
@@ -0,0 +1,31 @@
+use std::io::{stdout, Write};
+
+use anyhow::Context;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Copies the text to the system clipboard through the terminal with an OSC 52 escape
+/// sequence, this also works in SSH sessions. Terminals may have to allow it first.
+pub fn copy_to_clipboard(text: &str) -> anyhow::Result<()> {
+    let mut stdout = stdout();
+    stdout
+        .write_all(create_osc52_sequence(text).as_bytes())
+        .and_then(|_| stdout.flush())
+        .context("Could not copy to the clipboard")
+}
+
+fn create_osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_text_as_osc52_sequence() {
+        assert_eq!(
+            create_osc52_sequence("https://example.com/?a=1"),
+            "\x1b]52;c;aHR0cHM6Ly9leGFtcGxlLmNvbS8/YT0x\x07"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use chrono::{DateTime, SecondsFormat};
 use crossterm::event::KeyEvent;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -14,8 +15,12 @@ use crate::state::{
 
 pub mod base;
 pub mod cloud_watch_logs;
+pub mod dynamodb;
+pub mod ec2;
+pub mod eks;
 pub mod profiles;
 pub mod regions;
+pub mod s3;
 pub mod services;
 pub mod status;
 pub mod toolbar;
@@ -41,6 +46,11 @@ pub trait Component {
 
     fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()>;
 
+    /// True while the component edits text, all keys then go to the component.
+    fn is_capturing_input(&self) -> bool {
+        false
+    }
+
     fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState);
 
     fn centered_rect(&self, percent_x: u16, percent_y: u16, rect: Rect) -> Rect {
@@ -62,4 +72,24 @@ pub trait Component {
             ])
             .split(popup_layout[1])[1]
     }
+
+    fn centered_list_rect(&self, percent_x: u16, list_len: usize, rect: Rect) -> Rect {
+        // the list gets a line per element plus the border, limited to half the screen
+        let height = (list_len as u16 + 2).clamp(3, (rect.height / 2).max(3));
+        let width = rect.width * percent_x / 100;
+
+        Rect {
+            x: rect.x + (rect.width - width) / 2,
+            y: rect.y + rect.height.saturating_sub(height) / 2,
+            width,
+            height: height.min(rect.height),
+        }
+    }
+}
+
+fn format_date_time(secs: Option<i64>, fallback: &str) -> String {
+    match secs.and_then(|secs| DateTime::from_timestamp(secs, 0)) {
+        Some(date_time) => date_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+        None => fallback.into(),
+    }
 }
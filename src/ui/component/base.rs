@@ -1,2 +1,4 @@
+pub mod json_highlight;
 pub mod list_component;
 pub mod text_edit_component;
+pub mod text_filter;
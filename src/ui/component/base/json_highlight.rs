@@ -0,0 +1,132 @@
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use serde_json::Value;
+
+use crate::ui::tui_config::TUI_CONFIG;
+
+pub fn highlight_json(text: &str) -> Option<Vec<Line<'static>>> {
+    let value = serde_json::from_str::<Value>(text.trim()).ok()?;
+    if !value.is_object() && !value.is_array() {
+        return None;
+    }
+
+    let pretty_text = serde_json::to_string_pretty(&value).ok()?;
+    Some(pretty_text.lines().map(highlight_line).collect())
+}
+
+fn highlight_line(line: &str) -> Line<'static> {
+    let chars = line.chars().collect::<Vec<char>>();
+    let mut spans = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+        let style = match chars[index] {
+            '"' => {
+                index += 1;
+                while index < chars.len() && chars[index] != '"' {
+                    index += if chars[index] == '\\' { 2 } else { 1 };
+                }
+                index = (index + 1).min(chars.len());
+
+                let is_key = chars[index..]
+                    .iter()
+                    .find(|char| !char.is_whitespace())
+                    .is_some_and(|char| *char == ':');
+                if is_key {
+                    Style::new().fg(TUI_CONFIG.theme.json_key)
+                } else {
+                    Style::new().fg(TUI_CONFIG.theme.json_string)
+                }
+            }
+            '-' | '0'..='9' => {
+                while index < chars.len() && !is_delimiter(chars[index]) {
+                    index += 1;
+                }
+                Style::new().fg(TUI_CONFIG.theme.json_number)
+            }
+            char if char.is_alphabetic() => {
+                while index < chars.len() && !is_delimiter(chars[index]) {
+                    index += 1;
+                }
+                Style::new().fg(TUI_CONFIG.theme.json_literal).italic()
+            }
+            _ => {
+                while index < chars.len() && (is_delimiter(chars[index]) || chars[index] == ':') {
+                    index += 1;
+                }
+                Style::new()
+            }
+        };
+
+        spans.push(Span::styled(
+            chars[start..index].iter().collect::<String>(),
+            style,
+        ));
+    }
+
+    Line::from(spans)
+}
+
+fn is_delimiter(char: char) -> bool {
+    char.is_whitespace() || matches!(char, ',' | '{' | '}' | '[' | ']')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_plain_text_is_not_highlighted() {
+        assert!(highlight_json("START RequestId: 1234 Version: $LATEST").is_none());
+        assert!(highlight_json("42").is_none());
+        assert!(highlight_json("\"just a string\"").is_none());
+    }
+
+    #[test]
+    fn test_json_object_is_pretty_printed() {
+        let lines = highlight_json(r#"{"level":"INFO","count":3}"#).unwrap();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(line_text(&lines[1]), r#"  "level": "INFO","#);
+        assert_eq!(line_text(&lines[2]), r#"  "count": 3"#);
+    }
+
+    #[test]
+    fn test_json_tokens_are_coloured() {
+        let lines = highlight_json(r#"{"key":"value \"quoted\"","n":-1.5,"ok":true}"#).unwrap();
+
+        let key_span = lines[1].spans.iter().find(|span| span.content == "\"key\"");
+        assert_eq!(key_span.unwrap().style.fg, Some(TUI_CONFIG.theme.json_key));
+
+        let string_span = lines[1]
+            .spans
+            .iter()
+            .find(|span| span.content == r#""value \"quoted\"""#);
+        assert_eq!(
+            string_span.unwrap().style.fg,
+            Some(TUI_CONFIG.theme.json_string)
+        );
+
+        let number_span = lines[2].spans.iter().find(|span| span.content == "-1.5");
+        assert_eq!(
+            number_span.unwrap().style.fg,
+            Some(TUI_CONFIG.theme.json_number)
+        );
+
+        let literal_span = lines[3].spans.iter().find(|span| span.content == "true");
+        assert_eq!(
+            literal_span.unwrap().style.fg,
+            Some(TUI_CONFIG.theme.json_literal)
+        );
+    }
+}
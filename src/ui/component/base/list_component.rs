@@ -2,7 +2,7 @@ use std::cmp::min;
 
 use ratatui::{
     style::{Color, Style},
-    text::Text,
+    text::{Line, Text},
     widgets::ListItem,
 };
 
@@ -38,7 +38,8 @@ impl ListComponent<'_> {
     }
 
     pub fn create_list_items(&mut self, list_items: impl IntoIterator<Item = impl Into<String>>) {
-        self.list_items = list_items.into_iter().map(|item| item.into()).collect()
+        self.list_items = list_items.into_iter().map(|item| item.into()).collect();
+        self.tui_list_items = vec![];
     }
 
     pub fn get_selected_index(&self) -> usize {
@@ -76,7 +77,7 @@ impl ListComponent<'_> {
         Some(self.list_items[self.selected_index].clone())
     }
 
-    pub fn create_tui_list(&self) -> Vec<ListItem> {
+    pub fn create_tui_list(&self) -> Vec<ListItem<'_>> {
         if !self.tui_list_items.is_empty() {
             return self.tui_list_items.clone();
         }
@@ -88,7 +89,7 @@ impl ListComponent<'_> {
             .collect::<Vec<ListItem>>()
     }
 
-    pub fn create_text_item(&self, index: usize) -> Text {
+    pub fn create_text_item(&self, index: usize) -> Text<'_> {
         let is_active_index = match self.active_index {
             None => false,
             Some(active_index) => active_index == index,
@@ -103,3 +104,18 @@ impl ListComponent<'_> {
         }
     }
 }
+
+impl<'a> ListComponent<'a> {
+    pub fn create_styled_list_items(&mut self, list_items: Vec<Line<'a>>) {
+        self.list_items = list_items
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect()
+            })
+            .collect();
+        self.tui_list_items = list_items.into_iter().map(ListItem::new).collect();
+    }
+}
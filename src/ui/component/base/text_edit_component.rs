@@ -0,0 +1,144 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    text::Span,
+    widgets::{
+        block::{Position, Title},
+        Block, BorderType, Borders,
+    },
+    Frame,
+};
+use tui_textarea::TextArea;
+
+use crate::ui::tui_config::TUI_CONFIG;
+
+#[derive(Debug, PartialEq)]
+pub enum TextEditEvent {
+    Input,
+    Submit,
+    Cancel,
+}
+
+/// A multi-line editor for documents like JSON. `<ctrl-s>` submits and `<esc>` cancels
+/// the edit, all other keys edit the text. An error, e.g. of the validation of the text
+/// on submit, is shown below the text until the next edit.
+pub struct TextEditComponent<'a> {
+    textarea: TextArea<'a>,
+    title: String,
+    err_message: Option<String>,
+}
+
+impl<'a> TextEditComponent<'a> {
+    pub fn new() -> Self {
+        TextEditComponent {
+            textarea: create_textarea(""),
+            title: String::default(),
+            err_message: None,
+        }
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    pub fn get_text(&self) -> String {
+        self.textarea.lines().join("\n")
+    }
+
+    /// Replaces the text, which also clears the error and the undo history.
+    pub fn set_text(&mut self, text: &str) {
+        self.textarea = create_textarea(text);
+        self.err_message = None;
+    }
+
+    pub fn set_err_message(&mut self, err_message: impl Into<String>) {
+        self.err_message = Some(err_message.into());
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> TextEditEvent {
+        match key.code {
+            KeyCode::Esc => TextEditEvent::Cancel,
+            KeyCode::Char('s') if key.modifiers == KeyModifiers::CONTROL => TextEditEvent::Submit,
+            _ => {
+                if self.textarea.input(key) {
+                    self.err_message = None;
+                }
+                TextEditEvent::Input
+            }
+        }
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, has_focus: bool) {
+        let (row, column) = self.textarea.cursor();
+        let mut block = Block::default()
+            .title(format!(" {} ", self.title))
+            .title(
+                Title::from(format!(" {}:{} ", row + 1, column + 1))
+                    .alignment(Alignment::Right)
+                    .position(Position::Bottom),
+            )
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if has_focus {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        if let Some(err_message) = &self.err_message {
+            block = block.title(
+                Title::from(Span::styled(
+                    format!(" {} ", err_message.replace('\n', " ")),
+                    Style::new().fg(TUI_CONFIG.theme.error_message_text),
+                ))
+                .alignment(Alignment::Left)
+                .position(Position::Bottom),
+            );
+        }
+
+        self.textarea.set_block(block);
+        frame.render_widget(self.textarea.widget(), area);
+    }
+}
+
+fn create_textarea<'a>(text: &str) -> TextArea<'a> {
+    let mut textarea = TextArea::new(text.lines().map(|line| line.to_string()).collect());
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_line_number_style(Style::default().fg(TUI_CONFIG.theme.border));
+    textarea.set_tab_length(2);
+    textarea
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_text_and_clear_error() {
+        let mut text_edit = TextEditComponent::new();
+        text_edit.set_text("{\n  \"id\": 1\n}");
+        text_edit.set_err_message("invalid");
+
+        assert_eq!(
+            text_edit.handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE)),
+            TextEditEvent::Input
+        );
+        assert_eq!(text_edit.get_text(), "x{\n  \"id\": 1\n}");
+        assert!(text_edit.err_message.is_none());
+    }
+
+    #[test]
+    fn test_submit_and_cancel_text() {
+        let mut text_edit = TextEditComponent::new();
+        assert_eq!(
+            text_edit.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            TextEditEvent::Submit
+        );
+        assert_eq!(
+            text_edit.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)),
+            TextEditEvent::Cancel
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use regex::{Regex, RegexBuilder};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FilterMode {
+    #[default]
+    Substring,
+    Glob,
+    Regex,
+}
+
+impl FilterMode {
+    pub fn next(self) -> Self {
+        match self {
+            FilterMode::Substring => FilterMode::Glob,
+            FilterMode::Glob => FilterMode::Regex,
+            FilterMode::Regex => FilterMode::Substring,
+        }
+    }
+}
+
+impl fmt::Display for FilterMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterMode::Substring => write!(f, "substring"),
+            FilterMode::Glob => write!(f, "glob"),
+            FilterMode::Regex => write!(f, "regex"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TextFilter {
+    expression: String,
+    mode: FilterMode,
+    is_case_sensitive: bool,
+    regex: Regex,
+}
+
+impl TextFilter {
+    /// Substring and glob filters ignore case, regex filters are used as written.
+    pub fn new(expression: &str, mode: FilterMode) -> Result<Self, regex::Error> {
+        TextFilter::with_case(expression, mode, mode == FilterMode::Regex)
+    }
+
+    pub fn with_case(
+        expression: &str,
+        mode: FilterMode,
+        is_case_sensitive: bool,
+    ) -> Result<Self, regex::Error> {
+        let pattern = match mode {
+            FilterMode::Substring => regex::escape(expression),
+            FilterMode::Glob => TextFilter::glob_to_regex(expression),
+            FilterMode::Regex => expression.to_string(),
+        };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(!is_case_sensitive)
+            .build()?;
+
+        Ok(TextFilter {
+            expression: expression.to_string(),
+            mode,
+            is_case_sensitive,
+            regex,
+        })
+    }
+
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn mode(&self) -> FilterMode {
+        self.mode
+    }
+
+    pub fn is_case_sensitive(&self) -> bool {
+        self.is_case_sensitive
+    }
+
+    /// Returns the byte ranges of all matches, or `None` if the text does not match.
+    pub fn find_matches(&self, text: &str) -> Option<Vec<(usize, usize)>> {
+        let matches = self
+            .regex
+            .find_iter(text)
+            .filter(|found| !found.is_empty())
+            .map(|found| (found.start(), found.end()))
+            .collect::<Vec<(usize, usize)>>();
+
+        if matches.is_empty() && !self.regex.is_match(text) {
+            None
+        } else {
+            Some(matches)
+        }
+    }
+
+    /// The literal text before the first wildcard of a glob like `/aws/lambda/*`.
+    pub fn glob_prefix(&self) -> Option<&str> {
+        match self.mode {
+            FilterMode::Glob => self
+                .expression
+                .strip_suffix('*')
+                .filter(|prefix| !prefix.contains(['*', '?', '[']) && !prefix.is_empty()),
+            _ => None,
+        }
+    }
+
+    fn glob_to_regex(expression: &str) -> String {
+        let mut pattern = String::from("^");
+        for character in expression.chars() {
+            match character {
+                '*' => pattern.push_str(".*"),
+                '?' => pattern.push('.'),
+                _ => pattern.push_str(&regex::escape(&character.to_string())),
+            }
+        }
+        pattern.push('$');
+        pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_substring_ignoring_case() {
+        let filter = TextFilter::new("Lambda", FilterMode::Substring).unwrap();
+
+        assert_eq!(
+            filter.find_matches("/aws/lambda/my-lambda"),
+            Some(vec![(5, 11), (15, 21)])
+        );
+        assert_eq!(filter.find_matches("/aws/ecs/cluster"), None);
+
+        let filter = TextFilter::with_case("Lambda", FilterMode::Substring, true).unwrap();
+        assert_eq!(
+            filter.find_matches("/aws/lambda/my-Lambda"),
+            Some(vec![(15, 21)])
+        );
+    }
+
+    #[test]
+    fn test_match_whole_name_with_glob() {
+        let filter = TextFilter::new("/aws/*/prod-?", FilterMode::Glob).unwrap();
+
+        assert!(filter.find_matches("/aws/lambda/prod-1").is_some());
+        assert!(filter.find_matches("/aws/lambda/prod-12").is_none());
+        assert_eq!(filter.glob_prefix(), None);
+
+        let filter = TextFilter::new("/aws/lambda/*", FilterMode::Glob).unwrap();
+        assert_eq!(filter.glob_prefix(), Some("/aws/lambda/"));
+    }
+
+    #[test]
+    fn test_match_regex_and_reject_invalid_expressions() {
+        let filter = TextFilter::new("prod-[0-9]+$", FilterMode::Regex).unwrap();
+
+        assert_eq!(filter.find_matches("/app/prod-42"), Some(vec![(5, 12)]));
+        assert_eq!(filter.find_matches("/app/PROD-42"), None);
+        assert!(TextFilter::new("prod-[", FilterMode::Regex).is_err());
+    }
+}
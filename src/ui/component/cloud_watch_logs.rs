@@ -1 +1,14 @@
+use chrono::{DateTime, SecondsFormat};
+
+pub mod cloud_watch_insights;
+pub mod cloud_watch_log_events;
+pub mod cloud_watch_log_group_filters;
 pub mod cloud_watch_log_groups;
+pub mod cloud_watch_log_streams;
+
+fn format_timestamp(timestamp: Option<i64>, fallback: &str) -> String {
+    match timestamp.and_then(|millis| DateTime::from_timestamp(millis.div_euclid(1000), 0)) {
+        Some(date_time) => date_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+        None => fallback.into(),
+    }
+}
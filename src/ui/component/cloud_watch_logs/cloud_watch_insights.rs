@@ -0,0 +1,610 @@
+use std::cmp::Ordering;
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, CloudWatchLogsAction},
+        appstate::{AppState, ComponentType},
+        cloud_watch_logs_state::{CloudWatchInsightsState, CloudWatchSavedQuery},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{
+        Block, BorderType, Borders, Cell, Clear, List, ListState, Paragraph, Row, Table, TableState,
+    },
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+const MAX_COLUMN_WIDTH: usize = 60;
+
+pub struct CloudWatchInsightsComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    log_group_names: Vec<String>,
+    query_textarea: TextArea<'a>,
+    is_editing_query: bool,
+    time_range_index: usize,
+    selected_row: usize,
+    selected_column: usize,
+    column_offset: usize,
+    sort_column: Option<(usize, bool)>,
+    page_height: usize,
+    save_name_textarea: TextArea<'a>,
+    is_saving_query: bool,
+    saved_query_list: ListComponent<'a>,
+    is_loading_query: bool,
+}
+
+impl<'a> Component for CloudWatchInsightsComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        CloudWatchInsightsComponent {
+            action_tx: action_tx.clone(),
+            log_group_names: vec![],
+            query_textarea: CloudWatchInsightsComponent::create_query_textarea(vec![
+                "fields @timestamp, @message".into(),
+                "| sort @timestamp desc".into(),
+                "| limit 100".into(),
+            ]),
+            is_editing_query: false,
+            time_range_index: 2.min(TUI_CONFIG.insights_time_ranges_in_min.len() - 1),
+            selected_row: 0,
+            selected_column: 0,
+            column_offset: 0,
+            sort_column: None,
+            page_height: 0,
+            save_name_textarea: CloudWatchInsightsComponent::create_save_name_textarea(),
+            is_saving_query: false,
+            saved_query_list: ListComponent::new(),
+            is_loading_query: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.cloud_watch_logs.into(),
+                TUI_CONFIG.breadcrumbs.cloud_watch_insights.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![
+                    TUI_CONFIG.menu.save_query.into(),
+                    TUI_CONFIG.menu.load_query.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.edit_query.into(),
+                    TUI_CONFIG.menu.run_query.into(),
+                    TUI_CONFIG.menu.cancel_query.into(),
+                    TUI_CONFIG.menu.time_range.into(),
+                    TUI_CONFIG.menu.sort.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.scroll_horizontal.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_editing_query || self.is_saving_query || self.is_loading_query
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_editing_query {
+            match key.code {
+                KeyCode::Esc => self.is_editing_query = false,
+                KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+                    self.is_editing_query = false;
+                    self.run_query()?;
+                }
+                _ => {
+                    self.query_textarea.input(key);
+                }
+            }
+        } else if self.is_saving_query {
+            match key.code {
+                KeyCode::Esc => self.is_saving_query = false,
+                KeyCode::Enter => self.save_query()?,
+                _ => {
+                    self.save_name_textarea.input(key);
+                }
+            }
+        } else if self.is_loading_query {
+            self.handle_saved_query_key_event(key, app_state)?;
+        } else {
+            let insights = &app_state.cloud_watch_state.insights;
+            match key.code {
+                KeyCode::Char('e') => self.is_editing_query = true,
+                KeyCode::Char('r') => self.run_query()?,
+                KeyCode::Char('k') => self.action_tx.send(Action::CloudWatchLogs {
+                    action: CloudWatchLogsAction::CancelInsightsQuery,
+                })?,
+                KeyCode::Char('+') => {
+                    self.time_range_index = (self.time_range_index + 1)
+                        .min(TUI_CONFIG.insights_time_ranges_in_min.len() - 1)
+                }
+                KeyCode::Char('-') => {
+                    self.time_range_index = self.time_range_index.saturating_sub(1)
+                }
+                KeyCode::Char('s') => self.toggle_sort_column(),
+                KeyCode::Char('w') => {
+                    self.save_name_textarea =
+                        CloudWatchInsightsComponent::create_save_name_textarea();
+                    self.is_saving_query = true;
+                }
+                KeyCode::Char('l') => {
+                    self.action_tx.send(Action::CloudWatchLogs {
+                        action: CloudWatchLogsAction::LoadSavedQueries,
+                    })?;
+                    self.saved_query_list.set_selected_index(0);
+                    self.is_loading_query = true;
+                }
+                val if TUI_CONFIG.list_config.selection_up == val => {
+                    self.selected_row = self.selected_row.saturating_sub(1)
+                }
+                val if TUI_CONFIG.list_config.selection_down == val => {
+                    self.selected_row =
+                        (self.selected_row + 1).min(insights.rows.len().saturating_sub(1))
+                }
+                val if TUI_CONFIG.list_config.page_up == val => {
+                    self.selected_row = self.selected_row.saturating_sub(self.page_height)
+                }
+                val if TUI_CONFIG.list_config.page_down == val => {
+                    self.selected_row = (self.selected_row + self.page_height)
+                        .min(insights.rows.len().saturating_sub(1))
+                }
+                val if TUI_CONFIG.list_config.jump_to_start == val => self.selected_row = 0,
+                val if TUI_CONFIG.list_config.jump_to_end == val => {
+                    self.selected_row = insights.rows.len().saturating_sub(1)
+                }
+                val if TUI_CONFIG.list_config.scroll_left == val => {
+                    self.selected_column = self.selected_column.saturating_sub(1)
+                }
+                val if TUI_CONFIG.list_config.scroll_right == val => {
+                    self.selected_column =
+                        (self.selected_column + 1).min(insights.columns.len().saturating_sub(1))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let insights = &app_state.cloud_watch_state.insights;
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(8),
+                Constraint::Min(3),
+            ])
+            .split(area);
+
+        frame.render_widget(
+            Paragraph::new(self.create_info_text(insights))
+                .block(self.create_block(app_state, " Logs Insights ".into())),
+            layout[0],
+        );
+
+        self.query_textarea.set_block(
+            Block::default()
+                .title(if self.is_editing_query {
+                    " Query (editing, <esc> done, <ctrl-r> run) "
+                } else {
+                    " Query "
+                })
+                .title_alignment(Alignment::Center)
+                .border_style(Style::new().fg(if self.is_editing_query {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                }))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        );
+        frame.render_widget(self.query_textarea.widget(), layout[1]);
+
+        self.render_results(frame, layout[2], app_state);
+
+        if self.is_saving_query {
+            let popup_area = self.centered_rect(40, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(self.save_name_textarea.widget(), popup_area);
+        }
+
+        if self.is_loading_query {
+            self.render_saved_queries(frame, app_state);
+        }
+    }
+}
+
+impl<'a> CloudWatchInsightsComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn set_log_group_names(&mut self, log_group_names: Vec<String>) {
+        self.log_group_names = log_group_names;
+        self.selected_row = 0;
+        self.selected_column = 0;
+        self.column_offset = 0;
+        self.sort_column = None;
+    }
+
+    fn create_query_textarea(lines: Vec<String>) -> TextArea<'a> {
+        let mut query_textarea = TextArea::new(lines);
+        query_textarea.set_cursor_line_style(Style::default());
+        query_textarea.set_placeholder_text("Enter Logs Insights query");
+        query_textarea
+    }
+
+    fn create_save_name_textarea() -> TextArea<'a> {
+        let mut save_name_textarea = TextArea::default();
+        save_name_textarea.set_cursor_line_style(Style::default());
+        save_name_textarea.set_placeholder_text("Enter query name");
+        save_name_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Save query")
+                .title_alignment(Alignment::Center),
+        );
+        save_name_textarea
+    }
+
+    fn get_time_range_in_min(&self) -> u64 {
+        TUI_CONFIG.insights_time_ranges_in_min[self.time_range_index]
+    }
+
+    fn run_query(&mut self) -> anyhow::Result<()> {
+        let query_string = self.query_textarea.lines().join("\n");
+        if query_string.trim().is_empty() || self.log_group_names.is_empty() {
+            return Ok(());
+        }
+
+        let end_time = Utc::now().timestamp();
+        let start_time = end_time - (self.get_time_range_in_min() * 60) as i64;
+        self.selected_row = 0;
+        self.sort_column = None;
+
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::RunInsightsQuery {
+                log_group_names: self.log_group_names.clone(),
+                query_string,
+                time_range: (start_time, end_time),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    fn save_query(&mut self) -> anyhow::Result<()> {
+        let name = self.save_name_textarea.lines().join(" ").trim().to_string();
+        if name.is_empty() {
+            return Ok(());
+        }
+
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::SaveQuery {
+                saved_query: CloudWatchSavedQuery {
+                    name,
+                    query_string: self.query_textarea.lines().join("\n"),
+                    log_group_names: self.log_group_names.clone(),
+                },
+            },
+        })?;
+        self.is_saving_query = false;
+
+        Ok(())
+    }
+
+    fn handle_saved_query_key_event(
+        &mut self,
+        key: KeyEvent,
+        app_state: &AppState,
+    ) -> anyhow::Result<()> {
+        let saved_queries = &app_state.cloud_watch_state.insights.saved_queries;
+        let selected_query = saved_queries.get(self.saved_query_list.get_selected_index());
+
+        match key.code {
+            KeyCode::Esc => self.is_loading_query = false,
+            KeyCode::Char('d') => {
+                if let Some(saved_query) = selected_query {
+                    self.action_tx.send(Action::CloudWatchLogs {
+                        action: CloudWatchLogsAction::DeleteSavedQuery {
+                            name: saved_query.name.clone(),
+                        },
+                    })?;
+                    self.saved_query_list.move_up();
+                }
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.saved_query_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.saved_query_list.has_list_elements() =>
+            {
+                self.saved_query_list.move_down()
+            }
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                if let Some(saved_query) = selected_query {
+                    self.query_textarea = CloudWatchInsightsComponent::create_query_textarea(
+                        saved_query.query_string.lines().map(String::from).collect(),
+                    );
+                    if !saved_query.log_group_names.is_empty() {
+                        self.set_log_group_names(saved_query.log_group_names.clone());
+                    }
+                    self.is_loading_query = false;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn toggle_sort_column(&mut self) {
+        self.sort_column = match self.sort_column {
+            Some((column, is_descending)) if column == self.selected_column => {
+                Some((column, !is_descending))
+            }
+            _ => Some((self.selected_column, false)),
+        };
+        self.selected_row = 0;
+    }
+
+    fn create_info_text(&self, insights: &CloudWatchInsightsState) -> String {
+        let records_matched = match insights.records_matched {
+            Some(records_matched) => format!(", {} records matched", records_matched),
+            None => String::default(),
+        };
+
+        format!(
+            "{}  |  last {}  |  {}{}",
+            if self.log_group_names.is_empty() {
+                "no log groups".into()
+            } else {
+                self.log_group_names.join(", ")
+            },
+            format_minutes(self.get_time_range_in_min()),
+            if insights.status.is_empty() {
+                "not started"
+            } else {
+                insights.status.as_str()
+            },
+            records_matched
+        )
+    }
+
+    fn render_results(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let insights = &app_state.cloud_watch_state.insights;
+        // the rounded border and the header row take three lines
+        self.page_height = area.height.saturating_sub(3) as usize;
+        self.selected_row = self.selected_row.min(insights.rows.len().saturating_sub(1));
+        self.selected_column = self
+            .selected_column
+            .min(insights.columns.len().saturating_sub(1));
+
+        let title = format!(
+            " Results ({}/{}) ",
+            (self.selected_row + 1).min(insights.rows.len()),
+            insights.rows.len()
+        );
+
+        if insights.columns.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo query results available")
+                    .block(self.create_block(app_state, title)),
+                area,
+            );
+            return;
+        }
+
+        let column_widths = insights
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                insights
+                    .rows
+                    .iter()
+                    .map(|row| row[index].chars().count())
+                    .chain([column.chars().count() + 2])
+                    .max()
+                    .unwrap_or_default()
+                    .min(MAX_COLUMN_WIDTH)
+            })
+            .collect::<Vec<usize>>();
+        self.update_column_offset(&column_widths, area.width.saturating_sub(2) as usize);
+
+        let header = Row::new(
+            insights
+                .columns
+                .iter()
+                .enumerate()
+                .skip(self.column_offset)
+                .map(|(index, column)| {
+                    let sort_marker = match self.sort_column {
+                        Some((sort_column, false)) if sort_column == index => " ^",
+                        Some((sort_column, true)) if sort_column == index => " v",
+                        _ => "",
+                    };
+                    let style = if index == self.selected_column {
+                        Style::new()
+                            .fg(TUI_CONFIG.theme.border_highlight)
+                            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                    } else {
+                        Style::new().add_modifier(Modifier::BOLD)
+                    };
+                    Cell::from(format!("{}{}", column, sort_marker)).style(style)
+                }),
+        );
+
+        let mut rows = insights.rows.iter().collect::<Vec<&Vec<String>>>();
+        if let Some((sort_column, is_descending)) = self.sort_column {
+            rows.sort_by(|a, b| {
+                let ordering = compare_values(&a[sort_column], &b[sort_column]);
+                if is_descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        let widths = column_widths
+            .iter()
+            .skip(self.column_offset)
+            .map(|width| Constraint::Length(*width as u16))
+            .collect::<Vec<Constraint>>();
+
+        let table = Table::new(rows.into_iter().map(|row| {
+            Row::new(
+                row.iter()
+                    .skip(self.column_offset)
+                    .map(|value| Cell::from(value.replace('\n', " "))),
+            )
+        }))
+        .header(header)
+        .widths(&widths)
+        .column_spacing(2)
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .block(self.create_block(app_state, title));
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected_row));
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    fn update_column_offset(&mut self, column_widths: &[usize], available_width: usize) {
+        if self.selected_column < self.column_offset {
+            self.column_offset = self.selected_column;
+        }
+
+        // move the first visible column until the selected column fits into the table
+        while self.column_offset < self.selected_column
+            && column_widths[self.column_offset..=self.selected_column]
+                .iter()
+                .map(|width| width + 2)
+                .sum::<usize>()
+                > available_width
+        {
+            self.column_offset += 1;
+        }
+    }
+
+    fn render_saved_queries(&mut self, frame: &mut Frame, app_state: &AppState) {
+        let saved_queries = &app_state.cloud_watch_state.insights.saved_queries;
+        self.saved_query_list.create_list_items(
+            saved_queries
+                .iter()
+                .map(|saved_query| saved_query.name.clone()),
+        );
+        self.saved_query_list.set_selected_index(
+            self.saved_query_list
+                .get_selected_index()
+                .min(saved_queries.len().saturating_sub(1)),
+        );
+
+        let popup_area = self.centered_list_rect(40, saved_queries.len(), app_state.area);
+
+        let block = Block::default()
+            .title(" Saved queries (<enter> load, <d> delete, <esc> close) ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL);
+        frame.render_widget(Clear, popup_area);
+
+        if saved_queries.is_empty() {
+            frame.render_widget(Paragraph::new("No saved queries").block(block), popup_area);
+        } else {
+            let mut list_state = ListState::default()
+                .with_selected(Some(self.saved_query_list.get_selected_index()));
+            let list = List::new(self.saved_query_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(block);
+            frame.render_stateful_widget(list, popup_area, &mut list_state);
+        }
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'static> {
+        Block::default()
+            .title(format!(
+                "{}[{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(
+                if self.has_focus(app_state) && !self.is_capturing_input() {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                },
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn format_minutes(minutes: u64) -> String {
+    match minutes {
+        minutes if minutes % 1440 == 0 => format!("{}d", minutes / 1440),
+        minutes if minutes % 60 == 0 => format!("{}h", minutes / 60),
+        minutes => format!("{}m", minutes),
+    }
+}
+
+fn compare_values(a: &str, b: &str) -> Ordering {
+    // numbers sort before text so that mixed columns still have a total order
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_numbers_numerically_and_text_lexically() {
+        let mut values = vec!["10", "9", "100", "-1.5"];
+        values.sort_by(|a, b| compare_values(a, b));
+        assert_eq!(values, vec!["-1.5", "9", "10", "100"]);
+
+        let mut values = vec!["b", "1a", "10", "a", "9"];
+        values.sort_by(|a, b| compare_values(a, b));
+        assert_eq!(values, vec!["9", "10", "1a", "a", "b"]);
+    }
+
+    #[test]
+    fn test_format_time_ranges() {
+        assert_eq!(format_minutes(5), "5m");
+        assert_eq!(format_minutes(180), "3h");
+        assert_eq!(format_minutes(10080), "7d");
+    }
+}
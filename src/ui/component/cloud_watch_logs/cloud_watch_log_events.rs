@@ -0,0 +1,440 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, CloudWatchLogsAction},
+        appstate::{AppState, ComponentType},
+        cloud_watch_logs_state::{CloudWatchExportFormat, CloudWatchLogEvent},
+    },
+    ui::{
+        component::{base::json_highlight::highlight_json, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use super::format_timestamp;
+
+pub struct CloudWatchLogEventComponent {
+    action_tx: UnboundedSender<Action>,
+    text_lines: Vec<Line<'static>>,
+    title: Option<String>,
+    log_event_count: usize,
+    last_log_event_key: Option<(Option<i64>, Option<String>)>,
+    vertical_offset: usize,
+    horizontal_offset: u16,
+    page_height: usize,
+    export_textarea: TextArea<'static>,
+    export_format: CloudWatchExportFormat,
+    export_time_range_index: Option<usize>,
+    is_exporting: bool,
+}
+
+impl Component for CloudWatchLogEventComponent {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        CloudWatchLogEventComponent {
+            action_tx: action_tx.clone(),
+            text_lines: vec![],
+            title: None,
+            log_event_count: 0,
+            last_log_event_key: None,
+            vertical_offset: 0,
+            horizontal_offset: 0,
+            page_height: 0,
+            export_textarea: TextArea::default(),
+            export_format: CloudWatchExportFormat::default(),
+            export_time_range_index: None,
+            is_exporting: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.cloud_watch_logs.into(),
+                TUI_CONFIG.breadcrumbs.cloud_watch_log_streams.into(),
+                TUI_CONFIG.breadcrumbs.cloud_watch_log_events.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.scroll_horizontal.into(),
+                    TUI_CONFIG.menu.follow.into(),
+                    TUI_CONFIG.menu.export.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_exporting
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_exporting {
+            return self.handle_export_key_event(key);
+        }
+
+        let max_offset = self.get_max_vertical_offset();
+
+        match key.code {
+            KeyCode::Char('t') => self.toggle_live_tail(app_state)?,
+            KeyCode::Char('e') => self.show_export_dialog(),
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.vertical_offset = self.vertical_offset.saturating_sub(1)
+            }
+            val if TUI_CONFIG.list_config.selection_down == val => {
+                self.vertical_offset = (self.vertical_offset + 1).min(max_offset)
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.vertical_offset = self.vertical_offset.saturating_sub(self.page_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.vertical_offset = (self.vertical_offset + self.page_height).min(max_offset)
+            }
+            val if TUI_CONFIG.list_config.jump_to_start == val => self.vertical_offset = 0,
+            val if TUI_CONFIG.list_config.jump_to_end == val => self.vertical_offset = max_offset,
+            val if TUI_CONFIG.list_config.scroll_left == val => {
+                self.horizontal_offset = self.horizontal_offset.saturating_sub(4)
+            }
+            val if TUI_CONFIG.list_config.scroll_right == val => {
+                self.horizontal_offset = self.horizontal_offset.saturating_add(4)
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let cloud_watch_state = &app_state.cloud_watch_state;
+        let title = match (
+            &cloud_watch_state.selected_log_stream,
+            &cloud_watch_state.live_tail,
+        ) {
+            (Some(log_stream), _) => log_stream.log_stream_name.clone(),
+            (None, Some(live_tail)) if live_tail.log_stream_names.is_empty() => {
+                Some(format!("{} (all streams)", live_tail.log_group_name))
+            }
+            (None, Some(live_tail)) => Some(format!(
+                "{} ({} streams)",
+                live_tail.log_group_name,
+                live_tail.log_stream_names.len()
+            )),
+            (None, None) => None,
+        };
+        let last_log_event_key = cloud_watch_state
+            .log_events
+            .last()
+            .map(|log_event| (log_event.timestamp, log_event.event_id.clone()));
+
+        if self.title != title
+            || self.log_event_count != cloud_watch_state.log_events.len()
+            || self.last_log_event_key != last_log_event_key
+        {
+            let is_at_end = self.vertical_offset >= self.get_max_vertical_offset();
+            if self.title != title {
+                self.vertical_offset = 0;
+                self.horizontal_offset = 0;
+            } else if is_at_end && cloud_watch_state.live_tail.is_some() {
+                // keep following the newest events while the view is scrolled to the end
+                self.vertical_offset = usize::MAX;
+            }
+
+            let show_log_stream_name = cloud_watch_state.selected_log_stream.is_none();
+            self.title = title;
+            self.log_event_count = cloud_watch_state.log_events.len();
+            self.last_log_event_key = last_log_event_key;
+            self.text_lines = cloud_watch_state
+                .log_events
+                .iter()
+                .flat_map(|log_event| self.create_text_lines(log_event, show_log_stream_name))
+                .collect();
+        }
+
+        // the rounded border takes one line at the top and one at the bottom
+        self.page_height = area.height.saturating_sub(2) as usize;
+        self.vertical_offset = self.vertical_offset.min(self.get_max_vertical_offset());
+        let block = self.create_block(app_state);
+
+        if self.text_lines.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo Log Events available").block(block),
+                area,
+            );
+        } else {
+            let visible_lines = self
+                .text_lines
+                .iter()
+                .skip(self.vertical_offset)
+                .take(self.page_height)
+                .cloned()
+                .collect::<Vec<Line>>();
+
+            frame.render_widget(
+                Paragraph::new(visible_lines)
+                    .scroll((0, self.horizontal_offset))
+                    .block(block),
+                area,
+            );
+        }
+
+        if self.is_exporting {
+            let text_area = self.centered_rect(60, 10, app_state.area);
+            frame.render_widget(Clear, text_area);
+            frame.render_widget(self.export_textarea.widget(), text_area);
+        }
+    }
+}
+
+impl CloudWatchLogEventComponent {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn reset(&mut self) {
+        self.title = None;
+        self.log_event_count = 0;
+        self.last_log_event_key = None;
+        self.text_lines = vec![];
+    }
+
+    fn toggle_live_tail(&self, app_state: &AppState) -> anyhow::Result<()> {
+        let cloud_watch_state = &app_state.cloud_watch_state;
+        let action = match (
+            &cloud_watch_state.live_tail,
+            &cloud_watch_state.selected_log_stream,
+        ) {
+            (Some(live_tail), _) if !live_tail.cancellation_token.is_cancelled() => {
+                CloudWatchLogsAction::StopLiveTail
+            }
+            (Some(live_tail), _) => CloudWatchLogsAction::StartLiveTail {
+                log_stream_names: live_tail.log_stream_names.clone(),
+            },
+            (None, Some(log_stream)) => CloudWatchLogsAction::StartLiveTail {
+                log_stream_names: log_stream.log_stream_name.clone().into_iter().collect(),
+            },
+            (None, None) => return Ok(()),
+        };
+
+        self.action_tx.send(Action::CloudWatchLogs { action })?;
+        Ok(())
+    }
+
+    fn show_export_dialog(&mut self) {
+        let file_name = self
+            .title
+            .as_deref()
+            .unwrap_or("log-events")
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() || "-_.".contains(character) {
+                    character
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+
+        self.export_textarea = TextArea::new(vec![format!(
+            "{}-{}.{}",
+            file_name.trim_matches('_'),
+            Utc::now().format("%Y%m%dT%H%M%S"),
+            self.export_format.extension()
+        )]);
+        self.export_textarea.set_cursor_line_style(Style::default());
+        self.export_textarea
+            .move_cursor(tui_textarea::CursorMove::End);
+        self.update_export_block();
+        self.is_exporting = true;
+    }
+
+    fn handle_export_key_event(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.is_exporting = false,
+            KeyCode::Enter => self.export_log_events()?,
+            KeyCode::Tab => {
+                let path = self.export_textarea.lines().join("");
+                let path = path
+                    .strip_suffix(&format!(".{}", self.export_format.extension()))
+                    .unwrap_or(&path)
+                    .to_string();
+                self.export_format = self.export_format.next();
+                self.export_textarea =
+                    TextArea::new(vec![format!("{}.{}", path, self.export_format.extension())]);
+                self.export_textarea.set_cursor_line_style(Style::default());
+                self.export_textarea
+                    .move_cursor(tui_textarea::CursorMove::End);
+                self.update_export_block();
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.export_time_range_index = match self.export_time_range_index {
+                    Some(0) | None => None,
+                    Some(index) => Some(index - 1),
+                };
+                self.update_export_block();
+            }
+            val if TUI_CONFIG.list_config.selection_down == val => {
+                self.export_time_range_index = Some(match self.export_time_range_index {
+                    Some(index) => {
+                        (index + 1).min(TUI_CONFIG.insights_time_ranges_in_min.len() - 1)
+                    }
+                    None => 0,
+                });
+                self.update_export_block();
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ => {
+                self.export_textarea.input(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn export_log_events(&mut self) -> anyhow::Result<()> {
+        let path = self.export_textarea.lines().join("");
+        if path.trim().is_empty() {
+            return Ok(());
+        }
+
+        let time_range = self.export_time_range_index.map(|index| {
+            let end_time = Utc::now().timestamp_millis();
+            let range_in_ms = TUI_CONFIG.insights_time_ranges_in_min[index] as i64 * 60 * 1000;
+            (end_time - range_in_ms, end_time)
+        });
+
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::ExportLogEvents {
+                path: PathBuf::from(path.trim()),
+                format: self.export_format,
+                time_range,
+            },
+        })?;
+        self.is_exporting = false;
+
+        Ok(())
+    }
+
+    fn update_export_block(&mut self) {
+        let events = match self.export_time_range_index {
+            Some(index) => format!(
+                "all events of the last {} min",
+                TUI_CONFIG.insights_time_ranges_in_min[index]
+            ),
+            None => "shown events".into(),
+        };
+
+        self.export_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    "Export {} as {} (<tab> format, <up/down> range)",
+                    events,
+                    self.export_format.extension()
+                ))
+                .title_alignment(Alignment::Center),
+        );
+    }
+
+    fn get_max_vertical_offset(&self) -> usize {
+        self.text_lines.len().saturating_sub(self.page_height)
+    }
+
+    fn create_text_lines(
+        &self,
+        log_event: &CloudWatchLogEvent,
+        show_log_stream_name: bool,
+    ) -> Vec<Line<'static>> {
+        let mut header_spans = vec![
+            Span::styled(
+                format_timestamp(log_event.timestamp, "unknown timestamp"),
+                Style::new().fg(TUI_CONFIG.theme.log_event_timestamp),
+            ),
+            Span::raw("  ingested "),
+            Span::styled(
+                format_timestamp(log_event.ingestion_time, "unknown"),
+                Style::new().fg(TUI_CONFIG.theme.log_event_timestamp),
+            ),
+        ];
+        if show_log_stream_name {
+            header_spans.push(Span::raw(format!(
+                "  {}",
+                log_event
+                    .log_stream_name
+                    .as_deref()
+                    .unwrap_or("unknown log stream")
+            )));
+        }
+        let mut text_lines = vec![Line::from(header_spans)];
+
+        let message = log_event.message.clone().unwrap_or_default();
+        match highlight_json(&message) {
+            Some(json_lines) => text_lines.extend(json_lines),
+            None => text_lines.extend(
+                message
+                    .trim_end()
+                    .lines()
+                    .map(|line| Line::from(line.to_string())),
+            ),
+        }
+
+        text_lines
+    }
+
+    fn create_block(&self, app_state: &AppState) -> Block<'static> {
+        let follow_state = match &app_state.cloud_watch_state.live_tail {
+            Some(live_tail) if !live_tail.cancellation_token.is_cancelled() => " following",
+            Some(_) => " stopped",
+            None => "",
+        };
+
+        Block::default()
+            .title(format!(
+                " {} ({}/{}){} [{}] ",
+                self.title.as_deref().unwrap_or("unknown log stream"),
+                (self.vertical_offset + 1).min(self.text_lines.len()),
+                self.text_lines.len(),
+                follow_state,
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
@@ -0,0 +1,465 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, CloudWatchLogsAction},
+        appstate::{AppState, ComponentType},
+        cloud_watch_logs_state::{CloudWatchFilterMatch, CloudWatchLogGroupFilters},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+pub struct CloudWatchLogGroupFilterComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    filter_list: ListComponent<'a>,
+    pattern_textarea: TextArea<'a>,
+    is_editing_pattern: bool,
+    messages_textarea: TextArea<'a>,
+    is_editing_messages: bool,
+}
+
+impl<'a> Component for CloudWatchLogGroupFilterComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        CloudWatchLogGroupFilterComponent {
+            action_tx: action_tx.clone(),
+            filter_list: ListComponent::new(),
+            pattern_textarea: CloudWatchLogGroupFilterComponent::create_pattern_textarea(
+                String::default(),
+            ),
+            is_editing_pattern: false,
+            messages_textarea: CloudWatchLogGroupFilterComponent::create_messages_textarea(),
+            is_editing_messages: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.cloud_watch_logs.into(),
+                TUI_CONFIG.breadcrumbs.cloud_watch_log_group_filters.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.use_pattern.into(),
+                    TUI_CONFIG.menu.edit_pattern.into(),
+                    TUI_CONFIG.menu.edit_messages.into(),
+                    TUI_CONFIG.menu.test_pattern.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_editing_pattern || self.is_editing_messages
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_editing_pattern || self.is_editing_messages {
+            match key.code {
+                KeyCode::Esc => {
+                    self.is_editing_pattern = false;
+                    self.is_editing_messages = false;
+                }
+                KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
+                    self.is_editing_pattern = false;
+                    self.is_editing_messages = false;
+                    self.test_pattern()?;
+                }
+                KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+                // the filter pattern is a single line
+                KeyCode::Enter if self.is_editing_pattern => {}
+                _ if self.is_editing_pattern => {
+                    self.pattern_textarea.input(key);
+                }
+                _ => {
+                    self.messages_textarea.input(key);
+                }
+            }
+        } else {
+            match key.code {
+                KeyCode::Char('p') => self.is_editing_pattern = true,
+                KeyCode::Char('m') => self.is_editing_messages = true,
+                KeyCode::Char('r') => self.test_pattern()?,
+                val if TUI_CONFIG.list_config.selection_up == val => self.filter_list.move_up(),
+                val if TUI_CONFIG.list_config.selection_down == val
+                    && self.filter_list.has_list_elements() =>
+                {
+                    self.filter_list.move_down()
+                }
+                val if TUI_CONFIG.list_config.do_selection == val => {
+                    if let Some(filter_pattern) = app_state
+                        .cloud_watch_state
+                        .log_group_filters
+                        .as_ref()
+                        .and_then(|log_group_filters| {
+                            get_filter_patterns(log_group_filters)
+                                .into_iter()
+                                .nth(self.filter_list.get_selected_index())
+                        })
+                    {
+                        self.pattern_textarea =
+                            CloudWatchLogGroupFilterComponent::create_pattern_textarea(
+                                filter_pattern,
+                            );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let log_group_filters = app_state.cloud_watch_state.log_group_filters.as_ref();
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Percentage(40),
+                Constraint::Length(3),
+                Constraint::Length(8),
+                Constraint::Min(3),
+            ])
+            .split(area);
+
+        self.render_filters(frame, layout[0], app_state, log_group_filters);
+
+        self.pattern_textarea.set_block(create_editor_block(
+            if self.is_editing_pattern {
+                " Filter pattern (editing, <esc> done, <ctrl-r> test) "
+            } else {
+                " Filter pattern "
+            },
+            self.is_editing_pattern,
+        ));
+        frame.render_widget(self.pattern_textarea.widget(), layout[1]);
+
+        self.messages_textarea.set_block(create_editor_block(
+            if self.is_editing_messages {
+                " Sample log messages (editing, <esc> done, <ctrl-r> test) "
+            } else {
+                " Sample log messages "
+            },
+            self.is_editing_messages,
+        ));
+        frame.render_widget(self.messages_textarea.widget(), layout[2]);
+
+        frame.render_widget(
+            Paragraph::new(create_match_text(log_group_filters.and_then(
+                |log_group_filters| log_group_filters.test_matches.as_deref(),
+            )))
+            .block(self.create_block(app_state, " Test results ".into())),
+            layout[3],
+        );
+    }
+}
+
+impl<'a> CloudWatchLogGroupFilterComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn reset(&mut self) {
+        self.filter_list.set_selected_index(0);
+        self.is_editing_pattern = false;
+        self.is_editing_messages = false;
+    }
+
+    fn create_pattern_textarea(filter_pattern: String) -> TextArea<'a> {
+        let mut pattern_textarea = TextArea::new(vec![filter_pattern]);
+        pattern_textarea.set_cursor_line_style(Style::default());
+        pattern_textarea
+            .set_placeholder_text("Enter filter pattern, e.g. [ip, user, ..., status=5*]");
+        pattern_textarea
+    }
+
+    fn create_messages_textarea() -> TextArea<'a> {
+        let mut messages_textarea = TextArea::default();
+        messages_textarea.set_cursor_line_style(Style::default());
+        messages_textarea.set_placeholder_text("One sample log message per line");
+        messages_textarea
+    }
+
+    fn test_pattern(&mut self) -> anyhow::Result<()> {
+        let filter_pattern = self.pattern_textarea.lines().join("");
+        let log_event_messages = self
+            .messages_textarea
+            .lines()
+            .iter()
+            .filter(|message| !message.trim().is_empty())
+            .cloned()
+            .collect::<Vec<String>>();
+
+        if log_event_messages.is_empty() {
+            return Ok(());
+        }
+
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::TestFilterPattern {
+                filter_pattern,
+                log_event_messages,
+            },
+        })?;
+
+        Ok(())
+    }
+
+    fn render_filters(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        app_state: &AppState,
+        log_group_filters: Option<&CloudWatchLogGroupFilters>,
+    ) {
+        let Some(log_group_filters) = log_group_filters else {
+            frame.render_widget(
+                Paragraph::new("\nNo filters available")
+                    .block(self.create_block(app_state, " Filters ".into())),
+                area,
+            );
+            return;
+        };
+
+        self.filter_list
+            .create_list_items(create_filter_lines(log_group_filters));
+        self.filter_list.set_selected_index(
+            self.filter_list
+                .get_selected_index()
+                .min(self.filter_list.get_list_len().saturating_sub(1)),
+        );
+
+        let block = self.create_block(
+            app_state,
+            format!(
+                " Filters of {} ({} metric, {} subscription) ",
+                log_group_filters.log_group_name,
+                log_group_filters.metric_filters.len(),
+                log_group_filters.subscription_filters.len()
+            ),
+        );
+
+        if self.filter_list.has_list_elements() {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.filter_list.get_selected_index()));
+            let list = List::new(self.filter_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(block);
+            frame.render_stateful_widget(list, area, &mut list_state);
+        } else {
+            frame.render_widget(
+                Paragraph::new("\nThe log group has no metric or subscription filters")
+                    .block(block),
+                area,
+            );
+        }
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'static> {
+        Block::default()
+            .title(format!(
+                "{}[{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(
+                if self.has_focus(app_state) && !self.is_capturing_input() {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                },
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn create_editor_block(title: &str, is_editing: bool) -> Block<'_> {
+    Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_style(Style::new().fg(if is_editing {
+            TUI_CONFIG.theme.border_highlight
+        } else {
+            TUI_CONFIG.theme.border
+        }))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+}
+
+/// Metric filters are listed before subscription filters, in the same order as the list lines.
+fn get_filter_patterns(log_group_filters: &CloudWatchLogGroupFilters) -> Vec<String> {
+    log_group_filters
+        .metric_filters
+        .iter()
+        .map(|metric_filter| metric_filter.filter_pattern.clone())
+        .chain(
+            log_group_filters
+                .subscription_filters
+                .iter()
+                .map(|subscription_filter| subscription_filter.filter_pattern.clone()),
+        )
+        .map(Option::unwrap_or_default)
+        .collect()
+}
+
+fn create_filter_lines(log_group_filters: &CloudWatchLogGroupFilters) -> Vec<String> {
+    log_group_filters
+        .metric_filters
+        .iter()
+        .map(|metric_filter| {
+            format!(
+                "metric        {}  |  {}  |  {}",
+                metric_filter.filter_name.as_deref().unwrap_or_default(),
+                format_pattern(metric_filter.filter_pattern.as_deref()),
+                metric_filter.metric_transformations.join(", ")
+            )
+        })
+        .chain(
+            log_group_filters
+                .subscription_filters
+                .iter()
+                .map(|subscription_filter| {
+                    format!(
+                        "subscription  {}  |  {}  |  {}{}",
+                        subscription_filter
+                            .filter_name
+                            .as_deref()
+                            .unwrap_or_default(),
+                        format_pattern(subscription_filter.filter_pattern.as_deref()),
+                        subscription_filter
+                            .destination_arn
+                            .as_deref()
+                            .unwrap_or_default(),
+                        subscription_filter
+                            .distribution
+                            .as_ref()
+                            .map(|distribution| format!(" ({})", distribution))
+                            .unwrap_or_default()
+                    )
+                }),
+        )
+        .collect()
+}
+
+fn format_pattern(filter_pattern: Option<&str>) -> &str {
+    match filter_pattern {
+        Some(filter_pattern) if !filter_pattern.is_empty() => filter_pattern,
+        _ => "<all events>",
+    }
+}
+
+fn create_match_text(test_matches: Option<&[CloudWatchFilterMatch]>) -> String {
+    match test_matches {
+        None => "\nPattern not tested".into(),
+        Some([]) => "\nNo matches".into(),
+        Some(test_matches) => test_matches
+            .iter()
+            .map(|test_match| {
+                let extracted_values = test_match
+                    .extracted_values
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect::<Vec<String>>()
+                    .join(" ");
+                format!(
+                    "#{} {}  {}",
+                    test_match.event_number,
+                    test_match.event_message.as_deref().unwrap_or_default(),
+                    extracted_values
+                )
+                .trim_end()
+                .to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::cloud_watch_logs_state::{
+        CloudWatchMetricFilter, CloudWatchSubscriptionFilter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_list_metric_before_subscription_filters() {
+        let log_group_filters = CloudWatchLogGroupFilters {
+            log_group_name: "group".into(),
+            metric_filters: vec![CloudWatchMetricFilter {
+                filter_name: Some("errors".into()),
+                filter_pattern: Some("ERROR".into()),
+                metric_transformations: vec!["App/Errors = 1".into()],
+            }],
+            subscription_filters: vec![CloudWatchSubscriptionFilter {
+                filter_name: Some("to-lambda".into()),
+                filter_pattern: Some(String::default()),
+                destination_arn: Some("arn:aws:lambda:fn".into()),
+                distribution: Some("ByLogStream".into()),
+            }],
+            test_matches: None,
+        };
+
+        assert_eq!(
+            get_filter_patterns(&log_group_filters),
+            vec!["ERROR".to_string(), String::default()]
+        );
+        assert_eq!(
+            create_filter_lines(&log_group_filters),
+            vec![
+                "metric        errors  |  ERROR  |  App/Errors = 1".to_string(),
+                "subscription  to-lambda  |  <all events>  |  arn:aws:lambda:fn (ByLogStream)"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_test_matches() {
+        assert_eq!(create_match_text(None), "\nPattern not tested");
+        assert_eq!(create_match_text(Some(&[])), "\nNo matches");
+        assert_eq!(
+            create_match_text(Some(&[CloudWatchFilterMatch {
+                event_number: 2,
+                event_message: Some("127.0.0.1 GET 500".into()),
+                extracted_values: vec![
+                    ("$ip".into(), "127.0.0.1".into()),
+                    ("$status".into(), "500".into())
+                ],
+            }])),
+            "#2 127.0.0.1 GET 500  $ip=127.0.0.1 $status=500"
+        );
+    }
+}
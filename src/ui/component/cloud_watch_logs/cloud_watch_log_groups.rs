@@ -1,32 +1,90 @@
-use chrono::{DateTime, SecondsFormat};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use human_bytes::human_bytes;
 
 use crate::{
     state::{
         action_handlers::actions::{Action, CloudWatchLogsAction},
         appstate::{AppState, ComponentType},
-        cloud_watch_logs_state::CloudWatchLogGroup,
+        cloud_watch_logs_state::{CloudWatchLogGroup, CloudWatchLogGroupNameFilter},
     },
     ui::{
-        component::{base::list_component::ListComponent, Component},
+        component::{
+            base::{
+                list_component::ListComponent,
+                text_filter::{FilterMode, TextFilter},
+            },
+            Component,
+        },
         tui_config::TUI_CONFIG,
     },
 };
 use ratatui::{
     prelude::{Alignment, Rect},
-    style::Style,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph},
     Frame,
 };
 use tokio::sync::mpsc::UnboundedSender;
 use tui_textarea::TextArea;
 
+use super::{
+    cloud_watch_insights::CloudWatchInsightsComponent,
+    cloud_watch_log_group_filters::CloudWatchLogGroupFilterComponent,
+    cloud_watch_log_streams::CloudWatchLogStreamComponent, format_timestamp,
+};
+
+/// The retention periods accepted by PutRetentionPolicy, `None` keeps the events forever.
+const RETENTION_DAYS: [Option<i32>; 23] = [
+    None,
+    Some(1),
+    Some(3),
+    Some(5),
+    Some(7),
+    Some(14),
+    Some(30),
+    Some(60),
+    Some(90),
+    Some(120),
+    Some(150),
+    Some(180),
+    Some(365),
+    Some(400),
+    Some(545),
+    Some(731),
+    Some(1096),
+    Some(1827),
+    Some(2192),
+    Some(2557),
+    Some(2922),
+    Some(3288),
+    Some(3653),
+];
+
 pub struct CloudWatchLogGroupComponent<'a> {
     action_tx: UnboundedSender<Action>,
     log_group_list: ListComponent<'a>,
     first_time_render: bool,
     filter_textarea: TextArea<'a>,
     is_editing_filter: bool,
+    filter_mode: FilterMode,
+    text_filter: Option<TextFilter>,
+    filtered_indices: Vec<usize>,
+    log_groups_key: Option<u64>,
+    log_stream_component: CloudWatchLogStreamComponent<'a>,
+    is_log_stream_view: bool,
+    insights_component: CloudWatchInsightsComponent<'a>,
+    is_insights_view: bool,
+    filter_component: CloudWatchLogGroupFilterComponent<'a>,
+    is_filter_view: bool,
+    marked_log_group_names: Vec<String>,
+    retention_list: ListComponent<'a>,
+    is_choosing_retention: bool,
+    delete_textarea: TextArea<'a>,
+    is_confirming_delete: bool,
+    pending_log_group_name: Option<String>,
 }
 
 impl<'a> Component for CloudWatchLogGroupComponent<'a> {
@@ -37,12 +95,10 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
         let mut filter_text = TextArea::default();
         filter_text.set_cursor_line_style(Style::default());
         filter_text.set_placeholder_text("Enter filter expression");
-        filter_text.set_block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Filter")
-                .title_alignment(Alignment::Center),
-        );
+        filter_text.set_block(CloudWatchLogGroupComponent::create_filter_block(
+            FilterMode::default(),
+            None,
+        ));
 
         CloudWatchLogGroupComponent {
             action_tx: action_tx.clone(),
@@ -50,6 +106,22 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
             filter_textarea: filter_text,
             first_time_render: true,
             is_editing_filter: false,
+            filter_mode: FilterMode::default(),
+            text_filter: None,
+            filtered_indices: vec![],
+            log_groups_key: None,
+            log_stream_component: CloudWatchLogStreamComponent::new(action_tx.clone()),
+            is_log_stream_view: false,
+            insights_component: CloudWatchInsightsComponent::new(action_tx.clone()),
+            is_insights_view: false,
+            filter_component: CloudWatchLogGroupFilterComponent::new(action_tx.clone()),
+            is_filter_view: false,
+            marked_log_group_names: vec![],
+            retention_list: ListComponent::from(RETENTION_DAYS.map(format_retention)),
+            is_choosing_retention: false,
+            delete_textarea: TextArea::default(),
+            is_confirming_delete: false,
+            pending_log_group_name: None,
         }
     }
 
@@ -58,6 +130,18 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
     }
 
     fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_log_stream_view {
+            return self.log_stream_component.set_focus();
+        }
+
+        if self.is_insights_view {
+            return self.insights_component.set_focus();
+        }
+
+        if self.is_filter_view {
+            return self.filter_component.set_focus();
+        }
+
         self.set_breadcrumbs()?;
 
         self.action_tx.send(Action::SetMenu {
@@ -70,6 +154,11 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
                     TUI_CONFIG.menu.up.into(),
                     TUI_CONFIG.menu.down.into(),
                     TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.mark.into(),
+                    TUI_CONFIG.menu.insights.into(),
+                    TUI_CONFIG.menu.next_page.into(),
+                    TUI_CONFIG.menu.retention.into(),
+                    TUI_CONFIG.menu.delete.into(),
                 ],
             ],
         })?;
@@ -77,10 +166,77 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
         Ok(())
     }
 
+    fn is_capturing_input(&self) -> bool {
+        if self.is_insights_view {
+            self.insights_component.is_capturing_input()
+        } else if self.is_filter_view {
+            self.filter_component.is_capturing_input()
+        } else if self.is_log_stream_view {
+            self.log_stream_component.is_capturing_input()
+        } else {
+            !self.is_log_stream_view
+                && (self.is_editing_filter
+                    || self.is_choosing_retention
+                    || self.is_confirming_delete)
+        }
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
-        if self.is_editing_filter {
+        if self.is_log_stream_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.log_stream_component.is_log_event_view()
+            {
+                self.reset_active_log_group()?;
+            } else {
+                self.log_stream_component.handle_key_event(key, app_state)?;
+            }
+        } else if self.is_insights_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.insights_component.is_capturing_input()
+            {
+                self.is_insights_view = false;
+                self.set_focus()?;
+            } else {
+                self.insights_component.handle_key_event(key, app_state)?;
+            }
+        } else if self.is_filter_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.filter_component.is_capturing_input()
+            {
+                self.is_filter_view = false;
+                self.set_focus()?;
+            } else {
+                self.filter_component.handle_key_event(key, app_state)?;
+            }
+        } else if self.is_choosing_retention {
+            match key.code {
+                KeyCode::Esc => self.is_choosing_retention = false,
+                val if TUI_CONFIG.list_config.selection_up == val => self.retention_list.move_up(),
+                val if TUI_CONFIG.list_config.selection_down == val => {
+                    self.retention_list.move_down()
+                }
+                val if TUI_CONFIG.list_config.do_selection == val => self.set_retention()?,
+                _ => {}
+            }
+        } else if self.is_confirming_delete {
+            match key.code {
+                KeyCode::Esc => self.is_confirming_delete = false,
+                KeyCode::Enter => self.delete_log_group()?,
+                KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+                _ => {
+                    self.delete_textarea.input(key);
+                }
+            }
+        } else if self.is_editing_filter {
             match key.code {
-                KeyCode::Enter => self.is_editing_filter = false,
+                KeyCode::Enter => self.apply_filter(app_state)?,
+                KeyCode::Esc => self.cancel_filter(),
+                KeyCode::Tab => {
+                    self.filter_mode = self.filter_mode.next();
+                    self.filter_textarea.set_block(
+                        CloudWatchLogGroupComponent::create_filter_block(self.filter_mode, None),
+                    );
+                }
                 KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
                 _ => {
                     self.filter_textarea.input(key);
@@ -88,15 +244,30 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
             }
         } else {
             match key.code {
-                KeyCode::Char('u') => self.update()?,
+                KeyCode::Char('u') => {
+                    self.update(app_state.cloud_watch_state.log_group_name_filter.clone())?
+                }
                 KeyCode::Char('f') => self.is_editing_filter = true,
                 val if TUI_CONFIG.list_config.selection_up == val => self.log_group_list.move_up(),
-                val if TUI_CONFIG.list_config.selection_down == val => {
+                val if TUI_CONFIG.list_config.selection_down == val
+                    && self.log_group_list.has_list_elements() =>
+                {
                     self.log_group_list.move_down();
+                    if self.log_group_list.get_selected_index() + 1
+                        == self.log_group_list.get_list_len()
+                    {
+                        self.load_more_log_groups(app_state)?;
+                    }
                 }
+                KeyCode::Char('n') => self.load_more_log_groups(app_state)?,
+                KeyCode::Char('r') => self.show_retention_list(app_state),
+                KeyCode::Char('d') => self.show_delete_confirmation(app_state),
                 val if TUI_CONFIG.list_config.do_selection == val => {
                     self.set_active_log_group(app_state)?;
                 }
+                KeyCode::Char(' ') => self.toggle_marked_log_group(app_state),
+                KeyCode::Char('q') => self.show_insights(app_state)?,
+                KeyCode::Char('i') => self.show_filters(app_state)?,
 
                 _ => {}
             }
@@ -106,27 +277,44 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
 
     fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
         if self.first_time_render {
-            let _ = self.update();
+            let _ = self.update(None);
             self.first_time_render = false;
         }
 
-        if app_state.cloud_watch_state.log_groups.is_empty() {
+        if self.is_log_stream_view {
+            self.log_stream_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_insights_view {
+            self.insights_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_filter_view {
+            self.filter_component.render(frame, area, app_state);
+            return;
+        }
+
+        let log_groups = &app_state.cloud_watch_state.log_groups;
+        let log_groups_key = Some(create_log_groups_key(log_groups));
+        if self.log_groups_key != log_groups_key {
+            self.log_groups_key = log_groups_key;
+            self.create_list_items(app_state);
+        }
+
+        if log_groups.is_empty() {
             frame.render_widget(
                 Paragraph::new("\nNo Log Groups available").block(self.create_block(app_state)),
                 area,
             );
+        } else if self.filtered_indices.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo Log Groups match the filter")
+                    .block(self.create_block(app_state)),
+                area,
+            );
         } else {
-            if app_state.cloud_watch_state.log_groups.len() != self.log_group_list.get_list_len() {
-                self.log_group_list.create_list_items(
-                    app_state
-                        .cloud_watch_state
-                        .log_groups
-                        .iter()
-                        .map(|log_group| self.create_list_item(log_group))
-                        .collect::<Vec<String>>(),
-                );
-            }
-
             let mut list_state =
                 ListState::default().with_selected(Some(self.log_group_list.get_selected_index()));
             let list = List::new(self.log_group_list.create_tui_list())
@@ -134,12 +322,35 @@ impl<'a> Component for CloudWatchLogGroupComponent<'a> {
                 .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
                 .block(self.create_block(app_state));
             frame.render_stateful_widget(list, area, &mut list_state);
+        }
 
-            if self.is_editing_filter {
-                let text_area = self.centered_rect(40, 10, app_state.area);
-                frame.render_widget(Clear, text_area);
-                frame.render_widget(self.filter_textarea.widget(), text_area);
-            }
+        if self.is_editing_filter {
+            let text_area = self.centered_rect(40, 10, app_state.area);
+            frame.render_widget(Clear, text_area);
+            frame.render_widget(self.filter_textarea.widget(), text_area);
+        }
+
+        if self.is_choosing_retention {
+            let list_area = self.centered_list_rect(30, RETENTION_DAYS.len(), app_state.area);
+            let mut list_state =
+                ListState::default().with_selected(Some(self.retention_list.get_selected_index()));
+            let list = List::new(self.retention_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Retention")
+                        .title_alignment(Alignment::Center),
+                );
+            frame.render_widget(Clear, list_area);
+            frame.render_stateful_widget(list, list_area, &mut list_state);
+        }
+
+        if self.is_confirming_delete {
+            let text_area = self.centered_rect(40, 10, app_state.area);
+            frame.render_widget(Clear, text_area);
+            frame.render_widget(self.delete_textarea.widget(), text_area);
         }
     }
 }
@@ -149,39 +360,356 @@ impl<'a> CloudWatchLogGroupComponent<'a> {
         app_state.focus_component == self.component_type()
     }
 
-    fn create_list_item(&self, log_group: &CloudWatchLogGroup) -> String {
-        let name = match log_group.name.clone() {
-            Some(name) => name,
-            None => "unknown name".into(),
+    fn get_selected_log_group<'b>(
+        &self,
+        app_state: &'b AppState,
+    ) -> Option<&'b CloudWatchLogGroup> {
+        self.filtered_indices
+            .get(self.log_group_list.get_selected_index())
+            .and_then(|index| app_state.cloud_watch_state.log_groups.get(*index))
+    }
+
+    fn create_list_items(&mut self, app_state: &AppState) {
+        let mut filtered_indices = vec![];
+        let mut list_items = vec![];
+
+        for (index, log_group) in app_state.cloud_watch_state.log_groups.iter().enumerate() {
+            let name = log_group.name.as_deref().unwrap_or("unknown name");
+            let matches = match &self.text_filter {
+                Some(text_filter) => text_filter.find_matches(name),
+                None => Some(vec![]),
+            };
+
+            if let Some(matches) = matches {
+                filtered_indices.push(index);
+                list_items.push(self.create_list_item(log_group, name, &matches));
+            }
+        }
+
+        self.filtered_indices = filtered_indices;
+        self.log_group_list.create_styled_list_items(list_items);
+        self.log_group_list.set_selected_index(
+            self.log_group_list
+                .get_selected_index()
+                .min(self.filtered_indices.len().saturating_sub(1)),
+        );
+    }
+
+    fn create_list_item(
+        &self,
+        log_group: &CloudWatchLogGroup,
+        name: &str,
+        matches: &[(usize, usize)],
+    ) -> Line<'static> {
+        let marker = if self
+            .marked_log_group_names
+            .iter()
+            .any(|marked| marked == name)
+        {
+            "*"
+        } else {
+            " "
         };
 
-        let date_created = match log_group.date_created {
-            Some(date_created) => DateTime::from_timestamp(date_created / 1000, 0)
-                .unwrap()
-                .to_rfc3339_opts(SecondsFormat::Secs, true),
-            None => "unknown creation date".into(),
+        let date_created = format_timestamp(log_group.date_created, "unknown creation date");
+
+        let mut spans = vec![Span::raw(format!(
+            "{} {}  {:>12}  {:>10}  ",
+            marker,
+            date_created,
+            format_retention(log_group.retention_days),
+            human_bytes(log_group.stored_bytes.unwrap_or_default() as f64)
+        ))];
+        let mut position = 0;
+        for (start, end) in matches {
+            spans.push(Span::raw(name[position..*start].to_string()));
+            spans.push(Span::styled(
+                name[*start..*end].to_string(),
+                Style::new()
+                    .fg(TUI_CONFIG.theme.filter_match)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            position = *end;
+        }
+        spans.push(Span::raw(name[position..].to_string()));
+
+        Line::from(spans)
+    }
+
+    fn apply_filter(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        let expression = self.filter_textarea.lines().join("");
+        self.text_filter = if expression.is_empty() {
+            None
+        } else {
+            match TextFilter::new(&expression, self.filter_mode) {
+                Ok(text_filter) => Some(text_filter),
+                Err(err) => {
+                    self.filter_textarea.set_block(
+                        CloudWatchLogGroupComponent::create_filter_block(
+                            self.filter_mode,
+                            Some(err.to_string()),
+                        ),
+                    );
+                    return Ok(());
+                }
+            }
         };
 
-        format!("{}  {}", date_created, name)
+        // only a part of the log groups is loaded, so let the service filter by name as well
+        let cloud_watch_state = &app_state.cloud_watch_state;
+        let name_filter = self.text_filter.as_ref().and_then(create_name_filter);
+        let is_server_filtered = (cloud_watch_state.log_groups_next_token.is_some()
+            || cloud_watch_state.log_group_name_filter.is_some())
+            && name_filter.is_some();
+
+        // the service matches names case sensitive, the loaded ones have to match alike
+        if is_server_filtered {
+            self.text_filter = TextFilter::with_case(&expression, self.filter_mode, true).ok();
+        }
+
+        self.is_editing_filter = false;
+        self.filter_textarea
+            .set_block(CloudWatchLogGroupComponent::create_filter_block(
+                self.filter_mode,
+                None,
+            ));
+        self.log_group_list.set_selected_index(0);
+        self.create_list_items(app_state);
+        self.set_breadcrumbs()?;
+
+        if (cloud_watch_state.log_groups_next_token.is_some()
+            || cloud_watch_state.log_group_name_filter.is_some())
+            && cloud_watch_state.log_group_name_filter != name_filter
+        {
+            self.update(name_filter)?;
+        }
+
+        Ok(())
+    }
+
+    fn cancel_filter(&mut self) {
+        let (expression, filter_mode) = match &self.text_filter {
+            Some(text_filter) => (text_filter.expression().to_string(), text_filter.mode()),
+            None => (String::default(), FilterMode::default()),
+        };
+
+        self.filter_textarea.select_all();
+        self.filter_textarea.cut();
+        self.filter_textarea.insert_str(expression);
+        self.filter_mode = filter_mode;
+        self.filter_textarea
+            .set_block(CloudWatchLogGroupComponent::create_filter_block(
+                self.filter_mode,
+                None,
+            ));
+        self.is_editing_filter = false;
+    }
+
+    fn create_filter_block(filter_mode: FilterMode, error: Option<String>) -> Block<'a> {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center);
+
+        match error {
+            Some(error) => block
+                .title(format!("Filter ({}): {}", filter_mode, error))
+                .border_style(Style::new().fg(TUI_CONFIG.theme.error_message_text)),
+            None => block.title(format!("Filter ({}, <tab> mode)", filter_mode)),
+        }
+    }
+
+    fn toggle_marked_log_group(&mut self, app_state: &AppState) {
+        if let Some(log_group_name) = self
+            .get_selected_log_group(app_state)
+            .and_then(|log_group| log_group.name.clone())
+        {
+            match self
+                .marked_log_group_names
+                .iter()
+                .position(|name| *name == log_group_name)
+            {
+                Some(index) => {
+                    self.marked_log_group_names.remove(index);
+                }
+                None => self.marked_log_group_names.push(log_group_name),
+            }
+            self.create_list_items(app_state);
+        }
+    }
+
+    fn show_insights(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        let log_group_names = if self.marked_log_group_names.is_empty() {
+            self.get_selected_log_group(app_state)
+                .and_then(|log_group| log_group.name.clone())
+                .into_iter()
+                .collect()
+        } else {
+            self.marked_log_group_names.clone()
+        };
+
+        if !log_group_names.is_empty() {
+            self.insights_component.set_log_group_names(log_group_names);
+            self.is_insights_view = true;
+            self.insights_component.set_focus()?;
+        }
+
+        Ok(())
+    }
+
+    fn show_filters(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(log_group_name) = self
+            .get_selected_log_group(app_state)
+            .and_then(|log_group| log_group.name.clone())
+        {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::GetLogGroupFilters { log_group_name },
+            })?;
+            self.filter_component.reset();
+            self.is_filter_view = true;
+            self.filter_component.set_focus()?;
+        }
+
+        Ok(())
+    }
+
+    fn set_active_log_group(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(log_group_name) = self
+            .get_selected_log_group(app_state)
+            .and_then(|log_group| log_group.name.clone())
+        {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::SelectLogGroup { log_group_name },
+            })?;
+            self.is_log_stream_view = true;
+            self.log_stream_component.set_focus()?;
+        }
+
+        Ok(())
+    }
+
+    fn reset_active_log_group(&mut self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::DeselectLogGroup,
+        })?;
+        self.is_log_stream_view = false;
+        self.log_stream_component.reset();
+        self.set_focus()
+    }
+
+    fn show_retention_list(&mut self, app_state: &AppState) {
+        if let Some(log_group) = self.get_selected_log_group(app_state) {
+            self.retention_list
+                .set_selected_index(get_retention_index(log_group.retention_days));
+            self.pending_log_group_name = log_group.name.clone();
+            self.is_choosing_retention = true;
+        }
+    }
+
+    fn set_retention(&mut self) -> anyhow::Result<()> {
+        if let Some(log_group_name) = self.pending_log_group_name.take() {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::SetLogGroupRetention {
+                    log_group_name,
+                    retention_days: RETENTION_DAYS[self.retention_list.get_selected_index()],
+                },
+            })?;
+        }
+        self.is_choosing_retention = false;
+
+        Ok(())
+    }
+
+    fn show_delete_confirmation(&mut self, app_state: &AppState) {
+        if let Some(log_group_name) = self
+            .get_selected_log_group(app_state)
+            .and_then(|log_group| log_group.name.clone())
+        {
+            self.delete_textarea = TextArea::default();
+            self.delete_textarea.set_cursor_line_style(Style::default());
+            self.delete_textarea
+                .set_placeholder_text("Type the log group name to confirm");
+            self.delete_textarea.set_block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Delete {}", log_group_name))
+                    .title_alignment(Alignment::Center)
+                    .border_style(Style::new().fg(TUI_CONFIG.theme.error_message_text)),
+            );
+            self.pending_log_group_name = Some(log_group_name);
+            self.is_confirming_delete = true;
+        }
+    }
+
+    fn delete_log_group(&mut self) -> anyhow::Result<()> {
+        let confirmation = self.delete_textarea.lines().join("");
+        if let Some(log_group_name) = self
+            .pending_log_group_name
+            .take_if(|log_group_name| *log_group_name == confirmation)
+        {
+            self.marked_log_group_names
+                .retain(|marked| *marked != log_group_name);
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::DeleteLogGroup { log_group_name },
+            })?;
+            self.is_confirming_delete = false;
+        }
+
+        Ok(())
     }
 
-    fn set_active_log_group(&mut self, _app_state: &AppState) -> anyhow::Result<()> {
+    fn load_more_log_groups(&self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(token) = &app_state.cloud_watch_state.log_groups_next_token {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::GetLogGroups {
+                    token: Some(token.clone()),
+                    name_filter: None,
+                },
+            })?;
+        }
+
         Ok(())
     }
 
-    fn update(&self) -> anyhow::Result<()> {
+    fn update(&self, name_filter: Option<CloudWatchLogGroupNameFilter>) -> anyhow::Result<()> {
         self.action_tx.send(Action::CloudWatchLogs {
-            action: CloudWatchLogsAction::GetLogGroups { token: None },
+            action: CloudWatchLogsAction::GetLogGroups {
+                token: None,
+                name_filter,
+            },
         })?;
 
         self.set_breadcrumbs()
     }
 
-    fn create_block(&self, app_state: &AppState) -> Block {
+    fn create_block(&self, app_state: &AppState) -> Block<'_> {
+        let filter_info = match &self.text_filter {
+            Some(text_filter) => format!(
+                "({}/{} {}{} filter) ",
+                self.filtered_indices.len(),
+                app_state.cloud_watch_state.log_groups.len(),
+                if text_filter.is_case_sensitive() {
+                    "case sensitive "
+                } else {
+                    ""
+                },
+                text_filter.mode()
+            ),
+            None => String::default(),
+        };
+        let cloud_watch_state = &app_state.cloud_watch_state;
+        let page_info = if cloud_watch_state.log_groups_next_token.is_some() {
+            format!(
+                "loaded {}, more available ",
+                cloud_watch_state.log_groups.len()
+            )
+        } else {
+            String::default()
+        };
+
         Block::default()
             .title(format!(
-                " CloudWatch Logs [{}] ",
-                TUI_CONFIG.key_config.focus_aws_service.key_string
+                " CloudWatch Logs {}{}[{}] ",
+                filter_info, page_info, TUI_CONFIG.key_config.focus_aws_service.key_string
             ))
             .title_alignment(Alignment::Center)
             .border_style(Style::new().fg(if self.has_focus(app_state) {
@@ -195,7 +723,7 @@ impl<'a> CloudWatchLogGroupComponent<'a> {
 
     fn set_breadcrumbs(&self) -> Result<(), anyhow::Error> {
         self.action_tx.send(Action::SetBreadcrumbs {
-            breadcrumbs: if self.filter_textarea.is_empty() {
+            breadcrumbs: if self.text_filter.is_none() {
                 vec![TUI_CONFIG.breadcrumbs.cloud_watch_logs.into()]
             } else {
                 vec![
@@ -207,3 +735,107 @@ impl<'a> CloudWatchLogGroupComponent<'a> {
         Ok(())
     }
 }
+
+/// Fingerprint of the shown columns, the list items are recreated when it changes.
+fn create_log_groups_key(log_groups: &[CloudWatchLogGroup]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for log_group in log_groups {
+        log_group.arn.hash(&mut hasher);
+        log_group.retention_days.hash(&mut hasher);
+        log_group.stored_bytes.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// The entry of the retention list, periods not offered by the list select the first one.
+fn get_retention_index(retention_days: Option<i32>) -> usize {
+    RETENTION_DAYS
+        .iter()
+        .position(|offered_days| *offered_days == retention_days)
+        .unwrap_or_default()
+}
+
+fn format_retention(retention_days: Option<i32>) -> String {
+    match retention_days {
+        Some(retention_days) => format!("{} days", retention_days),
+        None => "never expire".into(),
+    }
+}
+
+/// DescribeLogGroups supports plain prefixes and case sensitive substrings of a restricted charset.
+fn create_name_filter(text_filter: &TextFilter) -> Option<CloudWatchLogGroupNameFilter> {
+    let is_name_pattern = |expression: &str| {
+        expression
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || "._-/#".contains(character))
+    };
+
+    match text_filter.mode() {
+        FilterMode::Substring if is_name_pattern(text_filter.expression()) => Some(
+            CloudWatchLogGroupNameFilter::Pattern(text_filter.expression().into()),
+        ),
+        FilterMode::Glob => text_filter
+            .glob_prefix()
+            .map(|prefix| CloudWatchLogGroupNameFilter::Prefix(prefix.into())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+    use super::*;
+
+    fn create_component<'a>() -> (CloudWatchLogGroupComponent<'a>, UnboundedReceiver<Action>) {
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        (CloudWatchLogGroupComponent::new(action_tx), action_rx)
+    }
+
+    #[test]
+    fn test_delete_only_after_typing_the_log_group_name() {
+        let (mut component, mut action_rx) = create_component();
+        component.pending_log_group_name = Some("/aws/lambda/app".into());
+        component.is_confirming_delete = true;
+
+        component.delete_textarea.insert_str("/aws/lambda/ap");
+        component.delete_log_group().unwrap();
+        assert!(action_rx.try_recv().is_err());
+        assert!(component.is_confirming_delete);
+
+        component.delete_textarea.insert_char('p');
+        component.delete_log_group().unwrap();
+        assert!(matches!(
+            action_rx.try_recv(),
+            Ok(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::DeleteLogGroup { log_group_name }
+            }) if log_group_name == "/aws/lambda/app"
+        ));
+        assert!(!component.is_confirming_delete);
+    }
+
+    #[test]
+    fn test_map_retention_list_entries_to_days() {
+        assert_eq!(get_retention_index(None), 0);
+        assert_eq!(get_retention_index(Some(30)), 6);
+        assert_eq!(get_retention_index(Some(2)), 0);
+        assert_eq!(format_retention(RETENTION_DAYS[6]), "30 days");
+        assert_eq!(format_retention(RETENTION_DAYS[0]), "never expire");
+
+        let (mut component, mut action_rx) = create_component();
+        component.pending_log_group_name = Some("/aws/lambda/app".into());
+        component
+            .retention_list
+            .set_selected_index(get_retention_index(Some(3653)));
+        component.set_retention().unwrap();
+        assert!(matches!(
+            action_rx.try_recv(),
+            Ok(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::SetLogGroupRetention {
+                    retention_days: Some(3653),
+                    ..
+                }
+            })
+        ));
+    }
+}
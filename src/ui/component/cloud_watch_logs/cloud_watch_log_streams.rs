@@ -0,0 +1,313 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use human_bytes::human_bytes;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, CloudWatchLogsAction},
+        appstate::{AppState, ComponentType},
+        cloud_watch_logs_state::{CloudWatchLogGroup, CloudWatchLogStream},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{cloud_watch_log_events::CloudWatchLogEventComponent, format_timestamp};
+
+pub struct CloudWatchLogStreamComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    log_stream_list: ListComponent<'a>,
+    log_group_name: Option<String>,
+    log_streams_len: usize,
+    log_event_component: CloudWatchLogEventComponent,
+    is_log_event_view: bool,
+    marked_log_stream_names: Vec<String>,
+}
+
+impl<'a> Component for CloudWatchLogStreamComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        CloudWatchLogStreamComponent {
+            action_tx: action_tx.clone(),
+            log_stream_list: ListComponent::new(),
+            log_group_name: None,
+            log_streams_len: 0,
+            log_event_component: CloudWatchLogEventComponent::new(action_tx.clone()),
+            is_log_event_view: false,
+            marked_log_stream_names: vec![],
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_log_event_view {
+            return self.log_event_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.cloud_watch_logs.into(),
+                TUI_CONFIG.breadcrumbs.cloud_watch_log_streams.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.mark.into(),
+                    TUI_CONFIG.menu.follow.into(),
+                    TUI_CONFIG.menu.next_page.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_log_event_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.log_event_component.is_capturing_input()
+            {
+                self.reset_active_log_stream()?;
+            } else {
+                self.log_event_component.handle_key_event(key, app_state)?;
+            }
+        } else if self.log_stream_list.has_list_elements() {
+            match key.code {
+                val if TUI_CONFIG.list_config.selection_up == val => self.log_stream_list.move_up(),
+                val if TUI_CONFIG.list_config.selection_down == val => {
+                    self.log_stream_list.move_down();
+                    if self.log_stream_list.get_selected_index() + 1
+                        == self.log_stream_list.get_list_len()
+                    {
+                        self.load_more_log_streams(app_state)?;
+                    }
+                }
+                KeyCode::Char('n') => self.load_more_log_streams(app_state)?,
+                val if TUI_CONFIG.list_config.do_selection == val => {
+                    self.set_active_log_stream(app_state)?;
+                }
+                KeyCode::Char(' ') => self.toggle_marked_log_stream(app_state),
+                KeyCode::Char('t') => self.start_live_tail()?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_log_event_view && self.log_event_component.is_capturing_input()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.is_log_event_view {
+            self.log_event_component.render(frame, area, app_state);
+            return;
+        }
+
+        match &app_state.cloud_watch_state.selected_log_group {
+            Some(log_group) => {
+                if self.log_group_name != log_group.name {
+                    self.log_group_name = log_group.name.clone();
+                    self.marked_log_stream_names = vec![];
+                    self.create_list_items(log_group);
+                    self.log_stream_list.set_selected_index(0);
+                } else if self.log_streams_len != log_group.log_streams.len() {
+                    self.create_list_items(log_group);
+                }
+
+                let log_group_name = log_group.name.as_deref().unwrap_or("unknown log group");
+                if log_group.log_streams.is_empty() {
+                    frame.render_widget(
+                        Paragraph::new("\nNo Log Streams available")
+                            .block(self.create_block(app_state, log_group_name)),
+                        area,
+                    );
+                } else {
+                    let mut list_state = ListState::default()
+                        .with_selected(Some(self.log_stream_list.get_selected_index()));
+                    let list = List::new(self.log_stream_list.create_tui_list())
+                        .highlight_style(TUI_CONFIG.list_config.selected_style)
+                        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                        .block(self.create_block(app_state, log_group_name));
+                    frame.render_stateful_widget(list, area, &mut list_state);
+                }
+            }
+            None => frame.render_widget(
+                Paragraph::new("\nNo Log Streams available")
+                    .block(self.create_block(app_state, "unknown log group")),
+                area,
+            ),
+        }
+    }
+}
+
+impl<'a> CloudWatchLogStreamComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn reset(&mut self) {
+        self.log_group_name = None;
+        self.is_log_event_view = false;
+        self.log_event_component.reset();
+    }
+
+    pub fn is_log_event_view(&self) -> bool {
+        self.is_log_event_view
+    }
+
+    fn set_active_log_stream(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(log_stream_name) = app_state
+            .cloud_watch_state
+            .selected_log_group
+            .as_ref()
+            .and_then(|log_group| {
+                log_group
+                    .log_streams
+                    .get(self.log_stream_list.get_selected_index())
+            })
+            .and_then(|log_stream| log_stream.log_stream_name.clone())
+        {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::SelectLogStream { log_stream_name },
+            })?;
+            self.is_log_event_view = true;
+            self.log_event_component.set_focus()?;
+        }
+
+        Ok(())
+    }
+
+    fn reset_active_log_stream(&mut self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::DeselectLogStream,
+        })?;
+        self.is_log_event_view = false;
+        self.log_event_component.reset();
+        self.set_focus()
+    }
+
+    fn toggle_marked_log_stream(&mut self, app_state: &AppState) {
+        if let Some(log_group) = &app_state.cloud_watch_state.selected_log_group {
+            if let Some(log_stream_name) = log_group
+                .log_streams
+                .get(self.log_stream_list.get_selected_index())
+                .and_then(|log_stream| log_stream.log_stream_name.clone())
+            {
+                match self
+                    .marked_log_stream_names
+                    .iter()
+                    .position(|name| *name == log_stream_name)
+                {
+                    Some(index) => {
+                        self.marked_log_stream_names.remove(index);
+                    }
+                    None => self.marked_log_stream_names.push(log_stream_name),
+                }
+                self.create_list_items(log_group);
+            }
+        }
+    }
+
+    fn start_live_tail(&mut self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::CloudWatchLogs {
+            action: CloudWatchLogsAction::StartLiveTail {
+                log_stream_names: self.marked_log_stream_names.clone(),
+            },
+        })?;
+        self.is_log_event_view = true;
+        self.log_event_component.set_focus()
+    }
+
+    fn load_more_log_streams(&self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(token) = app_state
+            .cloud_watch_state
+            .selected_log_group
+            .as_ref()
+            .and_then(|log_group| log_group.log_streams_next_token.clone())
+        {
+            self.action_tx.send(Action::CloudWatchLogs {
+                action: CloudWatchLogsAction::GetMoreLogStreams { token },
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn create_list_items(&mut self, log_group: &CloudWatchLogGroup) {
+        self.log_streams_len = log_group.log_streams.len();
+        self.log_stream_list.create_list_items(
+            log_group
+                .log_streams
+                .iter()
+                .map(|log_stream| self.create_list_item(log_stream))
+                .collect::<Vec<String>>(),
+        );
+    }
+
+    fn create_list_item(&self, log_stream: &CloudWatchLogStream) -> String {
+        let name = match log_stream.log_stream_name.clone() {
+            Some(name) => name,
+            None => "unknown name".into(),
+        };
+        let marker = if self.marked_log_stream_names.contains(&name) {
+            "*"
+        } else {
+            " "
+        };
+
+        format!(
+            "{} {}  {}  {:>10}  {}",
+            marker,
+            format_timestamp(log_stream.first_event_timestamp, "no first event"),
+            format_timestamp(log_stream.last_event_timestamp, "no last event"),
+            human_bytes(log_stream.stored_bytes.unwrap_or_default() as f64),
+            name
+        )
+    }
+
+    fn create_block(&self, app_state: &AppState, log_group_name: &str) -> Block<'_> {
+        let page_info = match &app_state.cloud_watch_state.selected_log_group {
+            Some(log_group) if log_group.log_streams_next_token.is_some() => {
+                format!("loaded {}, more available ", log_group.log_streams.len())
+            }
+            _ => String::default(),
+        };
+
+        Block::default()
+            .title(format!(
+                " {} {}[{}] ",
+                log_group_name, page_info, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dynamodb_item_editor;
+pub mod dynamodb_item_table;
+pub mod dynamodb_items;
+pub mod dynamodb_partiql;
+pub mod dynamodb_tables;
@@ -0,0 +1,288 @@
+use anyhow::{bail, Context};
+use aws_sdk_dynamodb::types::AttributeValue;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{prelude::Rect, Frame};
+use serde_json::{Map, Value};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    repository::dynamodb_json::{
+        item_key, item_to_json, item_to_plain_json, json_to_item, plain_item_to_json,
+    },
+    state::{
+        action_handlers::actions::{Action, DynamoDbAction},
+        appstate::{AppState, ComponentType},
+        dynamodb_state::DynamoDbTable,
+    },
+    ui::{
+        component::{
+            base::text_edit_component::{TextEditComponent, TextEditEvent},
+            Component,
+        },
+        tui_config::TUI_CONFIG,
+    },
+};
+
+/// An item as editable JSON, either in DynamoDB JSON with the type of every value or
+/// in plain JSON, which is typed like the original item when it is saved.
+pub struct DynamoDbItemEditorComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    table: DynamoDbTable,
+    original_item: Option<Value>,
+    is_plain: bool,
+    is_active: bool,
+    text_edit: TextEditComponent<'a>,
+}
+
+impl<'a> Component for DynamoDbItemEditorComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        DynamoDbItemEditorComponent {
+            action_tx,
+            table: DynamoDbTable::default(),
+            original_item: None,
+            is_plain: false,
+            is_active: false,
+            text_edit: TextEditComponent::new(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.dynamodb_tables.into(),
+                TUI_CONFIG.breadcrumbs.dynamodb_items.into(),
+                TUI_CONFIG.breadcrumbs.dynamodb_item.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.save.into(),
+                    TUI_CONFIG.menu.toggle_json.into(),
+                ],
+                vec![TUI_CONFIG.menu.back.into()],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_active
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, _app_state: &AppState) -> anyhow::Result<()> {
+        if key.code == KeyCode::Char('t') && key.modifiers == KeyModifiers::CONTROL {
+            match self.parse_item() {
+                Ok(item) => {
+                    self.is_plain = !self.is_plain;
+                    self.text_edit.set_text(&format_item(&item, self.is_plain));
+                    self.update_title();
+                }
+                Err(err) => self.text_edit.set_err_message(format!("{:#}", err)),
+            }
+            return Ok(());
+        }
+
+        match self.text_edit.handle_key_event(key) {
+            TextEditEvent::Cancel => self.is_active = false,
+            TextEditEvent::Submit => match self.parse_item() {
+                Ok(item) => {
+                    self.action_tx.send(Action::DynamoDb {
+                        action: DynamoDbAction::SaveItem {
+                            table_name: self.table.table_name.clone(),
+                            key_names: self.table.key_names(),
+                            original_item: self.original_item.clone(),
+                            item,
+                        },
+                    })?;
+                    self.is_active = false;
+                }
+                Err(err) => self.text_edit.set_err_message(format!("{:#}", err)),
+            },
+            TextEditEvent::Input => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        self.text_edit.render(
+            frame,
+            area,
+            app_state.focus_component == self.component_type(),
+        );
+    }
+}
+
+impl<'a> DynamoDbItemEditorComponent<'a> {
+    /// Edits the item, or a new item with empty keys if none is given.
+    pub fn show(&mut self, table: &DynamoDbTable, item: Option<&Value>) -> anyhow::Result<()> {
+        self.table = table.clone();
+        self.original_item = item.cloned();
+        self.is_plain = false;
+        self.is_active = true;
+        self.text_edit.set_text(&format_item(
+            &item.cloned().unwrap_or_else(|| create_new_item(table)),
+            self.is_plain,
+        ));
+        self.update_title();
+        self.set_focus()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    fn update_title(&mut self) {
+        let item_text = match &self.original_item {
+            Some(original_item) => format!(
+                "item {}",
+                item_to_plain_json(&item_key(original_item, &self.table.key_names()))
+            ),
+            None => "new item".into(),
+        };
+        self.text_edit.set_title(format!(
+            "{}: {} in {} JSON (<ctrl-s> save, <ctrl-t> typed/plain, <esc> cancel)",
+            self.table.table_name,
+            item_text,
+            if self.is_plain { "plain" } else { "DynamoDB" }
+        ));
+    }
+
+    fn parse_item(&self) -> anyhow::Result<Value> {
+        parse_item(
+            &self.text_edit.get_text(),
+            self.is_plain,
+            self.original_item.as_ref(),
+            &self.table,
+        )
+    }
+}
+
+fn format_item(item: &Value, is_plain: bool) -> String {
+    let item = if is_plain {
+        item_to_plain_json(item)
+    } else {
+        item.clone()
+    };
+    serde_json::to_string_pretty(&item).unwrap_or_default()
+}
+
+fn create_new_item(table: &DynamoDbTable) -> Value {
+    Value::Object(
+        [Some(&table.partition_key), table.sort_key.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|key| {
+                let value = if key.attribute_type == "N" { "0" } else { "" };
+                let mut typed_value = Map::new();
+                typed_value.insert(key.attribute_type.clone(), Value::String(value.into()));
+                (key.attribute_name.clone(), Value::Object(typed_value))
+            })
+            .collect::<Map<String, Value>>(),
+    )
+}
+
+/// The DynamoDB JSON of the edited text, an error if it is no valid item of the table.
+fn parse_item(
+    text: &str,
+    is_plain: bool,
+    original_item: Option<&Value>,
+    table: &DynamoDbTable,
+) -> anyhow::Result<Value> {
+    let value = serde_json::from_str::<Value>(text).context("Invalid JSON")?;
+    let value = if is_plain {
+        plain_item_to_json(&value, original_item)
+    } else {
+        value
+    };
+    let item = json_to_item(&value)?;
+
+    for key in [Some(&table.partition_key), table.sort_key.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        let is_valid = match (key.attribute_type.as_str(), item.get(&key.attribute_name)) {
+            ("S", Some(AttributeValue::S(text))) => !text.is_empty(),
+            ("N", Some(AttributeValue::N(_))) => true,
+            ("B", Some(AttributeValue::B(blob))) => !blob.as_ref().is_empty(),
+            _ => false,
+        };
+        if !is_valid {
+            bail!(
+                "The key {} must be a non-empty value of type {}",
+                key.attribute_name,
+                key.attribute_type
+            );
+        }
+    }
+
+    Ok(item_to_json(&item))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::state::dynamodb_state::DynamoDbKey;
+
+    use super::*;
+
+    fn create_table() -> DynamoDbTable {
+        DynamoDbTable {
+            table_name: "users".into(),
+            partition_key: DynamoDbKey {
+                attribute_name: "id".into(),
+                attribute_type: "N".into(),
+            },
+            ..DynamoDbTable::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_typed_and_plain_items_of_the_table() {
+        let table = create_table();
+        let original_item = json!({ "id": { "N": "1" }, "tags": { "SS": ["a"] } });
+
+        assert_eq!(
+            parse_item(
+                r#"{ "tags": ["a", "b"], "id": 1 }"#,
+                true,
+                Some(&original_item),
+                &table
+            )
+            .unwrap(),
+            json!({ "id": { "N": "1" }, "tags": { "SS": ["a", "b"] } })
+        );
+        assert_eq!(
+            parse_item(&format_item(&original_item, false), false, None, &table).unwrap(),
+            original_item
+        );
+    }
+
+    #[test]
+    fn test_create_new_item_with_key_attributes() {
+        assert_eq!(
+            create_new_item(&create_table()),
+            json!({ "id": { "N": "0" } })
+        );
+    }
+
+    #[test]
+    fn test_reject_invalid_items_and_key_types() {
+        let table = create_table();
+        assert!(parse_item("{ \"id\": ", false, None, &table).is_err());
+        assert!(parse_item(r#"{ "id": { "S": "1" } }"#, false, None, &table).is_err());
+        assert!(parse_item(r#"{ "name": "a" }"#, true, None, &table).is_err());
+    }
+}
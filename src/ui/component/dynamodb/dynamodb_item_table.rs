@@ -0,0 +1,200 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    prelude::Rect,
+    style::{Modifier, Style},
+    widgets::{Block, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use serde_json::Value;
+
+use crate::{repository::dynamodb_json::format_cell_value, ui::tui_config::TUI_CONFIG};
+
+const MAX_COLUMN_WIDTH: usize = 40;
+
+/// A table of DynamoDB items, scrolled by row and by column, as the attributes of
+/// the items rarely fit into the screen.
+pub struct DynamoDbItemTable {
+    selected_row: usize,
+    selected_column: usize,
+    column_offset: usize,
+    page_height: usize,
+}
+
+impl DynamoDbItemTable {
+    pub fn new() -> Self {
+        DynamoDbItemTable {
+            selected_row: 0,
+            selected_column: 0,
+            column_offset: 0,
+            page_height: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.selected_row = 0;
+        self.selected_column = 0;
+        self.column_offset = 0;
+    }
+
+    pub fn get_selected_row(&self) -> usize {
+        self.selected_row
+    }
+
+    /// Moves the selection, returns false for keys the table does not handle.
+    pub fn handle_key_code(
+        &mut self,
+        key_code: KeyCode,
+        rows_len: usize,
+        columns_len: usize,
+    ) -> bool {
+        let last_row = rows_len.saturating_sub(1);
+        match key_code {
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.selected_row = self.selected_row.saturating_sub(1)
+            }
+            val if TUI_CONFIG.list_config.selection_down == val => {
+                self.selected_row = (self.selected_row + 1).min(last_row)
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.selected_row = self.selected_row.saturating_sub(self.page_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.selected_row = (self.selected_row + self.page_height).min(last_row)
+            }
+            val if TUI_CONFIG.list_config.jump_to_start == val => self.selected_row = 0,
+            val if TUI_CONFIG.list_config.jump_to_end == val => self.selected_row = last_row,
+            val if TUI_CONFIG.list_config.scroll_left == val => {
+                self.selected_column = self.selected_column.saturating_sub(1)
+            }
+            val if TUI_CONFIG.list_config.scroll_right == val => {
+                self.selected_column = (self.selected_column + 1).min(columns_len.saturating_sub(1))
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        block: Block,
+        columns: &[String],
+        rows: &[Vec<String>],
+    ) {
+        // the border and the header row take three lines
+        self.page_height = area.height.saturating_sub(3) as usize;
+        self.selected_row = self.selected_row.min(rows.len().saturating_sub(1));
+        self.selected_column = self.selected_column.min(columns.len().saturating_sub(1));
+
+        if rows.is_empty() {
+            frame.render_widget(Paragraph::new("\nNo items available").block(block), area);
+            return;
+        }
+
+        let column_widths = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                rows.iter()
+                    .map(|row| row[index].chars().count())
+                    .chain([column.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+                    .min(MAX_COLUMN_WIDTH)
+            })
+            .collect::<Vec<usize>>();
+        self.update_column_offset(&column_widths, area.width.saturating_sub(2) as usize);
+
+        let header = Row::new(columns.iter().enumerate().skip(self.column_offset).map(
+            |(index, column)| {
+                let style = if index == self.selected_column {
+                    Style::new()
+                        .fg(TUI_CONFIG.theme.border_highlight)
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                } else {
+                    Style::new().add_modifier(Modifier::BOLD)
+                };
+                Cell::from(column.as_str()).style(style)
+            },
+        ));
+
+        let widths = column_widths
+            .iter()
+            .skip(self.column_offset)
+            .map(|width| Constraint::Length(*width as u16))
+            .collect::<Vec<Constraint>>();
+
+        let table = Table::new(rows.iter().map(|row| {
+            Row::new(
+                row.iter()
+                    .skip(self.column_offset)
+                    .map(|value| Cell::from(value.replace('\n', " "))),
+            )
+        }))
+        .header(header)
+        .widths(&widths)
+        .column_spacing(2)
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .block(block);
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected_row));
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    fn update_column_offset(&mut self, column_widths: &[usize], available_width: usize) {
+        if self.selected_column < self.column_offset {
+            self.column_offset = self.selected_column;
+        }
+
+        // move the first visible column until the selected column fits into the table
+        while self.column_offset < self.selected_column
+            && column_widths[self.column_offset..=self.selected_column]
+                .iter()
+                .map(|width| width + 2)
+                .sum::<usize>()
+                > available_width
+        {
+            self.column_offset += 1;
+        }
+    }
+}
+
+/// The cells of the items, empty where an item lacks the attribute.
+pub fn create_rows(items: &[Value], columns: &[String]) -> Vec<Vec<String>> {
+    items
+        .iter()
+        .map(|item| {
+            columns
+                .iter()
+                .map(|column| item.get(column).map(format_cell_value).unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::repository::dynamodb_json::infer_columns;
+
+    use super::*;
+
+    #[test]
+    fn test_infer_columns_with_key_attributes_first() {
+        let items = vec![
+            json!({ "name": { "S": "a" }, "id": { "N": "1" }, "tags": { "SS": ["x", "y"] } }),
+            json!({ "id": { "N": "2" }, "age": { "N": "42" } }),
+        ];
+
+        let columns = infer_columns(&items, &["id"]);
+        assert_eq!(columns, vec!["id", "age", "name", "tags"]);
+        assert_eq!(
+            create_rows(&items, &columns),
+            vec![vec!["1", "", "a", "[\"x\",\"y\"]"], vec!["2", "42", "", ""]]
+        );
+    }
+}
@@ -0,0 +1,733 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    repository::dynamodb_json::{infer_columns, item_key, item_to_plain_json},
+    state::{
+        action_handlers::actions::{Action, DynamoDbAction},
+        appstate::{AppState, ComponentType},
+        dynamodb_state::{
+            DynamoDbExportFormat, DynamoDbKey, DynamoDbKeyCondition, DynamoDbQuery,
+            DynamoDbSortKeyCondition, DynamoDbSortKeyOperator, DynamoDbTable,
+        },
+    },
+    ui::{component::Component, tui_config::TUI_CONFIG},
+};
+
+use super::{
+    dynamodb_item_editor::DynamoDbItemEditorComponent,
+    dynamodb_item_table::{create_rows, DynamoDbItemTable},
+};
+
+const SORT_KEY_OPERATORS: [(Option<DynamoDbSortKeyOperator>, &str); 8] = [
+    (None, "none"),
+    (Some(DynamoDbSortKeyOperator::Equal), "="),
+    (Some(DynamoDbSortKeyOperator::Less), "<"),
+    (Some(DynamoDbSortKeyOperator::LessOrEqual), "<="),
+    (Some(DynamoDbSortKeyOperator::Greater), ">"),
+    (Some(DynamoDbSortKeyOperator::GreaterOrEqual), ">="),
+    (Some(DynamoDbSortKeyOperator::BeginsWith), "begins_with"),
+    (Some(DynamoDbSortKeyOperator::Between), "between"),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum QueryField {
+    Index,
+    PartitionKeyValue,
+    SortKeyOperator,
+    SortKeyValue,
+    SecondSortKeyValue,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FileDialog {
+    Export,
+    Import,
+}
+
+/// The items of a table, fetched page by page by a scan or a query of the table
+/// or one of its indexes.
+pub struct DynamoDbItemComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    table: DynamoDbTable,
+    item_table: DynamoDbItemTable,
+    is_query_form: bool,
+    selected_field: QueryField,
+    index_position: usize,
+    sort_key_operator_position: usize,
+    partition_key_textarea: TextArea<'a>,
+    sort_key_textarea: TextArea<'a>,
+    second_sort_key_textarea: TextArea<'a>,
+    item_editor: DynamoDbItemEditorComponent<'a>,
+    pending_delete_key: Option<Value>,
+    file_dialog: Option<FileDialog>,
+    file_textarea: TextArea<'a>,
+    export_format: DynamoDbExportFormat,
+}
+
+impl<'a> Component for DynamoDbItemComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        DynamoDbItemComponent {
+            item_editor: DynamoDbItemEditorComponent::new(action_tx.clone()),
+            pending_delete_key: None,
+            file_dialog: None,
+            file_textarea: TextArea::default(),
+            export_format: DynamoDbExportFormat::default(),
+            action_tx,
+            table: DynamoDbTable::default(),
+            item_table: DynamoDbItemTable::new(),
+            is_query_form: false,
+            selected_field: QueryField::Index,
+            index_position: 0,
+            sort_key_operator_position: 0,
+            partition_key_textarea: TextArea::default(),
+            sort_key_textarea: TextArea::default(),
+            second_sort_key_textarea: TextArea::default(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.item_editor.is_active() {
+            return self.item_editor.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.dynamodb_tables.into(),
+                TUI_CONFIG.breadcrumbs.dynamodb_items.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.scan.into(),
+                    TUI_CONFIG.menu.query.into(),
+                    TUI_CONFIG.menu.next_page.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.edit_item.into(),
+                    TUI_CONFIG.menu.create_item.into(),
+                    TUI_CONFIG.menu.delete.into(),
+                    TUI_CONFIG.menu.export_items.into(),
+                    TUI_CONFIG.menu.import_items.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.scroll_horizontal.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_query_form
+            || self.pending_delete_key.is_some()
+            || self.file_dialog.is_some()
+            || self.item_editor.is_active()
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.item_editor.is_active() {
+            self.item_editor.handle_key_event(key, app_state)?;
+            if !self.item_editor.is_active() {
+                self.set_focus()?;
+            }
+            return Ok(());
+        }
+
+        if self.is_query_form {
+            return self.handle_query_form_key_event(key);
+        }
+
+        if let Some(file_dialog) = self.file_dialog {
+            return self.handle_file_dialog_key_event(key, file_dialog);
+        }
+
+        if let Some(key_value) = self.pending_delete_key.clone() {
+            match key.code {
+                KeyCode::Esc => self.pending_delete_key = None,
+                KeyCode::Enter => {
+                    self.action_tx.send(Action::DynamoDb {
+                        action: DynamoDbAction::DeleteItem {
+                            table_name: self.table.table_name.clone(),
+                            key: key_value,
+                        },
+                    })?;
+                    self.pending_delete_key = None;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let dynamodb_state = &app_state.dynamodb_state;
+        let selected_item = dynamodb_state.items.get(self.item_table.get_selected_row());
+        match key.code {
+            KeyCode::Char('e') => {
+                if let Some(item) = selected_item {
+                    self.item_editor.show(&self.table, Some(item))?;
+                }
+            }
+            KeyCode::Char('a') => self.item_editor.show(&self.table, None)?,
+            KeyCode::Char('d') => {
+                self.pending_delete_key =
+                    selected_item.map(|item| item_key(item, &self.table.key_names()));
+            }
+            KeyCode::Char('s') => self.fetch_items(DynamoDbQuery {
+                table_name: self.table.table_name.clone(),
+                ..DynamoDbQuery::default()
+            })?,
+            KeyCode::Char('q') => {
+                self.selected_field = QueryField::Index;
+                self.is_query_form = true;
+            }
+            KeyCode::Char('n') => self.action_tx.send(Action::DynamoDb {
+                action: DynamoDbAction::FetchNextItems,
+            })?,
+            KeyCode::Char('w') if dynamodb_state.query.is_some() => {
+                self.show_file_dialog(FileDialog::Export)
+            }
+            KeyCode::Char('i') => self.show_file_dialog(FileDialog::Import),
+            KeyCode::Char('u') => {
+                if let Some(query) = dynamodb_state.query.clone() {
+                    self.fetch_items(query)?;
+                }
+            }
+            key_code => {
+                let columns_len = self.get_columns(app_state).len();
+                self.item_table
+                    .handle_key_code(key_code, dynamodb_state.items.len(), columns_len);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.item_editor.is_active() {
+            self.item_editor.render(frame, area, app_state);
+            return;
+        }
+
+        let dynamodb_state = &app_state.dynamodb_state;
+        let columns = self.get_columns(app_state);
+        let rows = create_rows(&dynamodb_state.items, &columns);
+
+        let query_text = match &dynamodb_state.query {
+            Some(DynamoDbQuery {
+                index_name,
+                key_condition,
+                ..
+            }) => format!(
+                "{} of {}",
+                if key_condition.is_some() {
+                    "Query"
+                } else {
+                    "Scan"
+                },
+                index_name.as_deref().unwrap_or("table")
+            ),
+            None => "Items".into(),
+        };
+        let block = Block::default()
+            .title(format!(
+                " {}: {} ({}/{}{}) [{}] ",
+                self.table.table_name,
+                query_text,
+                (self.item_table.get_selected_row() + 1).min(rows.len()),
+                rows.len(),
+                if dynamodb_state.last_evaluated_key.is_some() {
+                    ", more with <n>"
+                } else {
+                    ""
+                },
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(
+                if app_state.focus_component == self.component_type() && !self.is_query_form {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                },
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        self.item_table.render(frame, area, block, &columns, &rows);
+
+        if self.is_query_form {
+            self.render_query_form(frame, app_state);
+        }
+
+        if self.file_dialog.is_some() {
+            let text_area = self.centered_rect(60, 10, app_state.area);
+            frame.render_widget(Clear, text_area);
+            frame.render_widget(self.file_textarea.widget(), text_area);
+        }
+
+        if let Some(key_value) = &self.pending_delete_key {
+            let popup_area = self.centered_rect(50, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "Delete item {}? <enter> confirm, <esc> cancel",
+                    item_to_plain_json(key_value)
+                ))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL)),
+                popup_area,
+            );
+        }
+    }
+}
+
+impl<'a> DynamoDbItemComponent<'a> {
+    pub fn show(&mut self, table: DynamoDbTable) -> anyhow::Result<()> {
+        self.table = table;
+        self.is_query_form = false;
+        self.pending_delete_key = None;
+        self.file_dialog = None;
+        self.index_position = 0;
+        self.sort_key_operator_position = 0;
+        self.partition_key_textarea = create_value_textarea();
+        self.sort_key_textarea = create_value_textarea();
+        self.second_sort_key_textarea = create_value_textarea();
+        self.fetch_items(DynamoDbQuery {
+            table_name: self.table.table_name.clone(),
+            ..DynamoDbQuery::default()
+        })?;
+        self.set_focus()
+    }
+
+    fn fetch_items(&mut self, query: DynamoDbQuery) -> anyhow::Result<()> {
+        self.item_table.reset();
+        self.action_tx.send(Action::DynamoDb {
+            action: DynamoDbAction::FetchItems {
+                query: Box::new(query),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    /// The path of the file to export the scan or query to, or to import items from.
+    fn show_file_dialog(&mut self, file_dialog: FileDialog) {
+        let path = match file_dialog {
+            FileDialog::Export => format!(
+                "{}-{}.{}",
+                self.table.table_name,
+                Utc::now().format("%Y%m%dT%H%M%S"),
+                self.export_format.extension()
+            ),
+            FileDialog::Import => String::default(),
+        };
+
+        self.file_textarea = TextArea::new(vec![path]);
+        self.file_textarea.set_cursor_line_style(Style::default());
+        self.file_textarea.set_placeholder_text("items.jsonl");
+        self.file_textarea
+            .move_cursor(tui_textarea::CursorMove::End);
+        self.file_dialog = Some(file_dialog);
+        self.update_file_dialog_block();
+    }
+
+    fn handle_file_dialog_key_event(
+        &mut self,
+        key: KeyEvent,
+        file_dialog: FileDialog,
+    ) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.file_dialog = None,
+            KeyCode::Enter => {
+                let path = self.file_textarea.lines().join("");
+                if path.trim().is_empty() {
+                    return Ok(());
+                }
+
+                let path = PathBuf::from(path.trim());
+                let action = match file_dialog {
+                    FileDialog::Export => DynamoDbAction::ExportItems {
+                        path,
+                        format: self.export_format,
+                    },
+                    FileDialog::Import => DynamoDbAction::ImportItems {
+                        table_name: self.table.table_name.clone(),
+                        path,
+                    },
+                };
+                self.action_tx.send(Action::DynamoDb { action })?;
+                self.file_dialog = None;
+            }
+            KeyCode::Tab if file_dialog == FileDialog::Export => {
+                let path = self.file_textarea.lines().join("");
+                let path = path
+                    .strip_suffix(&format!(".{}", self.export_format.extension()))
+                    .unwrap_or(&path)
+                    .to_string();
+                self.export_format = self.export_format.next();
+                self.file_textarea =
+                    TextArea::new(vec![format!("{}.{}", path, self.export_format.extension())]);
+                self.file_textarea.set_cursor_line_style(Style::default());
+                self.file_textarea
+                    .move_cursor(tui_textarea::CursorMove::End);
+                self.update_file_dialog_block();
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ => {
+                self.file_textarea.input(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_file_dialog_block(&mut self) {
+        let title = match self.file_dialog {
+            Some(FileDialog::Export) => format!(
+                "Export all items of the scan or query as {} (<tab> format)",
+                self.export_format.extension()
+            ),
+            _ => format!(
+                "Import JSON Lines of DynamoDB items into {}",
+                self.table.table_name
+            ),
+        };
+
+        self.file_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center),
+        );
+    }
+
+    /// The key attributes of the queried index come first, followed by those of the table.
+    fn get_columns(&self, app_state: &AppState) -> Vec<String> {
+        let dynamodb_state = &app_state.dynamodb_state;
+        let index = dynamodb_state
+            .query
+            .as_ref()
+            .and_then(|query| query.index_name.as_ref())
+            .and_then(|index_name| {
+                self.table
+                    .indexes
+                    .iter()
+                    .find(|index| &index.index_name == index_name)
+            });
+
+        let mut key_attributes = vec![];
+        for (partition_key, sort_key) in index
+            .map(|index| (&index.partition_key, &index.sort_key))
+            .into_iter()
+            .chain([(&self.table.partition_key, &self.table.sort_key)])
+        {
+            for key in [Some(partition_key), sort_key.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                if !key.attribute_name.is_empty()
+                    && !key_attributes.contains(&key.attribute_name.as_str())
+                {
+                    key_attributes.push(key.attribute_name.as_str());
+                }
+            }
+        }
+
+        infer_columns(&dynamodb_state.items, &key_attributes)
+    }
+
+    /// The partition and sort key of the table or of the selected index.
+    fn get_selected_keys(&self) -> (&DynamoDbKey, Option<&DynamoDbKey>) {
+        match self.index_position.checked_sub(1) {
+            Some(position) => {
+                let index = &self.table.indexes[position];
+                (&index.partition_key, index.sort_key.as_ref())
+            }
+            None => (&self.table.partition_key, self.table.sort_key.as_ref()),
+        }
+    }
+
+    fn get_visible_fields(&self) -> Vec<QueryField> {
+        let mut fields = vec![QueryField::Index, QueryField::PartitionKeyValue];
+        if self.get_selected_keys().1.is_some() {
+            fields.push(QueryField::SortKeyOperator);
+            match SORT_KEY_OPERATORS[self.sort_key_operator_position].0 {
+                Some(DynamoDbSortKeyOperator::Between) => {
+                    fields.push(QueryField::SortKeyValue);
+                    fields.push(QueryField::SecondSortKeyValue);
+                }
+                Some(_) => fields.push(QueryField::SortKeyValue),
+                None => {}
+            }
+        }
+        fields
+    }
+
+    fn handle_query_form_key_event(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        let fields = self.get_visible_fields();
+        let position = fields
+            .iter()
+            .position(|field| *field == self.selected_field)
+            .unwrap_or_default();
+
+        match (key.code, self.selected_field) {
+            (KeyCode::Esc, _) => self.is_query_form = false,
+            (KeyCode::Enter, _) => {
+                let query = create_query(
+                    &self.table,
+                    self.index_position,
+                    &self.partition_key_textarea.lines().join(""),
+                    SORT_KEY_OPERATORS[self.sort_key_operator_position].0,
+                    &self.sort_key_textarea.lines().join(""),
+                    &self.second_sort_key_textarea.lines().join(""),
+                );
+                self.is_query_form = false;
+                self.fetch_items(query)?;
+            }
+            (KeyCode::Up, _) => self.selected_field = fields[position.saturating_sub(1)],
+            (KeyCode::Down, _) => {
+                self.selected_field = fields[(position + 1).min(fields.len() - 1)]
+            }
+            (KeyCode::Left, QueryField::Index) => {
+                self.index_position = self.index_position.saturating_sub(1)
+            }
+            (KeyCode::Right, QueryField::Index) => {
+                self.index_position = (self.index_position + 1).min(self.table.indexes.len())
+            }
+            (KeyCode::Left, QueryField::SortKeyOperator) => {
+                self.sort_key_operator_position = self.sort_key_operator_position.saturating_sub(1)
+            }
+            (KeyCode::Right, QueryField::SortKeyOperator) => {
+                self.sort_key_operator_position =
+                    (self.sort_key_operator_position + 1).min(SORT_KEY_OPERATORS.len() - 1)
+            }
+            (_, QueryField::PartitionKeyValue) => {
+                self.partition_key_textarea.input(key);
+            }
+            (_, QueryField::SortKeyValue) => {
+                self.sort_key_textarea.input(key);
+            }
+            (_, QueryField::SecondSortKeyValue) => {
+                self.second_sort_key_textarea.input(key);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render_query_form(&mut self, frame: &mut Frame, app_state: &AppState) {
+        let fields = self.get_visible_fields();
+        let popup_area = self.centered_rect(60, 60, app_state.area);
+        frame.render_widget(Clear, popup_area);
+
+        let block = Block::default()
+            .title(" Query (<up/down> field, <left/right> option, <enter> run, <esc> cancel) ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL);
+        let inner_area = block.inner(popup_area);
+        frame.render_widget(block, popup_area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                fields
+                    .iter()
+                    .map(|_| Constraint::Length(3))
+                    .chain([Constraint::Min(0)])
+                    .collect::<Vec<Constraint>>(),
+            )
+            .split(inner_area);
+
+        let (partition_key, sort_key) = self.get_selected_keys();
+        let partition_title = format!(
+            " {} ({}), empty to scan ",
+            partition_key.attribute_name, partition_key.attribute_type
+        );
+        let sort_title = sort_key
+            .map(|key| format!(" {} ({}) ", key.attribute_name, key.attribute_type))
+            .unwrap_or_default();
+        let index_text = match self.index_position.checked_sub(1) {
+            Some(position) => {
+                let index = &self.table.indexes[position];
+                format!(
+                    "< {} ({}) >",
+                    index.index_name,
+                    if index.is_global { "GSI" } else { "LSI" }
+                )
+            }
+            None => "< table >".into(),
+        };
+        let operator_text = format!(
+            "< {} >",
+            SORT_KEY_OPERATORS[self.sort_key_operator_position].1
+        );
+
+        for (field, area) in fields.iter().zip(layout.iter()) {
+            let field_block = |title: String| {
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(Style::new().fg(if *field == self.selected_field {
+                        TUI_CONFIG.theme.border_highlight
+                    } else {
+                        TUI_CONFIG.theme.border
+                    }))
+            };
+
+            match field {
+                QueryField::Index => frame.render_widget(
+                    Paragraph::new(index_text.clone()).block(field_block(" Index ".into())),
+                    *area,
+                ),
+                QueryField::SortKeyOperator => frame.render_widget(
+                    Paragraph::new(operator_text.clone())
+                        .block(field_block(" Sort key condition ".into())),
+                    *area,
+                ),
+                QueryField::PartitionKeyValue => {
+                    self.partition_key_textarea
+                        .set_block(field_block(partition_title.clone()));
+                    frame.render_widget(self.partition_key_textarea.widget(), *area);
+                }
+                QueryField::SortKeyValue => {
+                    self.sort_key_textarea
+                        .set_block(field_block(sort_title.clone()));
+                    frame.render_widget(self.sort_key_textarea.widget(), *area);
+                }
+                QueryField::SecondSortKeyValue => {
+                    self.second_sort_key_textarea
+                        .set_block(field_block(format!("{}upper bound ", sort_title)));
+                    frame.render_widget(self.second_sort_key_textarea.widget(), *area);
+                }
+            }
+        }
+    }
+}
+
+fn create_value_textarea<'a>() -> TextArea<'a> {
+    let mut textarea = TextArea::default();
+    textarea.set_cursor_line_style(Style::default());
+    textarea
+}
+
+/// A query of the table (index position 0) or one of its indexes. Without a partition
+/// key value the table or index is scanned, without an operator all sort keys match.
+fn create_query(
+    table: &DynamoDbTable,
+    index_position: usize,
+    partition_key_value: &str,
+    operator: Option<DynamoDbSortKeyOperator>,
+    value: &str,
+    second_value: &str,
+) -> DynamoDbQuery {
+    let index = index_position
+        .checked_sub(1)
+        .and_then(|position| table.indexes.get(position));
+    let (partition_key, sort_key) = match index {
+        Some(index) => (&index.partition_key, &index.sort_key),
+        None => (&table.partition_key, &table.sort_key),
+    };
+
+    DynamoDbQuery {
+        table_name: table.table_name.clone(),
+        index_name: index.map(|index| index.index_name.clone()),
+        key_condition: (!partition_key_value.is_empty()).then(|| DynamoDbKeyCondition {
+            partition_key: partition_key.clone(),
+            partition_key_value: partition_key_value.into(),
+            sort_key_condition: sort_key.as_ref().zip(operator).map(|(sort_key, operator)| {
+                DynamoDbSortKeyCondition {
+                    sort_key: sort_key.clone(),
+                    operator,
+                    value: value.into(),
+                    second_value: second_value.into(),
+                }
+            }),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::dynamodb_state::DynamoDbIndex;
+
+    use super::*;
+
+    #[test]
+    fn test_create_scans_and_queries_of_tables_and_indexes() {
+        let key = |attribute_name: &str| DynamoDbKey {
+            attribute_name: attribute_name.into(),
+            attribute_type: "S".into(),
+        };
+        let table = DynamoDbTable {
+            table_name: "orders".into(),
+            partition_key: key("customer"),
+            sort_key: Some(key("order")),
+            indexes: vec![DynamoDbIndex {
+                index_name: "by-status".into(),
+                is_global: true,
+                partition_key: key("status"),
+                sort_key: None,
+            }],
+            ..DynamoDbTable::default()
+        };
+
+        let scan = create_query(&table, 1, "", None, "", "");
+        assert_eq!(scan.index_name.as_deref(), Some("by-status"));
+        assert!(scan.key_condition.is_none());
+
+        let query = create_query(
+            &table,
+            0,
+            "alice",
+            Some(DynamoDbSortKeyOperator::BeginsWith),
+            "2023-",
+            "",
+        );
+        let key_condition = query.key_condition.unwrap();
+        assert_eq!(query.index_name, None);
+        assert_eq!(key_condition.partition_key.attribute_name, "customer");
+        assert_eq!(key_condition.sort_key_condition.unwrap().value, "2023-");
+
+        let index_query = create_query(
+            &table,
+            1,
+            "OPEN",
+            Some(DynamoDbSortKeyOperator::Equal),
+            "x",
+            "",
+        );
+        assert!(index_query
+            .key_condition
+            .unwrap()
+            .sort_key_condition
+            .is_none());
+    }
+}
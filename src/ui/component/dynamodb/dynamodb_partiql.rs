@@ -0,0 +1,293 @@
+use std::sync::OnceLock;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders},
+    Frame,
+};
+use regex::Regex;
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    repository::dynamodb_json::infer_columns,
+    state::{
+        action_handlers::actions::{Action, DynamoDbAction},
+        appstate::{AppState, ComponentType},
+    },
+    ui::{component::Component, tui_config::TUI_CONFIG},
+};
+
+use super::dynamodb_item_table::{create_rows, DynamoDbItemTable};
+
+/// A console for PartiQL statements of the selected region. The statements of the
+/// session are kept as history, the results are shown like the items of a scan.
+pub struct DynamoDbPartiQlComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    statement_textarea: TextArea<'a>,
+    is_editing_statement: bool,
+    history_position: Option<usize>,
+    item_table: DynamoDbItemTable,
+}
+
+impl<'a> Component for DynamoDbPartiQlComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        DynamoDbPartiQlComponent {
+            action_tx,
+            statement_textarea: create_statement_textarea(""),
+            is_editing_statement: true,
+            history_position: None,
+            item_table: DynamoDbItemTable::new(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.dynamodb_tables.into(),
+                TUI_CONFIG.breadcrumbs.dynamodb_partiql.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.edit_statement.into(),
+                    TUI_CONFIG.menu.run_statement.into(),
+                    TUI_CONFIG.menu.next_page.into(),
+                ],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.scroll_horizontal.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_editing_statement
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        let statement_state = &app_state.dynamodb_state.statement_state;
+
+        if self.is_editing_statement {
+            match key.code {
+                KeyCode::Esc => self.is_editing_statement = false,
+                KeyCode::Enter => self.run_statement()?,
+                KeyCode::Up | KeyCode::Down => {
+                    self.history_position = navigate_history(
+                        statement_state.history.len(),
+                        self.history_position,
+                        key.code == KeyCode::Up,
+                    );
+                    self.statement_textarea = create_statement_textarea(
+                        self.history_position
+                            .map(|position| statement_state.history[position].as_str())
+                            .unwrap_or_default(),
+                    );
+                }
+                KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+                _ => {
+                    self.statement_textarea.input(key);
+                }
+            }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Char('e') => self.is_editing_statement = true,
+            KeyCode::Char('r') => self.run_statement()?,
+            KeyCode::Char('n') => self.action_tx.send(Action::DynamoDb {
+                action: DynamoDbAction::FetchNextStatementItems,
+            })?,
+            key_code => {
+                let columns_len = self.get_columns(app_state).len();
+                self.item_table
+                    .handle_key_code(key_code, statement_state.items.len(), columns_len);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let statement_state = &app_state.dynamodb_state.statement_state;
+        let has_focus = app_state.focus_component == self.component_type();
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        self.statement_textarea.set_block(
+            Block::default()
+                .title(if self.is_editing_statement {
+                    " PartiQL (<enter> run, <up/down> history, <esc> results) "
+                } else {
+                    " PartiQL (<e> edit) "
+                })
+                .title_alignment(Alignment::Center)
+                .border_style(Style::new().fg(if has_focus && self.is_editing_statement {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                }))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        );
+        frame.render_widget(self.statement_textarea.widget(), layout[0]);
+
+        let columns = self.get_columns(app_state);
+        let rows = create_rows(&statement_state.items, &columns);
+        let block = Block::default()
+            .title(format!(
+                " Results ({}/{}{}) [{}] ",
+                (self.item_table.get_selected_row() + 1).min(rows.len()),
+                rows.len(),
+                if statement_state.next_token.is_some() {
+                    ", more with <n>"
+                } else {
+                    ""
+                },
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if has_focus && !self.is_editing_statement {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+        self.item_table
+            .render(frame, layout[1], block, &columns, &rows);
+    }
+}
+
+impl<'a> DynamoDbPartiQlComponent<'a> {
+    pub fn show(&mut self) -> anyhow::Result<()> {
+        self.is_editing_statement = true;
+        self.history_position = None;
+        self.set_focus()
+    }
+
+    fn run_statement(&mut self) -> anyhow::Result<()> {
+        let statement = self.statement_textarea.lines().join(" ");
+        if statement.trim().is_empty() {
+            return Ok(());
+        }
+
+        self.is_editing_statement = false;
+        self.history_position = None;
+        self.item_table.reset();
+        self.action_tx.send(Action::DynamoDb {
+            action: DynamoDbAction::ExecuteStatement {
+                statement: statement.trim().into(),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    /// The key attributes of the selected table come first, if it is a known one.
+    fn get_columns(&self, app_state: &AppState) -> Vec<String> {
+        let dynamodb_state = &app_state.dynamodb_state;
+        let statement_state = &dynamodb_state.statement_state;
+        let key_names = statement_state
+            .statement
+            .as_deref()
+            .and_then(get_table_name)
+            .and_then(|table_name| {
+                dynamodb_state
+                    .tables
+                    .iter()
+                    .find(|table| table.table_name == table_name)
+            })
+            .map(|table| table.key_names())
+            .unwrap_or_default();
+
+        infer_columns(
+            &statement_state.items,
+            &key_names
+                .iter()
+                .map(|key_name| key_name.as_str())
+                .collect::<Vec<&str>>(),
+        )
+    }
+}
+
+fn create_statement_textarea<'a>(statement: &str) -> TextArea<'a> {
+    let mut textarea = TextArea::new(vec![statement.to_string()]);
+    textarea.set_cursor_line_style(Style::default());
+    textarea.set_placeholder_text("SELECT * FROM \"table\" WHERE ...");
+    textarea.move_cursor(tui_textarea::CursorMove::End);
+    textarea
+}
+
+/// The position in the history after moving up to older or down to newer statements,
+/// none below the latest statement, where a new statement is typed.
+fn navigate_history(history_len: usize, position: Option<usize>, is_up: bool) -> Option<usize> {
+    match (position, is_up) {
+        (None, true) => history_len.checked_sub(1),
+        (Some(position), true) => Some(position.saturating_sub(1)),
+        (Some(position), false) if position + 1 < history_len => Some(position + 1),
+        _ => None,
+    }
+}
+
+/// The table of the statement, e.g. `orders` of `SELECT * FROM "orders"."by-status"`.
+fn get_table_name(statement: &str) -> Option<String> {
+    static TABLE_NAME_REGEX: OnceLock<Regex> = OnceLock::new();
+
+    TABLE_NAME_REGEX
+        .get_or_init(|| {
+            Regex::new(r#"(?i)\b(?:from|into|update)\s+"?([A-Za-z0-9_.-]+?)"?(?:\.|\s|$)"#)
+                .expect("invalid table name regex")
+        })
+        .captures(statement)
+        .map(|captures| captures[1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigate_history() {
+        assert_eq!(navigate_history(0, None, true), None);
+        assert_eq!(navigate_history(3, None, true), Some(2));
+        assert_eq!(navigate_history(3, Some(0), true), Some(0));
+        assert_eq!(navigate_history(3, Some(1), false), Some(2));
+        assert_eq!(navigate_history(3, Some(2), false), None);
+    }
+
+    #[test]
+    fn test_find_table_name_of_statement() {
+        assert_eq!(
+            get_table_name(r#"select * from "orders"."by-status" where status = 'OPEN'"#),
+            Some("orders".into())
+        );
+        assert_eq!(
+            get_table_name("UPDATE users SET age = 3 WHERE id = 1"),
+            Some("users".into())
+        );
+        assert_eq!(get_table_name("EXISTS(...)"), None);
+    }
+}
@@ -0,0 +1,231 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use human_bytes::human_bytes;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, DynamoDbAction},
+        appstate::{AppState, ComponentType},
+        dynamodb_state::{DynamoDbKey, DynamoDbTable},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+use super::{dynamodb_items::DynamoDbItemComponent, dynamodb_partiql::DynamoDbPartiQlComponent};
+
+/// The tables of the selected region with their size and keys.
+pub struct DynamoDbTableComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    table_list: ListComponent<'a>,
+    first_time_render: bool,
+    item_component: DynamoDbItemComponent<'a>,
+    is_item_view: bool,
+    partiql_component: DynamoDbPartiQlComponent<'a>,
+    is_partiql_view: bool,
+}
+
+impl<'a> Component for DynamoDbTableComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        DynamoDbTableComponent {
+            item_component: DynamoDbItemComponent::new(action_tx.clone()),
+            is_item_view: false,
+            partiql_component: DynamoDbPartiQlComponent::new(action_tx.clone()),
+            is_partiql_view: false,
+            action_tx,
+            table_list: ListComponent::new(),
+            first_time_render: true,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_item_view {
+            return self.item_component.set_focus();
+        }
+
+        if self.is_partiql_view {
+            return self.partiql_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![TUI_CONFIG.breadcrumbs.dynamodb_tables.into()],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.partiql.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        (self.is_item_view && self.item_component.is_capturing_input())
+            || (self.is_partiql_view && self.partiql_component.is_capturing_input())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_item_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.item_component.is_capturing_input()
+            {
+                self.is_item_view = false;
+                return self.set_focus();
+            }
+            return self.item_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_partiql_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.partiql_component.is_capturing_input()
+            {
+                self.is_partiql_view = false;
+                return self.set_focus();
+            }
+            return self.partiql_component.handle_key_event(key, app_state);
+        }
+
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('p') => {
+                self.is_partiql_view = true;
+                self.partiql_component.show()?;
+            }
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                if let Some(table) = app_state
+                    .dynamodb_state
+                    .tables
+                    .get(self.table_list.get_selected_index())
+                {
+                    self.is_item_view = true;
+                    self.item_component.show(table.clone())?;
+                }
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.table_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.table_list.has_list_elements() =>
+            {
+                self.table_list.move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.first_time_render {
+            let _ = self.update();
+            self.first_time_render = false;
+        }
+
+        if self.is_item_view {
+            self.item_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_partiql_view {
+            self.partiql_component.render(frame, area, app_state);
+            return;
+        }
+
+        let tables = &app_state.dynamodb_state.tables;
+        self.table_list
+            .create_list_items(tables.iter().map(create_list_item));
+        self.table_list.set_selected_index(
+            self.table_list
+                .get_selected_index()
+                .min(tables.len().saturating_sub(1)),
+        );
+
+        let block = Block::default()
+            .title(format!(
+                " DynamoDB Tables ({}) [{}] ",
+                tables.len(),
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(
+                Style::new().fg(if app_state.focus_component == self.component_type() {
+                    TUI_CONFIG.theme.border_highlight
+                } else {
+                    TUI_CONFIG.theme.border
+                }),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        if tables.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo DynamoDB tables available").block(block),
+                area,
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.table_list.get_selected_index()));
+            let list = List::new(self.table_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(block);
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+    }
+}
+
+impl<'a> DynamoDbTableComponent<'a> {
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::DynamoDb {
+            action: DynamoDbAction::GetTables,
+        })?;
+
+        Ok(())
+    }
+}
+
+fn format_key(key: &DynamoDbKey) -> String {
+    format!("{} ({})", key.attribute_name, key.attribute_type)
+}
+
+fn create_list_item(table: &DynamoDbTable) -> String {
+    let mut keys = format_key(&table.partition_key);
+    if let Some(sort_key) = &table.sort_key {
+        keys = format!("{}, {}", keys, format_key(sort_key));
+    }
+    if !table.indexes.is_empty() {
+        keys = format!("{} +{} indexes", keys, table.indexes.len());
+    }
+
+    format!(
+        "{:<40}  {:<9}  {:>10} items  {:>10}  {:<15}  {}",
+        table.table_name,
+        table.status.as_deref().unwrap_or_default(),
+        table.item_count.unwrap_or_default(),
+        human_bytes(table.size_bytes.unwrap_or_default() as f64),
+        table.billing_mode.as_deref().unwrap_or_default(),
+        keys
+    )
+}
@@ -0,0 +1,6 @@
+use super::format_date_time;
+
+pub mod ec2_instances;
+pub mod ec2_security;
+pub mod ec2_storage;
+pub mod ec2_vpcs;
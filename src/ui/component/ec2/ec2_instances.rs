@@ -0,0 +1,478 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, Ec2Action},
+        appstate::{AppState, ComponentType},
+        ec2_state::{Ec2Instance, Ec2InstanceCommand},
+    },
+    ui::{
+        component::{
+            base::{json_highlight::highlight_json, list_component::ListComponent},
+            Component,
+        },
+        tui_config::TUI_CONFIG,
+    },
+};
+
+use super::{
+    ec2_security::Ec2SecurityComponent, ec2_storage::Ec2StorageComponent,
+    ec2_vpcs::Ec2VpcComponent, format_date_time,
+};
+
+/// The instances of the selected region with the full record of the selected one below.
+pub struct Ec2InstanceComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    instance_list: ListComponent<'a>,
+    first_time_render: bool,
+    details_offset: usize,
+    details_height: usize,
+    pending_command: Option<(Ec2InstanceCommand, String)>,
+    terminate_textarea: TextArea<'a>,
+    security_component: Ec2SecurityComponent<'a>,
+    is_security_view: bool,
+    vpc_component: Ec2VpcComponent,
+    is_vpc_view: bool,
+    storage_component: Ec2StorageComponent<'a>,
+    is_storage_view: bool,
+}
+
+impl<'a> Component for Ec2InstanceComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        Ec2InstanceComponent {
+            security_component: Ec2SecurityComponent::new(action_tx.clone()),
+            is_security_view: false,
+            vpc_component: Ec2VpcComponent::new(action_tx.clone()),
+            is_vpc_view: false,
+            storage_component: Ec2StorageComponent::new(action_tx.clone()),
+            is_storage_view: false,
+            action_tx,
+            instance_list: ListComponent::new(),
+            first_time_render: true,
+            details_offset: 0,
+            details_height: 0,
+            pending_command: None,
+            terminate_textarea: TextArea::default(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_security_view {
+            return self.security_component.set_focus();
+        }
+
+        if self.is_vpc_view {
+            return self.vpc_component.set_focus();
+        }
+
+        if self.is_storage_view {
+            return self.storage_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![TUI_CONFIG.breadcrumbs.ec2_instances.into()],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.start.into(),
+                    TUI_CONFIG.menu.stop.into(),
+                    TUI_CONFIG.menu.reboot.into(),
+                    TUI_CONFIG.menu.terminate.into(),
+                    TUI_CONFIG.menu.security.into(),
+                    TUI_CONFIG.menu.vpcs.into(),
+                    TUI_CONFIG.menu.storage.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.pending_command.is_some()
+            || (self.is_security_view && self.security_component.is_capturing_input())
+            || (self.is_storage_view && self.storage_component.is_capturing_input())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some((command, instance_id)) = self.pending_command.clone() {
+            match key.code {
+                KeyCode::Esc => self.pending_command = None,
+                KeyCode::Enter => {
+                    // terminating cannot be undone, the instance id has to be typed
+                    let is_confirmed = command != Ec2InstanceCommand::Terminate
+                        || self.terminate_textarea.lines().join("").trim() == instance_id;
+                    if is_confirmed {
+                        self.action_tx.send(Action::Ec2 {
+                            action: Ec2Action::ChangeInstanceState {
+                                instance_id,
+                                command,
+                            },
+                        })?;
+                        self.pending_command = None;
+                    }
+                }
+                KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+                _ if command == Ec2InstanceCommand::Terminate => {
+                    self.terminate_textarea.input(key);
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        if self.is_security_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.is_security_view = false;
+                return self.set_focus();
+            }
+            return self.security_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_vpc_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.is_vpc_view = false;
+                return self.set_focus();
+            }
+            return self.vpc_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_storage_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && !self.storage_component.is_capturing_input()
+            {
+                self.is_storage_view = false;
+                return self.set_focus();
+            }
+            return self.storage_component.handle_key_event(key, app_state);
+        }
+
+        let details_len = self.get_details_lines(app_state).len();
+
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('s') => self.confirm_command(Ec2InstanceCommand::Start, app_state),
+            KeyCode::Char('o') => self.confirm_command(Ec2InstanceCommand::Stop, app_state),
+            KeyCode::Char('b') => self.confirm_command(Ec2InstanceCommand::Reboot, app_state),
+            KeyCode::Char('t') => self.confirm_command(Ec2InstanceCommand::Terminate, app_state),
+            KeyCode::Char('g') => {
+                self.is_security_view = true;
+                self.security_component.show()?;
+            }
+            KeyCode::Char('v') => {
+                self.is_vpc_view = true;
+                self.vpc_component.show()?;
+            }
+            KeyCode::Char('e') => {
+                self.is_storage_view = true;
+                self.storage_component.show()?;
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.instance_list.move_up();
+                self.details_offset = 0;
+            }
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.instance_list.has_list_elements() =>
+            {
+                self.instance_list.move_down();
+                self.details_offset = 0;
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.details_offset = self.details_offset.saturating_sub(self.details_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.details_offset = (self.details_offset + self.details_height)
+                    .min(details_len.saturating_sub(self.details_height))
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.first_time_render {
+            let _ = self.update();
+            self.first_time_render = false;
+        }
+
+        if self.is_security_view {
+            self.security_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_vpc_view {
+            self.vpc_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_storage_view {
+            self.storage_component.render(frame, area, app_state);
+            return;
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let instances = &app_state.ec2_state.instances;
+        self.instance_list
+            .create_list_items(instances.iter().map(create_list_item));
+        self.instance_list.set_selected_index(
+            self.instance_list
+                .get_selected_index()
+                .min(instances.len().saturating_sub(1)),
+        );
+
+        if instances.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo EC2 instances available").block(self.create_block(
+                    app_state,
+                    format!(
+                        " EC2 Instances [{}] ",
+                        TUI_CONFIG.key_config.focus_aws_service.key_string
+                    ),
+                )),
+                layout[0],
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.instance_list.get_selected_index()));
+            let list = List::new(self.instance_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(self.create_block(
+                    app_state,
+                    format!(
+                        " EC2 Instances ({}) [{}] ",
+                        instances.len(),
+                        TUI_CONFIG.key_config.focus_aws_service.key_string
+                    ),
+                ));
+            frame.render_stateful_widget(list, layout[0], &mut list_state);
+        }
+
+        self.details_height = layout[1].height.saturating_sub(2) as usize;
+        frame.render_widget(
+            Paragraph::new(self.get_details_lines(app_state))
+                .scroll((self.details_offset as u16, 0))
+                .block(self.create_block(
+                    app_state,
+                    format!(
+                        " {} ",
+                        self.get_selected_instance(app_state)
+                            .map(|instance| instance.instance_id.as_str())
+                            .unwrap_or("Details")
+                    ),
+                )),
+            layout[1],
+        );
+
+        if let Some((command, instance_id)) = &self.pending_command {
+            if *command == Ec2InstanceCommand::Terminate {
+                let popup_area = self.centered_rect(50, 10, app_state.area);
+                frame.render_widget(Clear, popup_area);
+                frame.render_widget(self.terminate_textarea.widget(), popup_area);
+            } else {
+                let popup_area = self.centered_rect(50, 10, app_state.area);
+                frame.render_widget(Clear, popup_area);
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "{} {}? <enter> confirm, <esc> cancel",
+                        command_name(*command),
+                        instance_id
+                    ))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true })
+                    .block(Block::default().borders(Borders::ALL)),
+                    popup_area,
+                );
+            }
+        }
+    }
+}
+
+impl<'a> Ec2InstanceComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Ec2 {
+            action: Ec2Action::GetInstances,
+        })?;
+
+        Ok(())
+    }
+
+    fn get_selected_instance<'b>(&self, app_state: &'b AppState) -> Option<&'b Ec2Instance> {
+        app_state
+            .ec2_state
+            .instances
+            .get(self.instance_list.get_selected_index())
+    }
+
+    fn get_details_lines(&self, app_state: &AppState) -> Vec<Line<'static>> {
+        match self.get_selected_instance(app_state) {
+            Some(instance) => highlight_json(&instance.details.to_string()).unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    fn confirm_command(&mut self, command: Ec2InstanceCommand, app_state: &AppState) {
+        let Some(instance) = self
+            .get_selected_instance(app_state)
+            .filter(|instance| is_command_allowed(command, instance.state.as_deref()))
+        else {
+            return;
+        };
+
+        if command == Ec2InstanceCommand::Terminate {
+            self.terminate_textarea = TextArea::default();
+            self.terminate_textarea
+                .set_cursor_line_style(Style::default());
+            self.terminate_textarea
+                .set_placeholder_text("Type the instance id to confirm");
+            self.terminate_textarea.set_block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Terminate {}", instance.instance_id))
+                    .title_alignment(Alignment::Center)
+                    .border_style(Style::new().fg(TUI_CONFIG.theme.error_message_text)),
+            );
+        }
+        self.pending_command = Some((command, instance.instance_id.clone()));
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'_> {
+        Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn command_name(command: Ec2InstanceCommand) -> &'static str {
+    match command {
+        Ec2InstanceCommand::Start => "Start",
+        Ec2InstanceCommand::Stop => "Stop",
+        Ec2InstanceCommand::Reboot => "Reboot",
+        Ec2InstanceCommand::Terminate => "Terminate",
+    }
+}
+
+/// Only commands EC2 accepts in the current instance state are offered.
+fn is_command_allowed(command: Ec2InstanceCommand, state: Option<&str>) -> bool {
+    match (command, state) {
+        (Ec2InstanceCommand::Start, Some("stopped")) => true,
+        (Ec2InstanceCommand::Stop, Some("pending" | "running")) => true,
+        (Ec2InstanceCommand::Reboot, Some("running")) => true,
+        (Ec2InstanceCommand::Terminate, Some(state)) => {
+            !matches!(state, "shutting-down" | "terminated")
+        }
+        _ => false,
+    }
+}
+
+fn create_list_item(instance: &Ec2Instance) -> String {
+    format!(
+        "{:<19}  {:<24}  {:<13}  {:<12}  {:<15}  {:<15}  {:<12}  {}",
+        instance.instance_id,
+        instance.name.as_deref().unwrap_or_default(),
+        instance.state.as_deref().unwrap_or_default(),
+        instance.instance_type.as_deref().unwrap_or_default(),
+        instance.private_ip_address.as_deref().unwrap_or_default(),
+        instance.public_ip_address.as_deref().unwrap_or_default(),
+        instance.availability_zone.as_deref().unwrap_or_default(),
+        format_date_time(instance.launch_time, "")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allow_commands_for_instance_state_only() {
+        assert!(is_command_allowed(
+            Ec2InstanceCommand::Start,
+            Some("stopped")
+        ));
+        assert!(!is_command_allowed(
+            Ec2InstanceCommand::Start,
+            Some("running")
+        ));
+        assert!(is_command_allowed(
+            Ec2InstanceCommand::Stop,
+            Some("pending")
+        ));
+        assert!(!is_command_allowed(
+            Ec2InstanceCommand::Reboot,
+            Some("stopped")
+        ));
+        assert!(is_command_allowed(
+            Ec2InstanceCommand::Terminate,
+            Some("stopped")
+        ));
+        assert!(!is_command_allowed(
+            Ec2InstanceCommand::Terminate,
+            Some("terminated")
+        ));
+        assert!(!is_command_allowed(Ec2InstanceCommand::Terminate, None));
+    }
+
+    #[test]
+    fn test_list_instance_columns() {
+        let instance = Ec2Instance {
+            instance_id: "i-0abc".into(),
+            name: Some("web".into()),
+            state: Some("running".into()),
+            instance_type: Some("t3.micro".into()),
+            private_ip_address: Some("10.0.0.12".into()),
+            availability_zone: Some("eu-west-1a".into()),
+            launch_time: Some(1_700_000_000),
+            ..Ec2Instance::default()
+        };
+
+        assert_eq!(
+            create_list_item(&instance),
+            format!(
+                "{:<19}  {:<24}  running        t3.micro      10.0.0.12        {:<15}  eu-west-1a    2023-11-14T22:13:20Z",
+                "i-0abc", "web", ""
+            )
+        );
+    }
+}
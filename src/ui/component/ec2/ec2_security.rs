@@ -0,0 +1,454 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    repository::ec2_network::{format_port_range, is_risky_acl_entry, is_risky_rule},
+    state::{
+        action_handlers::actions::{Action, Ec2Action},
+        appstate::{AppState, ComponentType},
+        ec2_state::{Ec2NetworkAcl, Ec2NetworkInterface, Ec2SecurityGroup, Ec2SecurityGroupRule},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+const LIST_WIDTH: u16 = 48;
+
+/// Security groups with the interfaces that use them, or the network ACLs of the subnets.
+/// Rules that open SSH or RDP to the world are highlighted.
+pub struct Ec2SecurityComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    group_list: ListComponent<'a>,
+    acl_list: ListComponent<'a>,
+    is_acl_view: bool,
+    rules_offset: usize,
+    rules_height: usize,
+}
+
+impl<'a> Component for Ec2SecurityComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        Ec2SecurityComponent {
+            action_tx,
+            group_list: ListComponent::new(),
+            acl_list: ListComponent::new(),
+            is_acl_view: false,
+            rules_offset: 0,
+            rules_height: 0,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.ec2_instances.into(),
+                if self.is_acl_view {
+                    TUI_CONFIG.breadcrumbs.ec2_network_acls.into()
+                } else {
+                    TUI_CONFIG.breadcrumbs.ec2_security_groups.into()
+                },
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.switch_view.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        let rules_len = self.get_rule_lines(app_state).len();
+        let list = if self.is_acl_view {
+            &mut self.acl_list
+        } else {
+            &mut self.group_list
+        };
+
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('a') => {
+                self.is_acl_view = !self.is_acl_view;
+                self.rules_offset = 0;
+                self.set_focus()?;
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                list.move_up();
+                self.rules_offset = 0;
+            }
+            val if TUI_CONFIG.list_config.selection_down == val && list.has_list_elements() => {
+                list.move_down();
+                self.rules_offset = 0;
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.rules_offset = self.rules_offset.saturating_sub(self.rules_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.rules_offset = (self.rules_offset + self.rules_height)
+                    .min(rules_len.saturating_sub(self.rules_height))
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Length(LIST_WIDTH), Constraint::Min(10)])
+            .split(area);
+        let ec2_state = &app_state.ec2_state;
+
+        let (list, title) = if self.is_acl_view {
+            self.acl_list
+                .create_list_items(ec2_state.network_acls.iter().map(create_acl_item));
+            self.acl_list.set_selected_index(
+                self.acl_list
+                    .get_selected_index()
+                    .min(ec2_state.network_acls.len().saturating_sub(1)),
+            );
+            (
+                &self.acl_list,
+                format!(" Network ACLs ({}) ", ec2_state.network_acls.len()),
+            )
+        } else {
+            self.group_list
+                .create_list_items(ec2_state.security_groups.iter().map(create_group_item));
+            self.group_list.set_selected_index(
+                self.group_list
+                    .get_selected_index()
+                    .min(ec2_state.security_groups.len().saturating_sub(1)),
+            );
+            (
+                &self.group_list,
+                format!(" Security Groups ({}) ", ec2_state.security_groups.len()),
+            )
+        };
+
+        let mut list_state = ListState::default().with_selected(Some(list.get_selected_index()));
+        let tui_list = List::new(
+            list.create_tui_list()
+                .into_iter()
+                .map(|item| item.style(Style::default()))
+                .collect::<Vec<ListItem>>(),
+        )
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+        .block(self.create_block(
+            app_state,
+            format!(
+                "{}[{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ),
+        ));
+        frame.render_stateful_widget(tui_list, layout[0], &mut list_state);
+
+        self.rules_height = layout[1].height.saturating_sub(2) as usize;
+        frame.render_widget(
+            Paragraph::new(self.get_rule_lines(app_state))
+                .scroll((self.rules_offset as u16, 0))
+                .block(self.create_block(app_state, " Rules ".into())),
+            layout[1],
+        );
+    }
+}
+
+impl<'a> Ec2SecurityComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self) -> anyhow::Result<()> {
+        self.is_acl_view = false;
+        self.rules_offset = 0;
+        self.group_list.set_selected_index(0);
+        self.acl_list.set_selected_index(0);
+        self.update()?;
+        self.set_focus()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Ec2 {
+            action: Ec2Action::GetNetworkSecurity,
+        })?;
+
+        Ok(())
+    }
+
+    fn get_rule_lines(&self, app_state: &AppState) -> Vec<Line<'static>> {
+        let ec2_state = &app_state.ec2_state;
+        if self.is_acl_view {
+            ec2_state
+                .network_acls
+                .get(self.acl_list.get_selected_index())
+                .map(create_acl_lines)
+                .unwrap_or_default()
+        } else {
+            ec2_state
+                .security_groups
+                .get(self.group_list.get_selected_index())
+                .map(|security_group| {
+                    create_group_lines(security_group, &ec2_state.network_interfaces)
+                })
+                .unwrap_or_default()
+        }
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'_> {
+        Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+/// Groups with risky inbound rules are marked with `!`.
+fn create_group_item(security_group: &Ec2SecurityGroup) -> String {
+    format!(
+        "{} {:<20}  {}",
+        if security_group.inbound_rules.iter().any(is_risky_rule) {
+            "!"
+        } else {
+            " "
+        },
+        security_group.group_id,
+        security_group.group_name
+    )
+}
+
+fn create_acl_item(network_acl: &Ec2NetworkAcl) -> String {
+    format!(
+        "{} {:<21}  {}{}",
+        if network_acl.entries.iter().any(is_risky_acl_entry) {
+            "!"
+        } else {
+            " "
+        },
+        network_acl.network_acl_id,
+        network_acl.vpc_id.as_deref().unwrap_or_default(),
+        if network_acl.is_default {
+            " (default)"
+        } else {
+            ""
+        }
+    )
+}
+
+fn create_heading(text: String) -> Line<'static> {
+    Line::styled(text, Style::default().add_modifier(Modifier::BOLD))
+}
+
+fn create_rule_line(rule: &Ec2SecurityGroupRule, is_inbound: bool) -> Line<'static> {
+    let text = format!(
+        "  {:<8}  {:<11}  {:<24}  {}",
+        rule.protocol,
+        format_port_range(rule.from_port, rule.to_port),
+        rule.peer,
+        rule.description.as_deref().unwrap_or_default()
+    );
+
+    if is_inbound && is_risky_rule(rule) {
+        Line::styled(
+            text,
+            Style::default().fg(TUI_CONFIG.theme.error_message_text),
+        )
+    } else {
+        Line::from(text)
+    }
+}
+
+fn create_group_lines(
+    security_group: &Ec2SecurityGroup,
+    network_interfaces: &[Ec2NetworkInterface],
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        create_heading(format!(
+            "{} {}",
+            security_group.group_name,
+            security_group.vpc_id.as_deref().unwrap_or_default()
+        )),
+        Line::from(security_group.description.clone().unwrap_or_default()),
+        Line::from(""),
+        create_heading(format!(
+            "Inbound rules ({})",
+            security_group.inbound_rules.len()
+        )),
+    ];
+    lines.extend(
+        security_group
+            .inbound_rules
+            .iter()
+            .map(|rule| create_rule_line(rule, true)),
+    );
+    lines.push(Line::from(""));
+    lines.push(create_heading(format!(
+        "Outbound rules ({})",
+        security_group.outbound_rules.len()
+    )));
+    lines.extend(
+        security_group
+            .outbound_rules
+            .iter()
+            .map(|rule| create_rule_line(rule, false)),
+    );
+
+    let users = network_interfaces
+        .iter()
+        .filter(|network_interface| {
+            network_interface
+                .group_ids
+                .contains(&security_group.group_id)
+        })
+        .collect::<Vec<&Ec2NetworkInterface>>();
+    lines.push(Line::from(""));
+    lines.push(create_heading(format!("Used by ({})", users.len())));
+    lines.extend(users.iter().map(|network_interface| {
+        Line::from(format!(
+            "  {:<21}  {:<19}  {:<15}  {}",
+            network_interface.network_interface_id,
+            network_interface.instance_id.as_deref().unwrap_or_default(),
+            network_interface
+                .private_ip_address
+                .as_deref()
+                .unwrap_or_default(),
+            network_interface
+                .description
+                .as_deref()
+                .or(network_interface.interface_type.as_deref())
+                .unwrap_or_default()
+        ))
+    }));
+
+    lines
+}
+
+fn create_acl_lines(network_acl: &Ec2NetworkAcl) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        create_heading(format!(
+            "{} {}",
+            network_acl.network_acl_id,
+            network_acl.vpc_id.as_deref().unwrap_or_default()
+        )),
+        Line::from(format!("Subnets: {}", network_acl.subnet_ids.join(", "))),
+    ];
+
+    for (title, is_egress) in [("Inbound entries", false), ("Outbound entries", true)] {
+        lines.push(Line::from(""));
+        lines.push(create_heading(title.into()));
+        lines.extend(
+            network_acl
+                .entries
+                .iter()
+                .filter(|entry| entry.is_egress == is_egress)
+                .map(|entry| {
+                    let text = format!(
+                        "  {:>5}  {:<5}  {:<8}  {:<11}  {}",
+                        // the last entry denies everything that no other entry matched
+                        if entry.rule_number == 32767 {
+                            "*".into()
+                        } else {
+                            entry.rule_number.to_string()
+                        },
+                        if entry.is_allow { "allow" } else { "deny" },
+                        entry.protocol,
+                        format_port_range(entry.from_port, entry.to_port),
+                        entry.cidr_block
+                    );
+                    if is_risky_acl_entry(entry) {
+                        Line::styled(
+                            text,
+                            Style::default().fg(TUI_CONFIG.theme.error_message_text),
+                        )
+                    } else {
+                        Line::from(text)
+                    }
+                }),
+        );
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_rules_and_interfaces_of_group() {
+        let security_group = Ec2SecurityGroup {
+            group_id: "sg-1".into(),
+            group_name: "bastion".into(),
+            inbound_rules: vec![Ec2SecurityGroupRule {
+                protocol: "tcp".into(),
+                from_port: Some(22),
+                to_port: Some(22),
+                peer: "0.0.0.0/0".into(),
+                description: None,
+            }],
+            ..Ec2SecurityGroup::default()
+        };
+        let network_interfaces = vec![
+            Ec2NetworkInterface {
+                network_interface_id: "eni-1".into(),
+                instance_id: Some("i-1".into()),
+                group_ids: vec!["sg-1".into()],
+                ..Ec2NetworkInterface::default()
+            },
+            Ec2NetworkInterface {
+                network_interface_id: "eni-2".into(),
+                group_ids: vec!["sg-2".into()],
+                ..Ec2NetworkInterface::default()
+            },
+        ];
+
+        let lines = create_group_lines(&security_group, &network_interfaces);
+        let risky_line = &lines[4];
+        assert_eq!(
+            risky_line.spans[0].content,
+            format!("  {:<8}  {:<11}  {:<24}  ", "tcp", "22", "0.0.0.0/0")
+        );
+        assert_eq!(
+            risky_line.spans[0].style.fg,
+            Some(TUI_CONFIG.theme.error_message_text)
+        );
+        assert_eq!(lines[8], create_heading("Used by (1)".into()));
+        assert!(lines[9].spans[0].content.starts_with("  eni-1"));
+        assert_eq!(lines.len(), 10);
+        assert!(create_group_item(&security_group).starts_with('!'));
+    }
+}
@@ -0,0 +1,462 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    repository::ec2_storage::{is_orphaned_snapshot, is_unattached_volume},
+    state::{
+        action_handlers::actions::{Action, Ec2Action},
+        appstate::{AppState, ComponentType},
+        ec2_state::{Ec2Image, Ec2Snapshot, Ec2State, Ec2Volume},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+use super::format_date_time;
+
+#[derive(Clone, Copy, PartialEq)]
+enum StorageView {
+    Volumes,
+    Snapshots,
+    Images,
+}
+
+#[derive(Clone)]
+enum StorageCommand {
+    CreateSnapshot { volume_id: String },
+    DeregisterImage { image_id: String },
+    DeleteSnapshot { snapshot_id: String },
+}
+
+/// EBS volumes, the snapshots and the AMIs owned by the account. Unattached volumes
+/// and orphaned snapshots are highlighted as they are the usual cost to clean up.
+pub struct Ec2StorageComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    view: StorageView,
+    volume_list: ListComponent<'a>,
+    snapshot_list: ListComponent<'a>,
+    image_list: ListComponent<'a>,
+    pending_command: Option<StorageCommand>,
+    confirm_textarea: TextArea<'a>,
+}
+
+impl<'a> Component for Ec2StorageComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        Ec2StorageComponent {
+            action_tx,
+            view: StorageView::Volumes,
+            volume_list: ListComponent::new(),
+            snapshot_list: ListComponent::new(),
+            image_list: ListComponent::new(),
+            pending_command: None,
+            confirm_textarea: TextArea::default(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.ec2_instances.into(),
+                match self.view {
+                    StorageView::Volumes => TUI_CONFIG.breadcrumbs.ec2_volumes.into(),
+                    StorageView::Snapshots => TUI_CONFIG.breadcrumbs.ec2_snapshots.into(),
+                    StorageView::Images => TUI_CONFIG.breadcrumbs.ec2_images.into(),
+                },
+            ],
+        })?;
+
+        let mut menu_items = vec![
+            TUI_CONFIG.menu.up.into(),
+            TUI_CONFIG.menu.down.into(),
+            TUI_CONFIG.menu.switch_storage_view.into(),
+        ];
+        menu_items.push(match self.view {
+            StorageView::Volumes => TUI_CONFIG.menu.create_snapshot.into(),
+            StorageView::Snapshots => TUI_CONFIG.menu.delete.into(),
+            StorageView::Images => TUI_CONFIG.menu.deregister.into(),
+        });
+        menu_items.push(TUI_CONFIG.menu.refresh.into());
+        menu_items.push(TUI_CONFIG.menu.back.into());
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [vec![], vec![], menu_items],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.pending_command.is_some()
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(command) = self.pending_command.clone() {
+            return self.handle_confirm_key_event(key, command);
+        }
+
+        let ec2_state = &app_state.ec2_state;
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('a') => {
+                self.view = match self.view {
+                    StorageView::Volumes => StorageView::Snapshots,
+                    StorageView::Snapshots => StorageView::Images,
+                    StorageView::Images => StorageView::Volumes,
+                };
+                self.set_focus()?;
+            }
+            KeyCode::Char('s') if self.view == StorageView::Volumes => {
+                if let Some(volume) = ec2_state.volumes.get(self.volume_list.get_selected_index()) {
+                    self.pending_command = Some(StorageCommand::CreateSnapshot {
+                        volume_id: volume.volume_id.clone(),
+                    });
+                }
+            }
+            KeyCode::Char('d') if self.view == StorageView::Snapshots => {
+                if let Some(snapshot) = ec2_state
+                    .snapshots
+                    .get(self.snapshot_list.get_selected_index())
+                    .filter(|snapshot| {
+                        is_orphaned_snapshot(snapshot, &ec2_state.volumes, &ec2_state.images)
+                    })
+                {
+                    self.confirm_with_id("Delete snapshot", &snapshot.snapshot_id);
+                    self.pending_command = Some(StorageCommand::DeleteSnapshot {
+                        snapshot_id: snapshot.snapshot_id.clone(),
+                    });
+                }
+            }
+            KeyCode::Char('d') if self.view == StorageView::Images => {
+                if let Some(image) = ec2_state.images.get(self.image_list.get_selected_index()) {
+                    self.confirm_with_id("Deregister AMI", &image.image_id);
+                    self.pending_command = Some(StorageCommand::DeregisterImage {
+                        image_id: image.image_id.clone(),
+                    });
+                }
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.get_list().move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.get_list().has_list_elements() =>
+            {
+                self.get_list().move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let ec2_state = &app_state.ec2_state;
+        let (items, title) = create_view_items(self.view, ec2_state);
+
+        let list = self.get_list();
+        list.create_list_items(items.iter().map(|(item, _)| item));
+        list.set_selected_index(list.get_selected_index().min(items.len().saturating_sub(1)));
+
+        let block = Block::default()
+            .title(format!(
+                " {} [{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        let list = self.get_list();
+        let mut list_state = ListState::default().with_selected(Some(list.get_selected_index()));
+        let tui_list = List::new(
+            list.create_tui_list()
+                .into_iter()
+                .zip(items.iter())
+                .map(|(item, (_, is_highlighted))| {
+                    if *is_highlighted {
+                        item.style(Style::default().fg(TUI_CONFIG.theme.error_message_text))
+                    } else {
+                        item
+                    }
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+        .block(block);
+        frame.render_stateful_widget(tui_list, area, &mut list_state);
+
+        match &self.pending_command {
+            Some(StorageCommand::CreateSnapshot { volume_id }) => {
+                let popup_area = self.centered_rect(50, 10, app_state.area);
+                frame.render_widget(Clear, popup_area);
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "Create snapshot of {}? <enter> confirm, <esc> cancel",
+                        volume_id
+                    ))
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true })
+                    .block(Block::default().borders(Borders::ALL)),
+                    popup_area,
+                );
+            }
+            Some(_) => {
+                let popup_area = self.centered_rect(50, 10, app_state.area);
+                frame.render_widget(Clear, popup_area);
+                frame.render_widget(self.confirm_textarea.widget(), popup_area);
+            }
+            None => {}
+        }
+    }
+}
+
+impl<'a> Ec2StorageComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self) -> anyhow::Result<()> {
+        self.view = StorageView::Volumes;
+        self.volume_list.set_selected_index(0);
+        self.snapshot_list.set_selected_index(0);
+        self.image_list.set_selected_index(0);
+        self.update()?;
+        self.set_focus()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Ec2 {
+            action: Ec2Action::GetStorage,
+        })?;
+
+        Ok(())
+    }
+
+    fn get_list(&mut self) -> &mut ListComponent<'a> {
+        match self.view {
+            StorageView::Volumes => &mut self.volume_list,
+            StorageView::Snapshots => &mut self.snapshot_list,
+            StorageView::Images => &mut self.image_list,
+        }
+    }
+
+    fn handle_confirm_key_event(
+        &mut self,
+        key: KeyEvent,
+        command: StorageCommand,
+    ) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.pending_command = None,
+            KeyCode::Enter => {
+                let typed_id = self.confirm_textarea.lines().join("");
+                // deleting cannot be undone, the id has to be typed
+                let action = match command {
+                    StorageCommand::CreateSnapshot { volume_id } => {
+                        Some(Ec2Action::CreateSnapshot { volume_id })
+                    }
+                    StorageCommand::DeregisterImage { image_id } => (typed_id.trim() == image_id)
+                        .then_some(Ec2Action::DeregisterImage { image_id }),
+                    StorageCommand::DeleteSnapshot { snapshot_id } => (typed_id.trim()
+                        == snapshot_id)
+                        .then_some(Ec2Action::DeleteSnapshot { snapshot_id }),
+                };
+
+                if let Some(action) = action {
+                    self.action_tx.send(Action::Ec2 { action })?;
+                    self.pending_command = None;
+                }
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ if !matches!(command, StorageCommand::CreateSnapshot { .. }) => {
+                self.confirm_textarea.input(key);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn confirm_with_id(&mut self, title: &str, id: &str) {
+        self.confirm_textarea = TextArea::default();
+        self.confirm_textarea
+            .set_cursor_line_style(Style::default());
+        self.confirm_textarea
+            .set_placeholder_text(format!("Type {} to confirm", id));
+        self.confirm_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{} {}", title, id))
+                .title_alignment(Alignment::Center)
+                .border_style(Style::new().fg(TUI_CONFIG.theme.error_message_text)),
+        );
+    }
+}
+
+/// The list items of the view with their highlighting and the list title.
+fn create_view_items(view: StorageView, ec2_state: &Ec2State) -> (Vec<(String, bool)>, String) {
+    match view {
+        StorageView::Volumes => {
+            let unattached_volumes = ec2_state
+                .volumes
+                .iter()
+                .filter(|volume| is_unattached_volume(volume))
+                .collect::<Vec<&Ec2Volume>>();
+            (
+                ec2_state
+                    .volumes
+                    .iter()
+                    .map(|volume| (create_volume_item(volume), is_unattached_volume(volume)))
+                    .collect(),
+                format!(
+                    "EBS Volumes ({}, {} unattached with {} GiB)",
+                    ec2_state.volumes.len(),
+                    unattached_volumes.len(),
+                    unattached_volumes
+                        .iter()
+                        .filter_map(|volume| volume.size)
+                        .sum::<i32>()
+                ),
+            )
+        }
+        StorageView::Snapshots => {
+            let items = ec2_state
+                .snapshots
+                .iter()
+                .map(|snapshot| {
+                    (
+                        create_snapshot_item(snapshot),
+                        is_orphaned_snapshot(snapshot, &ec2_state.volumes, &ec2_state.images),
+                    )
+                })
+                .collect::<Vec<(String, bool)>>();
+            let title = format!(
+                "Snapshots ({}, {} orphaned)",
+                items.len(),
+                items.iter().filter(|(_, is_orphaned)| *is_orphaned).count()
+            );
+            (items, title)
+        }
+        StorageView::Images => (
+            ec2_state
+                .images
+                .iter()
+                .map(|image| (create_image_item(image), false))
+                .collect(),
+            format!("AMIs ({})", ec2_state.images.len()),
+        ),
+    }
+}
+
+fn format_size(size: Option<i32>) -> String {
+    size.map(|size| format!("{} GiB", size)).unwrap_or_default()
+}
+
+fn create_volume_item(volume: &Ec2Volume) -> String {
+    format!(
+        "{:<21}  {:>8}  {:<8}  {:<9}  {:<19}  {:<9}  {:<12}  {}",
+        volume.volume_id,
+        format_size(volume.size),
+        volume.volume_type.as_deref().unwrap_or_default(),
+        volume.state.as_deref().unwrap_or_default(),
+        volume.instance_id.as_deref().unwrap_or("-"),
+        volume.device.as_deref().unwrap_or_default(),
+        volume.availability_zone.as_deref().unwrap_or_default(),
+        volume.name.as_deref().unwrap_or_default()
+    )
+}
+
+fn create_snapshot_item(snapshot: &Ec2Snapshot) -> String {
+    format!(
+        "{:<22}  {:>8}  {:<9}  {:<21}  {:<20}  {}",
+        snapshot.snapshot_id,
+        format_size(snapshot.volume_size),
+        snapshot.state.as_deref().unwrap_or_default(),
+        snapshot.volume_id.as_deref().unwrap_or_default(),
+        format_date_time(snapshot.start_time, ""),
+        snapshot.description.as_deref().unwrap_or_default()
+    )
+}
+
+fn create_image_item(image: &Ec2Image) -> String {
+    format!(
+        "{:<21}  {:<9}  {:<24}  {}",
+        image.image_id,
+        image.state.as_deref().unwrap_or_default(),
+        image.creation_date.as_deref().unwrap_or_default(),
+        image.name.as_deref().unwrap_or_default()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_ec2_state() -> Ec2State {
+        Ec2State {
+            volumes: vec![
+                Ec2Volume {
+                    volume_id: "vol-1".into(),
+                    size: Some(8),
+                    state: Some("in-use".into()),
+                    ..Ec2Volume::default()
+                },
+                Ec2Volume {
+                    volume_id: "vol-2".into(),
+                    size: Some(100),
+                    state: Some("available".into()),
+                    ..Ec2Volume::default()
+                },
+            ],
+            snapshots: vec![
+                Ec2Snapshot {
+                    snapshot_id: "snap-1".into(),
+                    volume_id: Some("vol-1".into()),
+                    ..Ec2Snapshot::default()
+                },
+                Ec2Snapshot {
+                    snapshot_id: "snap-2".into(),
+                    volume_id: Some("vol-deleted".into()),
+                    ..Ec2Snapshot::default()
+                },
+            ],
+            ..Ec2State::default()
+        }
+    }
+
+    #[test]
+    fn test_highlight_unattached_volumes() {
+        let (items, title) = create_view_items(StorageView::Volumes, &create_ec2_state());
+        assert_eq!(title, "EBS Volumes (2, 1 unattached with 100 GiB)");
+        assert_eq!(
+            items.iter().map(|(_, flag)| *flag).collect::<Vec<bool>>(),
+            vec![false, true]
+        );
+    }
+
+    #[test]
+    fn test_highlight_orphaned_snapshots() {
+        let (items, title) = create_view_items(StorageView::Snapshots, &create_ec2_state());
+        assert_eq!(title, "Snapshots (2, 1 orphaned)");
+        assert!(!items[0].1 && items[1].1);
+        assert!(items[1].0.starts_with("snap-2"));
+    }
+}
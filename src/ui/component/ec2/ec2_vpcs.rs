@@ -0,0 +1,577 @@
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, Ec2Action},
+        appstate::{AppState, ComponentType},
+        ec2_state::{Ec2Route, Ec2RouteTable, Ec2Subnet, Ec2Vpc},
+    },
+    ui::{component::Component, tui_config::TUI_CONFIG},
+};
+
+/// The VPCs of the region as a tree of their subnets, route tables, gateways, endpoints
+/// and peering connections. Subnets list the routes of the table they use.
+pub struct Ec2VpcComponent {
+    action_tx: UnboundedSender<Action>,
+    /// Nodes whose expansion differs from their default.
+    toggled_nodes: HashSet<String>,
+    selected_index: usize,
+    list_height: usize,
+}
+
+struct TreeNode {
+    id: String,
+    text: String,
+    is_expanded_by_default: bool,
+    is_warning: bool,
+    children: Vec<TreeNode>,
+}
+
+#[derive(Debug, PartialEq)]
+struct VisibleNode {
+    id: String,
+    text: String,
+    depth: usize,
+    has_children: bool,
+    is_expanded: bool,
+    is_warning: bool,
+    parent_index: Option<usize>,
+}
+
+impl Component for Ec2VpcComponent {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        Ec2VpcComponent {
+            action_tx,
+            toggled_nodes: HashSet::new(),
+            selected_index: 0,
+            list_height: 0,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.ec2_instances.into(),
+                TUI_CONFIG.breadcrumbs.ec2_vpcs.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.toggle_node.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        let nodes = flatten_tree(
+            &create_vpc_tree(&app_state.ec2_state.vpcs),
+            &self.toggled_nodes,
+        );
+        let last_index = nodes.len().saturating_sub(1);
+        let selected_node = nodes.get(self.selected_index);
+
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.selected_index = self.selected_index.saturating_sub(1)
+            }
+            val if TUI_CONFIG.list_config.selection_down == val => {
+                self.selected_index = (self.selected_index + 1).min(last_index)
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.selected_index = self.selected_index.saturating_sub(self.list_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.selected_index = (self.selected_index + self.list_height).min(last_index)
+            }
+            val if TUI_CONFIG.list_config.jump_to_start == val => self.selected_index = 0,
+            val if TUI_CONFIG.list_config.jump_to_end == val => self.selected_index = last_index,
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                if let Some(node) = selected_node.filter(|node| node.has_children) {
+                    self.toggle_node(&node.id);
+                }
+            }
+            val if TUI_CONFIG.list_config.scroll_right == val => {
+                if let Some(node) =
+                    selected_node.filter(|node| node.has_children && !node.is_expanded)
+                {
+                    self.toggle_node(&node.id);
+                }
+            }
+            // collapses the selected node or else moves to its parent
+            val if TUI_CONFIG.list_config.scroll_left == val => match selected_node {
+                Some(node) if node.is_expanded => self.toggle_node(&node.id),
+                Some(node) => {
+                    self.selected_index = node.parent_index.unwrap_or(self.selected_index)
+                }
+                None => {}
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let vpcs = &app_state.ec2_state.vpcs;
+        let block = Block::default()
+            .title(format!(
+                " VPCs ({}) [{}] ",
+                vpcs.len(),
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        if vpcs.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No VPCs found")
+                    .alignment(Alignment::Center)
+                    .block(block),
+                area,
+            );
+            return;
+        }
+
+        let nodes = flatten_tree(&create_vpc_tree(vpcs), &self.toggled_nodes);
+        self.selected_index = self.selected_index.min(nodes.len().saturating_sub(1));
+        self.list_height = area.height.saturating_sub(2) as usize;
+
+        let list = List::new(
+            nodes
+                .iter()
+                .map(|node| {
+                    ListItem::new(format_node(node)).style(if node.is_warning {
+                        Style::default().fg(TUI_CONFIG.theme.error_message_text)
+                    } else if node.depth == 0 {
+                        Style::default().add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    })
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+        .block(block);
+
+        let mut list_state = ListState::default().with_selected(Some(self.selected_index));
+        frame.render_stateful_widget(list, area, &mut list_state);
+    }
+}
+
+impl Ec2VpcComponent {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self) -> anyhow::Result<()> {
+        self.selected_index = 0;
+        self.update()?;
+        self.set_focus()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Ec2 {
+            action: Ec2Action::GetVpcs,
+        })?;
+
+        Ok(())
+    }
+
+    fn toggle_node(&mut self, id: &str) {
+        if !self.toggled_nodes.remove(id) {
+            self.toggled_nodes.insert(id.into());
+        }
+    }
+}
+
+fn format_node(node: &VisibleNode) -> String {
+    format!(
+        "{}{}{}",
+        "  ".repeat(node.depth),
+        match (node.has_children, node.is_expanded) {
+            (false, _) => "  ",
+            (true, true) => "▾ ",
+            (true, false) => "▸ ",
+        },
+        node.text
+    )
+}
+
+/// The nodes of expanded parents in display order.
+fn flatten_tree(nodes: &[TreeNode], toggled_nodes: &HashSet<String>) -> Vec<VisibleNode> {
+    let mut visible_nodes = vec![];
+    add_visible_nodes(nodes, toggled_nodes, 0, None, &mut visible_nodes);
+    visible_nodes
+}
+
+fn add_visible_nodes(
+    nodes: &[TreeNode],
+    toggled_nodes: &HashSet<String>,
+    depth: usize,
+    parent_index: Option<usize>,
+    visible_nodes: &mut Vec<VisibleNode>,
+) {
+    for node in nodes {
+        let is_expanded = node.is_expanded_by_default != toggled_nodes.contains(&node.id);
+        visible_nodes.push(VisibleNode {
+            id: node.id.clone(),
+            text: node.text.clone(),
+            depth,
+            has_children: !node.children.is_empty(),
+            is_expanded,
+            is_warning: node.is_warning,
+            parent_index,
+        });
+
+        if is_expanded {
+            let index = visible_nodes.len() - 1;
+            add_visible_nodes(
+                &node.children,
+                toggled_nodes,
+                depth + 1,
+                Some(index),
+                visible_nodes,
+            );
+        }
+    }
+}
+
+fn create_node(id: String, text: String, children: Vec<TreeNode>) -> TreeNode {
+    TreeNode {
+        id,
+        text,
+        is_expanded_by_default: false,
+        is_warning: false,
+        children,
+    }
+}
+
+/// Empty categories are left out.
+fn create_category(vpc_id: &str, title: &str, children: Vec<TreeNode>) -> Option<TreeNode> {
+    (!children.is_empty()).then(|| TreeNode {
+        is_expanded_by_default: true,
+        ..create_node(
+            format!("{}/{}", vpc_id, title),
+            format!("{} ({})", title, children.len()),
+            children,
+        )
+    })
+}
+
+fn create_vpc_tree(vpcs: &[Ec2Vpc]) -> Vec<TreeNode> {
+    vpcs.iter()
+        .map(|vpc| {
+            let children = [
+                create_category(
+                    &vpc.vpc_id,
+                    "Subnets",
+                    vpc.subnets
+                        .iter()
+                        .map(|subnet| create_subnet_node(subnet, &vpc.route_tables))
+                        .collect(),
+                ),
+                create_category(
+                    &vpc.vpc_id,
+                    "Route tables",
+                    vpc.route_tables
+                        .iter()
+                        .map(create_route_table_node)
+                        .collect(),
+                ),
+                create_category(
+                    &vpc.vpc_id,
+                    "Internet gateways",
+                    vpc.internet_gateways
+                        .iter()
+                        .map(|internet_gateway| {
+                            create_node(
+                                internet_gateway.internet_gateway_id.clone(),
+                                join_columns(&[
+                                    Some(&internet_gateway.internet_gateway_id),
+                                    internet_gateway.state.as_ref(),
+                                    internet_gateway.name.as_ref(),
+                                ]),
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                ),
+                create_category(
+                    &vpc.vpc_id,
+                    "NAT gateways",
+                    vpc.nat_gateways
+                        .iter()
+                        .map(|nat_gateway| {
+                            create_node(
+                                nat_gateway.nat_gateway_id.clone(),
+                                join_columns(&[
+                                    Some(&nat_gateway.nat_gateway_id),
+                                    nat_gateway.subnet_id.as_ref(),
+                                    nat_gateway.public_ip.as_ref(),
+                                    nat_gateway.connectivity_type.as_ref(),
+                                    nat_gateway.state.as_ref(),
+                                ]),
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                ),
+                create_category(
+                    &vpc.vpc_id,
+                    "Endpoints",
+                    vpc.endpoints
+                        .iter()
+                        .map(|endpoint| {
+                            create_node(
+                                endpoint.vpc_endpoint_id.clone(),
+                                join_columns(&[
+                                    Some(&endpoint.vpc_endpoint_id),
+                                    endpoint.service_name.as_ref(),
+                                    endpoint.endpoint_type.as_ref(),
+                                    endpoint.state.as_ref(),
+                                ]),
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                ),
+                create_category(
+                    &vpc.vpc_id,
+                    "Peering connections",
+                    vpc.peering_connections
+                        .iter()
+                        .map(|peering_connection| TreeNode {
+                            is_warning: peering_connection.status.as_deref() != Some("active"),
+                            ..create_node(
+                                format!(
+                                    "{}/{}",
+                                    vpc.vpc_id, peering_connection.peering_connection_id
+                                ),
+                                format!(
+                                    "{}  {} <-> {}  {}",
+                                    peering_connection.peering_connection_id,
+                                    peering_connection.requester,
+                                    peering_connection.accepter,
+                                    peering_connection.status.as_deref().unwrap_or_default()
+                                ),
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                ),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            TreeNode {
+                is_expanded_by_default: true,
+                ..create_node(
+                    vpc.vpc_id.clone(),
+                    join_columns(&[
+                        Some(&vpc.vpc_id),
+                        Some(&vpc.cidr_blocks.join(", ")),
+                        vpc.name.as_ref(),
+                        vpc.is_default.then(|| "default".to_string()).as_ref(),
+                    ]),
+                    children,
+                )
+            }
+        })
+        .collect()
+}
+
+/// The subnet shows the routes of its table, which decide what it can reach.
+fn create_subnet_node(subnet: &Ec2Subnet, route_tables: &[Ec2RouteTable]) -> TreeNode {
+    let routes = subnet
+        .route_table_id
+        .as_ref()
+        .and_then(|route_table_id| {
+            route_tables
+                .iter()
+                .find(|route_table| &route_table.route_table_id == route_table_id)
+        })
+        .map(|route_table| route_table.routes.as_slice())
+        .unwrap_or_default();
+
+    create_node(
+        subnet.subnet_id.clone(),
+        join_columns(&[
+            Some(&subnet.subnet_id),
+            subnet.cidr_block.as_ref(),
+            subnet.availability_zone.as_ref(),
+            Some(
+                &(if subnet.is_public {
+                    "public"
+                } else {
+                    "private"
+                })
+                .to_string(),
+            ),
+            subnet.route_table_id.as_ref(),
+            subnet
+                .available_ip_address_count
+                .map(|count| format!("{} free", count))
+                .as_ref(),
+            subnet.name.as_ref(),
+        ]),
+        routes
+            .iter()
+            .map(|route| create_route_node(&subnet.subnet_id, route))
+            .collect(),
+    )
+}
+
+fn create_route_table_node(route_table: &Ec2RouteTable) -> TreeNode {
+    let subnets = (!route_table.subnet_ids.is_empty()).then(|| {
+        create_node(
+            format!("{}/subnets", route_table.route_table_id),
+            format!("subnets: {}", route_table.subnet_ids.join(", ")),
+            vec![],
+        )
+    });
+
+    create_node(
+        route_table.route_table_id.clone(),
+        join_columns(&[
+            Some(&route_table.route_table_id),
+            route_table.is_main.then(|| "main".to_string()).as_ref(),
+            route_table.name.as_ref(),
+        ]),
+        subnets
+            .into_iter()
+            .chain(
+                route_table
+                    .routes
+                    .iter()
+                    .map(|route| create_route_node(&route_table.route_table_id, route)),
+            )
+            .collect(),
+    )
+}
+
+/// Routes to deleted targets are blackholes and drop the traffic.
+fn create_route_node(parent_id: &str, route: &Ec2Route) -> TreeNode {
+    let is_blackhole = route.state.as_deref() == Some("blackhole");
+
+    TreeNode {
+        is_warning: is_blackhole,
+        ..create_node(
+            format!("{}/{}", parent_id, route.destination),
+            format!(
+                "{:<20} -> {}{}",
+                route.destination,
+                route.target,
+                if is_blackhole { "  blackhole" } else { "" }
+            ),
+            vec![],
+        )
+    }
+}
+
+fn join_columns(columns: &[Option<&String>]) -> String {
+    columns
+        .iter()
+        .flatten()
+        .filter(|column| !column.is_empty())
+        .map(|column| column.as_str())
+        .collect::<Vec<&str>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_vpcs_and_categories_and_toggle_nodes() {
+        let vpcs = vec![Ec2Vpc {
+            vpc_id: "vpc-1".into(),
+            cidr_blocks: vec!["10.0.0.0/16".into()],
+            subnets: vec![Ec2Subnet {
+                subnet_id: "subnet-1".into(),
+                cidr_block: Some("10.0.1.0/24".into()),
+                route_table_id: Some("rtb-1".into()),
+                is_public: true,
+                ..Ec2Subnet::default()
+            }],
+            route_tables: vec![Ec2RouteTable {
+                route_table_id: "rtb-1".into(),
+                is_main: true,
+                routes: vec![Ec2Route {
+                    destination: "0.0.0.0/0".into(),
+                    target: "igw-1".into(),
+                    state: Some("blackhole".into()),
+                }],
+                ..Ec2RouteTable::default()
+            }],
+            ..Ec2Vpc::default()
+        }];
+        let tree = create_vpc_tree(&vpcs);
+
+        let texts = |toggled_nodes: &HashSet<String>| {
+            flatten_tree(&tree, toggled_nodes)
+                .iter()
+                .map(format_node)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(
+            texts(&HashSet::new()),
+            vec![
+                "▾ vpc-1  10.0.0.0/16",
+                "  ▾ Subnets (1)",
+                "    ▸ subnet-1  10.0.1.0/24  public  rtb-1",
+                "  ▾ Route tables (1)",
+                "    ▸ rtb-1  main",
+            ]
+        );
+
+        let toggled_nodes = HashSet::from(["subnet-1".to_string(), "vpc-1/Route tables".into()]);
+        let nodes = flatten_tree(&tree, &toggled_nodes);
+        assert_eq!(
+            format_node(&nodes[3]),
+            format!("        {:<20} -> igw-1  blackhole", "0.0.0.0/0")
+        );
+        assert!(nodes[3].is_warning);
+        assert_eq!(nodes[3].parent_index, Some(2));
+        assert_eq!(format_node(&nodes[4]), "  ▸ Route tables (1)");
+        assert_eq!(nodes.len(), 5);
+    }
+}
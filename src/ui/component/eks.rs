@@ -0,0 +1,4 @@
+use super::format_date_time;
+
+pub mod eks_cluster_resources;
+pub mod eks_clusters;
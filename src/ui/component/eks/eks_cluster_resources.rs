@@ -0,0 +1,333 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, EksAction},
+        appstate::{AppState, ComponentType},
+        eks_state::{EksAddon, EksFargateProfile, EksNodeGroup, EksState},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum ResourceView {
+    NodeGroups,
+    FargateProfiles,
+    Addons,
+}
+
+/// The node groups, Fargate profiles and add-ons of a cluster. Add-ons with a newer
+/// version for the Kubernetes version of the cluster are highlighted.
+pub struct EksClusterResourceComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    cluster_name: String,
+    view: ResourceView,
+    node_group_list: ListComponent<'a>,
+    fargate_profile_list: ListComponent<'a>,
+    addon_list: ListComponent<'a>,
+}
+
+impl<'a> Component for EksClusterResourceComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        EksClusterResourceComponent {
+            action_tx,
+            cluster_name: String::default(),
+            view: ResourceView::NodeGroups,
+            node_group_list: ListComponent::new(),
+            fargate_profile_list: ListComponent::new(),
+            addon_list: ListComponent::new(),
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.eks_clusters.into(),
+                match self.view {
+                    ResourceView::NodeGroups => TUI_CONFIG.breadcrumbs.eks_node_groups.into(),
+                    ResourceView::FargateProfiles => {
+                        TUI_CONFIG.breadcrumbs.eks_fargate_profiles.into()
+                    }
+                    ResourceView::Addons => TUI_CONFIG.breadcrumbs.eks_addons.into(),
+                },
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.switch_cluster_view.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, _app_state: &AppState) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('a') => {
+                self.view = match self.view {
+                    ResourceView::NodeGroups => ResourceView::FargateProfiles,
+                    ResourceView::FargateProfiles => ResourceView::Addons,
+                    ResourceView::Addons => ResourceView::NodeGroups,
+                };
+                self.set_focus()?;
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.get_list().move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.get_list().has_list_elements() =>
+            {
+                self.get_list().move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let eks_state = &app_state.eks_state;
+        // the resources of the previously selected cluster are not shown while loading
+        let (items, title) = match &eks_state.selected_cluster {
+            Some(cluster) if cluster.name == self.cluster_name => {
+                create_view_items(self.view, eks_state)
+            }
+            _ => (vec![], String::default()),
+        };
+
+        let list = self.get_list();
+        list.create_list_items(items.iter().map(|(item, _)| item));
+        list.set_selected_index(list.get_selected_index().min(items.len().saturating_sub(1)));
+
+        let block = Block::default()
+            .title(format!(
+                " {} {} [{}] ",
+                self.cluster_name, title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded);
+
+        let list = self.get_list();
+        let mut list_state = ListState::default().with_selected(Some(list.get_selected_index()));
+        let tui_list = List::new(
+            list.create_tui_list()
+                .into_iter()
+                .zip(items.iter())
+                .map(|(item, (_, is_highlighted))| {
+                    if *is_highlighted {
+                        item.style(Style::default().fg(TUI_CONFIG.theme.error_message_text))
+                    } else {
+                        item
+                    }
+                })
+                .collect::<Vec<ListItem>>(),
+        )
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+        .block(block);
+        frame.render_stateful_widget(tui_list, area, &mut list_state);
+    }
+}
+
+impl<'a> EksClusterResourceComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self, cluster_name: String) -> anyhow::Result<()> {
+        self.cluster_name = cluster_name;
+        self.view = ResourceView::NodeGroups;
+        self.node_group_list.set_selected_index(0);
+        self.fargate_profile_list.set_selected_index(0);
+        self.addon_list.set_selected_index(0);
+        self.update()?;
+        self.set_focus()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Eks {
+            action: EksAction::SelectCluster {
+                cluster_name: self.cluster_name.clone(),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    fn get_list(&mut self) -> &mut ListComponent<'a> {
+        match self.view {
+            ResourceView::NodeGroups => &mut self.node_group_list,
+            ResourceView::FargateProfiles => &mut self.fargate_profile_list,
+            ResourceView::Addons => &mut self.addon_list,
+        }
+    }
+}
+
+/// The list items of the view with their highlighting and the list title.
+fn create_view_items(view: ResourceView, eks_state: &EksState) -> (Vec<(String, bool)>, String) {
+    match view {
+        ResourceView::NodeGroups => (
+            eks_state
+                .node_groups
+                .iter()
+                .map(|node_group| (create_node_group_item(node_group), false))
+                .collect(),
+            format!("Node Groups ({})", eks_state.node_groups.len()),
+        ),
+        ResourceView::FargateProfiles => (
+            eks_state
+                .fargate_profiles
+                .iter()
+                .map(|fargate_profile| (create_fargate_profile_item(fargate_profile), false))
+                .collect(),
+            format!("Fargate Profiles ({})", eks_state.fargate_profiles.len()),
+        ),
+        ResourceView::Addons => {
+            let items = eks_state
+                .addons
+                .iter()
+                .map(|addon| {
+                    (
+                        create_addon_item(addon),
+                        !addon.available_updates.is_empty(),
+                    )
+                })
+                .collect::<Vec<(String, bool)>>();
+            let title = format!(
+                "Add-ons ({}, {} with updates)",
+                items.len(),
+                items.iter().filter(|(_, has_updates)| *has_updates).count()
+            );
+            (items, title)
+        }
+    }
+}
+
+fn format_size(size: Option<i32>) -> String {
+    size.map(|size| size.to_string()).unwrap_or("-".into())
+}
+
+fn create_node_group_item(node_group: &EksNodeGroup) -> String {
+    format!(
+        "{:<32}  {:<14}  {:<5}  {:<20}  {:<9}  {:<20}  {:>3}/{}/{}  {}",
+        node_group.name,
+        node_group.status.as_deref().unwrap_or_default(),
+        node_group.version.as_deref().unwrap_or_default(),
+        node_group.release_version.as_deref().unwrap_or_default(),
+        node_group.capacity_type.as_deref().unwrap_or_default(),
+        node_group.ami_type.as_deref().unwrap_or_default(),
+        format_size(node_group.min_size),
+        format_size(node_group.desired_size),
+        format_size(node_group.max_size),
+        node_group.instance_types.join(", ")
+    )
+}
+
+fn create_fargate_profile_item(fargate_profile: &EksFargateProfile) -> String {
+    format!(
+        "{:<32}  {:<14}  {:>2} subnets  {:<40}  {}",
+        fargate_profile.name,
+        fargate_profile.status.as_deref().unwrap_or_default(),
+        fargate_profile.subnets.len(),
+        fargate_profile.selectors.join("; "),
+        fargate_profile
+            .pod_execution_role_arn
+            .as_deref()
+            .unwrap_or_default()
+    )
+}
+
+fn create_addon_item(addon: &EksAddon) -> String {
+    format!(
+        "{:<32}  {:<22}  {:<16}  {}",
+        addon.name,
+        addon.version.as_deref().unwrap_or_default(),
+        addon.status.as_deref().unwrap_or_default(),
+        match addon.available_updates.as_slice() {
+            [] => "up to date".to_string(),
+            updates => format!("updates: {}", updates.join(", ")),
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_addons_with_updates() {
+        let eks_state = EksState {
+            addons: vec![
+                EksAddon {
+                    name: "coredns".into(),
+                    version: Some("v1.11.1-eksbuild.4".into()),
+                    status: Some("ACTIVE".into()),
+                    available_updates: vec![],
+                },
+                EksAddon {
+                    name: "vpc-cni".into(),
+                    version: Some("v1.16.0-eksbuild.1".into()),
+                    status: Some("ACTIVE".into()),
+                    available_updates: vec![
+                        "v1.18.1-eksbuild.1".into(),
+                        "v1.17.1-eksbuild.1".into(),
+                    ],
+                },
+            ],
+            ..EksState::default()
+        };
+
+        let (items, title) = create_view_items(ResourceView::Addons, &eks_state);
+        assert_eq!(title, "Add-ons (2, 1 with updates)");
+        assert!(!items[0].1 && items[1].1);
+        assert!(items[0].0.ends_with("up to date"));
+        assert!(items[1]
+            .0
+            .ends_with("updates: v1.18.1-eksbuild.1, v1.17.1-eksbuild.1"));
+    }
+
+    #[test]
+    fn test_list_node_group_scaling() {
+        let node_group = EksNodeGroup {
+            name: "workers".into(),
+            min_size: Some(1),
+            desired_size: Some(3),
+            max_size: Some(5),
+            instance_types: vec!["m5.large".into(), "m5a.large".into()],
+            ..EksNodeGroup::default()
+        };
+
+        assert!(create_node_group_item(&node_group).ends_with("  1/3/5  m5.large, m5a.large"));
+    }
+}
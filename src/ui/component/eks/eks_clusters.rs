@@ -0,0 +1,324 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    config::app_config::get_default_kubeconfig_path,
+    state::{
+        action_handlers::actions::{Action, EksAction},
+        appstate::{AppState, ComponentType},
+        eks_state::EksCluster,
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+use super::{eks_cluster_resources::EksClusterResourceComponent, format_date_time};
+
+/// The clusters of the selected region with the details of the selected one below.
+pub struct EksClusterComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    cluster_list: ListComponent<'a>,
+    first_time_render: bool,
+    /// The cluster whose kubeconfig entry waits for confirmation.
+    pending_kubeconfig: Option<String>,
+    resource_component: EksClusterResourceComponent<'a>,
+    is_resource_view: bool,
+}
+
+impl<'a> Component for EksClusterComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        EksClusterComponent {
+            resource_component: EksClusterResourceComponent::new(action_tx.clone()),
+            is_resource_view: false,
+            action_tx,
+            cluster_list: ListComponent::new(),
+            first_time_render: true,
+            pending_kubeconfig: None,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_resource_view {
+            return self.resource_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![TUI_CONFIG.breadcrumbs.eks_clusters.into()],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.kubeconfig.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.pending_kubeconfig.is_some()
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(cluster_name) = self.pending_kubeconfig.clone() {
+            match key.code {
+                KeyCode::Esc => self.pending_kubeconfig = None,
+                KeyCode::Enter => {
+                    self.action_tx.send(Action::Eks {
+                        action: EksAction::WriteKubeconfig { cluster_name },
+                    })?;
+                    self.pending_kubeconfig = None;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        if self.is_resource_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.is_resource_view = false;
+                return self.set_focus();
+            }
+            return self.resource_component.handle_key_event(key, app_state);
+        }
+
+        match key.code {
+            KeyCode::Char('u') => self.update()?,
+            KeyCode::Char('k') => {
+                if let Some(cluster) = self.get_selected_cluster(app_state) {
+                    self.pending_kubeconfig = Some(cluster.name.clone());
+                }
+            }
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                if let Some(cluster) = self.get_selected_cluster(app_state) {
+                    self.is_resource_view = true;
+                    self.resource_component.show(cluster.name.clone())?;
+                }
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.cluster_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.cluster_list.has_list_elements() =>
+            {
+                self.cluster_list.move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.first_time_render {
+            let _ = self.update();
+            self.first_time_render = false;
+        }
+
+        if self.is_resource_view {
+            self.resource_component.render(frame, area, app_state);
+            return;
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let clusters = &app_state.eks_state.clusters;
+        self.cluster_list
+            .create_list_items(clusters.iter().map(create_list_item));
+        self.cluster_list.set_selected_index(
+            self.cluster_list
+                .get_selected_index()
+                .min(clusters.len().saturating_sub(1)),
+        );
+
+        if clusters.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo EKS clusters available").block(self.create_block(
+                    app_state,
+                    format!(
+                        " EKS Clusters [{}] ",
+                        TUI_CONFIG.key_config.focus_aws_service.key_string
+                    ),
+                )),
+                layout[0],
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.cluster_list.get_selected_index()));
+            let list = List::new(self.cluster_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(self.create_block(
+                    app_state,
+                    format!(
+                        " EKS Clusters ({}) [{}] ",
+                        clusters.len(),
+                        TUI_CONFIG.key_config.focus_aws_service.key_string
+                    ),
+                ));
+            frame.render_stateful_widget(list, layout[0], &mut list_state);
+        }
+
+        let selected_cluster = self.get_selected_cluster(app_state);
+        frame.render_widget(
+            Paragraph::new(
+                selected_cluster
+                    .map(create_details_lines)
+                    .unwrap_or_default(),
+            )
+            .block(self.create_block(
+                app_state,
+                format!(
+                    " {} ",
+                    selected_cluster
+                        .map(|cluster| cluster.name.as_str())
+                        .unwrap_or("Details")
+                ),
+            )),
+            layout[1],
+        );
+
+        if let Some(cluster_name) = &self.pending_kubeconfig {
+            let popup_area = self.centered_rect(50, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(
+                Paragraph::new(format!(
+                    "Write kubeconfig entry of {} to {}? <enter> confirm, <esc> cancel",
+                    cluster_name,
+                    get_default_kubeconfig_path().display()
+                ))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL)),
+                popup_area,
+            );
+        }
+    }
+}
+
+impl<'a> EksClusterComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::Eks {
+            action: EksAction::GetClusters,
+        })?;
+
+        Ok(())
+    }
+
+    fn get_selected_cluster<'b>(&self, app_state: &'b AppState) -> Option<&'b EksCluster> {
+        app_state
+            .eks_state
+            .clusters
+            .get(self.cluster_list.get_selected_index())
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'_> {
+        Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn create_list_item(cluster: &EksCluster) -> String {
+    format!(
+        "{:<32}  {:<5}  {:<9}  {:<10}  {:>3} node groups  {:>3} fargate profiles",
+        cluster.name,
+        cluster.version.as_deref().unwrap_or_default(),
+        cluster.status.as_deref().unwrap_or_default(),
+        cluster.platform_version.as_deref().unwrap_or_default(),
+        cluster.node_group_names.len(),
+        cluster.fargate_profile_names.len()
+    )
+}
+
+fn create_details_lines(cluster: &EksCluster) -> Vec<Line<'static>> {
+    let join_names = |names: &[String]| {
+        if names.is_empty() {
+            "-".to_string()
+        } else {
+            names.join(", ")
+        }
+    };
+
+    [
+        ("ARN", cluster.arn.clone().unwrap_or_default()),
+        ("Version", cluster.version.clone().unwrap_or_default()),
+        ("Status", cluster.status.clone().unwrap_or_default()),
+        (
+            "Platform version",
+            cluster.platform_version.clone().unwrap_or_default(),
+        ),
+        ("Endpoint", cluster.endpoint.clone().unwrap_or_default()),
+        ("Created", format_date_time(cluster.created_at, "")),
+        ("Node groups", join_names(&cluster.node_group_names)),
+        (
+            "Fargate profiles",
+            join_names(&cluster.fargate_profile_names),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, value)| Line::from(format!("{:<18}{}", name, value)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_cluster_columns() {
+        let cluster = EksCluster {
+            name: "prod".into(),
+            version: Some("1.29".into()),
+            status: Some("ACTIVE".into()),
+            platform_version: Some("eks.7".into()),
+            node_group_names: vec!["system".into(), "workers".into()],
+            ..EksCluster::default()
+        };
+
+        assert_eq!(
+            create_list_item(&cluster),
+            format!(
+                "{:<32}  1.29   ACTIVE     eks.7         2 node groups    0 fargate profiles",
+                "prod"
+            )
+        );
+    }
+}
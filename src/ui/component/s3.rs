@@ -0,0 +1,30 @@
+use ratatui::{
+    prelude::Alignment,
+    style::Style,
+    widgets::{Block, Borders},
+};
+use tui_textarea::{CursorMove, TextArea};
+
+use super::format_date_time;
+
+pub mod s3_bucket_config;
+pub mod s3_buckets;
+pub mod s3_object_preview;
+pub mod s3_object_versions;
+pub mod s3_objects;
+pub mod s3_presign;
+pub mod s3_sync;
+
+fn create_path_textarea<'a>(title: &'a str, path: String) -> TextArea<'a> {
+    let mut path_textarea = TextArea::new(vec![path]);
+    path_textarea.set_cursor_line_style(Style::default());
+    path_textarea.set_placeholder_text("Enter local path");
+    path_textarea.move_cursor(CursorMove::End);
+    path_textarea.set_block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_alignment(Alignment::Center),
+    );
+    path_textarea
+}
@@ -0,0 +1,262 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::S3BucketConfigSection,
+    },
+    ui::{
+        component::{base::json_highlight::highlight_json, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+const SECTION_LIST_WIDTH: u16 = 26;
+
+/// The configuration of a bucket, each section is fetched when it is selected first.
+pub struct S3BucketConfigComponent {
+    action_tx: UnboundedSender<Action>,
+    bucket_name: String,
+    selected_index: usize,
+    vertical_offset: usize,
+    page_height: usize,
+}
+
+impl Component for S3BucketConfigComponent {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3BucketConfigComponent {
+            action_tx,
+            bucket_name: String::default(),
+            selected_index: 0,
+            vertical_offset: 0,
+            page_height: 0,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![
+                TUI_CONFIG.breadcrumbs.s3_buckets.into(),
+                format!(" <{}> ", self.bucket_name),
+                TUI_CONFIG.breadcrumbs.s3_bucket_config.into(),
+            ],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        let max_offset = self
+            .get_section_lines(app_state)
+            .len()
+            .saturating_sub(self.page_height);
+
+        match key.code {
+            KeyCode::Char('u') => self.fetch_section()?,
+            val if TUI_CONFIG.list_config.selection_up == val && self.selected_index > 0 => {
+                self.select_section(self.selected_index - 1, app_state)?
+            }
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.selected_index + 1 < S3BucketConfigSection::ALL.len() =>
+            {
+                self.select_section(self.selected_index + 1, app_state)?
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.vertical_offset = self.vertical_offset.saturating_sub(self.page_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.vertical_offset = (self.vertical_offset + self.page_height).min(max_offset)
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Length(SECTION_LIST_WIDTH),
+                Constraint::Min(10),
+            ])
+            .split(area);
+
+        let mut list_state = ListState::default().with_selected(Some(self.selected_index));
+        let list = List::new(
+            S3BucketConfigSection::ALL
+                .iter()
+                .map(|section| ListItem::new(section_title(*section)))
+                .collect::<Vec<ListItem>>(),
+        )
+        .highlight_style(TUI_CONFIG.list_config.selected_style)
+        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+        .block(self.create_block(app_state, format!(" {} ", self.bucket_name)));
+        frame.render_stateful_widget(list, layout[0], &mut list_state);
+
+        self.page_height = layout[1].height.saturating_sub(2) as usize;
+        frame.render_widget(
+            Paragraph::new(self.get_section_lines(app_state))
+                .scroll((self.vertical_offset as u16, 0))
+                .block(self.create_block(
+                    app_state,
+                    format!(" {} ", section_title(self.get_section())),
+                )),
+            layout[1],
+        );
+    }
+}
+
+impl S3BucketConfigComponent {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self, bucket_name: String, app_state: &AppState) -> anyhow::Result<()> {
+        self.bucket_name = bucket_name;
+        self.select_section(0, app_state)?;
+        self.set_focus()
+    }
+
+    fn get_section(&self) -> S3BucketConfigSection {
+        S3BucketConfigSection::ALL[self.selected_index]
+    }
+
+    fn get_section_lines(&self, app_state: &AppState) -> Vec<Line<'static>> {
+        let bucket_config = &app_state.s3_state.bucket_config;
+        if bucket_config.bucket_name != self.bucket_name {
+            return create_section_lines(None);
+        }
+        create_section_lines(bucket_config.sections.get(&self.get_section()))
+    }
+
+    fn select_section(&mut self, index: usize, app_state: &AppState) -> anyhow::Result<()> {
+        self.selected_index = index;
+        self.vertical_offset = 0;
+
+        let bucket_config = &app_state.s3_state.bucket_config;
+        if bucket_config.bucket_name != self.bucket_name
+            || !bucket_config.sections.contains_key(&self.get_section())
+        {
+            self.fetch_section()?;
+        }
+
+        Ok(())
+    }
+
+    fn fetch_section(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::S3 {
+            action: S3Action::GetBucketConfig {
+                bucket_name: self.bucket_name.clone(),
+                section: self.get_section(),
+            },
+        })?;
+
+        Ok(())
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'_> {
+        Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn section_title(section: S3BucketConfigSection) -> &'static str {
+    match section {
+        S3BucketConfigSection::Versioning => "Versioning",
+        S3BucketConfigSection::Encryption => "Default encryption",
+        S3BucketConfigSection::PublicAccessBlock => "Public access block",
+        S3BucketConfigSection::Policy => "Bucket policy",
+        S3BucketConfigSection::Cors => "CORS",
+        S3BucketConfigSection::Lifecycle => "Lifecycle rules",
+        S3BucketConfigSection::Tags => "Tags",
+        S3BucketConfigSection::Logging => "Server access logging",
+        S3BucketConfigSection::Replication => "Replication",
+    }
+}
+
+fn create_section_lines(value: Option<&Value>) -> Vec<Line<'static>> {
+    match value {
+        None => vec![Line::from(""), Line::from("Loading configuration ...")],
+        Some(Value::Null) => vec![Line::from(""), Line::from("Not configured")],
+        Some(value) => highlight_json(&value.to_string()).unwrap_or_else(|| {
+            serde_json::to_string_pretty(value)
+                .unwrap_or_default()
+                .lines()
+                .map(|line| Line::from(line.to_string()))
+                .collect()
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_show_pretty_configuration_or_missing_state() {
+        assert_eq!(
+            create_section_lines(Some(&Value::Null))[1],
+            Line::from("Not configured")
+        );
+
+        let lines = create_section_lines(Some(&json!({ "Status": "Enabled" })));
+        assert_eq!(
+            lines
+                .iter()
+                .map(|line| line
+                    .spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect())
+                .collect::<Vec<String>>(),
+            vec!["{", "  \"Status\": \"Enabled\"", "}"]
+        );
+
+        let lines = create_section_lines(Some(&json!("not json")));
+        assert_eq!(lines, vec![Line::from("\"not json\"")]);
+    }
+}
@@ -0,0 +1,243 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::S3Bucket,
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{
+    format_date_time, s3_bucket_config::S3BucketConfigComponent, s3_objects::S3ObjectComponent,
+};
+
+pub struct S3BucketComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    bucket_list: ListComponent<'a>,
+    first_time_render: bool,
+    object_component: S3ObjectComponent<'a>,
+    is_object_view: bool,
+    config_component: S3BucketConfigComponent,
+    is_config_view: bool,
+}
+
+impl<'a> Component for S3BucketComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3BucketComponent {
+            action_tx: action_tx.clone(),
+            bucket_list: ListComponent::new(),
+            first_time_render: true,
+            object_component: S3ObjectComponent::new(action_tx.clone()),
+            is_object_view: false,
+            config_component: S3BucketConfigComponent::new(action_tx.clone()),
+            is_config_view: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_object_view {
+            return self.object_component.set_focus();
+        }
+
+        if self.is_config_view {
+            return self.config_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: vec![TUI_CONFIG.breadcrumbs.s3_buckets.into()],
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.info.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_object_view && self.object_component.is_capturing_input()
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_object_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && self.object_component.is_root_prefix()
+                && !self.object_component.is_capturing_input()
+            {
+                self.reset_active_bucket()?;
+            } else {
+                self.object_component.handle_key_event(key, app_state)?;
+            }
+        } else if self.is_config_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.is_config_view = false;
+                self.set_focus()?;
+            } else {
+                self.config_component.handle_key_event(key, app_state)?;
+            }
+        } else {
+            match key.code {
+                KeyCode::Char('u') => self.update()?,
+                KeyCode::Char('i') => {
+                    if let Some(bucket) = app_state
+                        .s3_state
+                        .buckets
+                        .get(self.bucket_list.get_selected_index())
+                    {
+                        self.config_component.show(bucket.name.clone(), app_state)?;
+                        self.is_config_view = true;
+                    }
+                }
+                val if TUI_CONFIG.list_config.selection_up == val => self.bucket_list.move_up(),
+                val if TUI_CONFIG.list_config.selection_down == val
+                    && self.bucket_list.has_list_elements() =>
+                {
+                    self.bucket_list.move_down()
+                }
+                val if TUI_CONFIG.list_config.do_selection == val => {
+                    self.set_active_bucket(app_state)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.first_time_render {
+            let _ = self.update();
+            self.first_time_render = false;
+        }
+
+        if self.is_object_view {
+            self.object_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_config_view {
+            self.config_component.render(frame, area, app_state);
+            return;
+        }
+
+        let buckets = &app_state.s3_state.buckets;
+        self.bucket_list
+            .create_list_items(buckets.iter().map(create_list_item));
+        self.bucket_list.set_selected_index(
+            self.bucket_list
+                .get_selected_index()
+                .min(buckets.len().saturating_sub(1)),
+        );
+
+        if buckets.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo S3 Buckets available").block(self.create_block(app_state)),
+                area,
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.bucket_list.get_selected_index()));
+            let list = List::new(self.bucket_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(self.create_block(app_state));
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+    }
+}
+
+impl<'a> S3BucketComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    fn update(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::S3 {
+            action: S3Action::GetBuckets,
+        })?;
+
+        Ok(())
+    }
+
+    fn set_active_bucket(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(bucket) = app_state
+            .s3_state
+            .buckets
+            .get(self.bucket_list.get_selected_index())
+        {
+            self.action_tx.send(Action::S3 {
+                action: S3Action::SelectBucket {
+                    bucket_name: bucket.name.clone(),
+                },
+            })?;
+            self.object_component.set_bucket_name(bucket.name.clone());
+            self.is_object_view = true;
+            self.object_component.set_focus()?;
+        }
+
+        Ok(())
+    }
+
+    fn reset_active_bucket(&mut self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::S3 {
+            action: S3Action::DeselectBucket,
+        })?;
+        self.is_object_view = false;
+        self.set_focus()
+    }
+
+    fn create_block(&self, app_state: &AppState) -> Block<'_> {
+        Block::default()
+            .title(format!(
+                " S3 Buckets ({}) [{}] ",
+                app_state.s3_state.buckets.len(),
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn create_list_item(bucket: &S3Bucket) -> String {
+    format!(
+        "{}  {:<14}  {}",
+        format_date_time(bucket.creation_date, "no creation date  "),
+        bucket.region.as_deref().unwrap_or("unknown region"),
+        bucket.name
+    )
+}
@@ -0,0 +1,475 @@
+use crossterm::event::KeyEvent;
+use human_bytes::human_bytes;
+use miniz_oxide::inflate::stream::{inflate, InflateState};
+use miniz_oxide::{DataFormat, MZFlush};
+
+use crate::{
+    state::{
+        action_handlers::actions::Action,
+        appstate::{AppState, ComponentType},
+        s3_state::{S3ObjectMetadata, S3ObjectPreview},
+    },
+    ui::{
+        component::{base::json_highlight::highlight_json, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::format_date_time;
+
+/// Decompressed previews of gzip objects are limited to this many times the fetched size.
+const GZIP_MAX_EXPANSION: usize = 16;
+const MAX_COLUMN_WIDTH: usize = 40;
+
+#[derive(Clone, Debug, PartialEq)]
+enum PreviewContent {
+    Text(Vec<Line<'static>>),
+    Csv(Vec<Vec<String>>),
+    Binary,
+}
+
+pub struct S3ObjectPreviewComponent {
+    action_tx: UnboundedSender<Action>,
+    breadcrumbs: Vec<String>,
+    preview_key: Option<String>,
+    content: PreviewContent,
+    vertical_offset: usize,
+    page_height: usize,
+}
+
+impl Component for S3ObjectPreviewComponent {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3ObjectPreviewComponent {
+            action_tx: action_tx.clone(),
+            breadcrumbs: vec![],
+            preview_key: None,
+            content: PreviewContent::Binary,
+            vertical_offset: 0,
+            page_height: 0,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: self.breadcrumbs.clone(),
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.page_up.into(),
+                    TUI_CONFIG.menu.page_down.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, _app_state: &AppState) -> anyhow::Result<()> {
+        let max_offset = self.get_line_count().saturating_sub(self.page_height);
+
+        match key.code {
+            val if TUI_CONFIG.list_config.selection_up == val => {
+                self.vertical_offset = self.vertical_offset.saturating_sub(1)
+            }
+            val if TUI_CONFIG.list_config.selection_down == val => {
+                self.vertical_offset = (self.vertical_offset + 1).min(max_offset)
+            }
+            val if TUI_CONFIG.list_config.page_up == val => {
+                self.vertical_offset = self.vertical_offset.saturating_sub(self.page_height)
+            }
+            val if TUI_CONFIG.list_config.page_down == val => {
+                self.vertical_offset = (self.vertical_offset + self.page_height).min(max_offset)
+            }
+            val if TUI_CONFIG.list_config.jump_to_start == val => self.vertical_offset = 0,
+            val if TUI_CONFIG.list_config.jump_to_end == val => self.vertical_offset = max_offset,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let Some(preview) = &app_state.s3_state.preview else {
+            frame.render_widget(
+                Paragraph::new("\nLoading preview ...")
+                    .block(self.create_block(app_state, " Preview ".into())),
+                area,
+            );
+            return;
+        };
+
+        if self.preview_key.as_ref() != Some(&preview.key) {
+            self.preview_key = Some(preview.key.clone());
+            self.content = create_preview_content(preview);
+            self.vertical_offset = 0;
+        }
+
+        let metadata_lines = create_metadata_lines(&preview.metadata);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(metadata_lines.len() as u16 + 2),
+                Constraint::Min(3),
+            ])
+            .split(area);
+
+        frame.render_widget(
+            Paragraph::new(metadata_lines.join("\n"))
+                .block(self.create_block(app_state, format!(" {} ", preview.key))),
+            layout[0],
+        );
+
+        let title = format!(
+            " Content{} ",
+            if preview.is_truncated {
+                format!(" (first {} KB)", TUI_CONFIG.s3_preview_size_in_kb)
+            } else {
+                String::default()
+            }
+        );
+        // the rounded border takes two lines, the csv header row a third
+        self.page_height = layout[1].height.saturating_sub(2) as usize;
+
+        match &self.content {
+            PreviewContent::Text(lines) => frame.render_widget(
+                Paragraph::new(lines.clone())
+                    .scroll((self.vertical_offset as u16, 0))
+                    .block(self.create_block(app_state, title)),
+                layout[1],
+            ),
+            PreviewContent::Csv(rows) => {
+                self.page_height = self.page_height.saturating_sub(1);
+                let column_count = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+                let widths = (0..column_count)
+                    .map(|index| {
+                        let width = rows
+                            .iter()
+                            .filter_map(|row| row.get(index))
+                            .map(|value| value.chars().count())
+                            .max()
+                            .unwrap_or_default();
+                        Constraint::Length(width.min(MAX_COLUMN_WIDTH) as u16)
+                    })
+                    .collect::<Vec<Constraint>>();
+                let header = Row::new(
+                    rows.first()
+                        .cloned()
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(Cell::from),
+                )
+                .style(Style::new().add_modifier(Modifier::BOLD));
+                let table = Table::new(
+                    rows.iter()
+                        .skip(1 + self.vertical_offset)
+                        .map(|row| Row::new(row.iter().map(|value| Cell::from(value.as_str())))),
+                )
+                .header(header)
+                .widths(&widths)
+                .column_spacing(2)
+                .block(self.create_block(app_state, title));
+                frame.render_widget(table, layout[1]);
+            }
+            PreviewContent::Binary => frame.render_widget(
+                Paragraph::new("\nBinary content, no preview available")
+                    .block(self.create_block(app_state, title)),
+                layout[1],
+            ),
+        }
+    }
+}
+
+impl S3ObjectPreviewComponent {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self, breadcrumbs: Vec<String>) {
+        self.breadcrumbs = breadcrumbs;
+        self.preview_key = None;
+        self.vertical_offset = 0;
+    }
+
+    fn get_line_count(&self) -> usize {
+        match &self.content {
+            PreviewContent::Text(lines) => lines.len(),
+            PreviewContent::Csv(rows) => rows.len().saturating_sub(1),
+            PreviewContent::Binary => 0,
+        }
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'static> {
+        Block::default()
+            .title(format!(
+                "{}[{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn create_metadata_lines(metadata: &S3ObjectMetadata) -> Vec<String> {
+    let encryption = match (&metadata.server_side_encryption, &metadata.sse_kms_key_id) {
+        (Some(encryption), Some(key_id)) => format!("{} ({})", encryption, key_id),
+        (Some(encryption), None) => encryption.clone(),
+        (None, _) => "none".into(),
+    };
+
+    let mut lines = vec![
+        format!(
+            "size: {}  |  last modified: {}  |  storage class: {}",
+            metadata
+                .content_length
+                .map(|size| human_bytes(size as f64))
+                .unwrap_or("unknown".into()),
+            format_date_time(metadata.last_modified, "unknown"),
+            metadata.storage_class.as_deref().unwrap_or("STANDARD")
+        ),
+        format!(
+            "content type: {}  |  encoding: {}  |  ETag: {}",
+            metadata.content_type.as_deref().unwrap_or("unknown"),
+            metadata.content_encoding.as_deref().unwrap_or("none"),
+            metadata.e_tag.as_deref().unwrap_or("none")
+        ),
+        format!("encryption: {}", encryption),
+    ];
+    lines.extend(
+        metadata
+            .user_metadata
+            .iter()
+            .map(|(key, value)| format!("x-amz-meta-{}: {}", key, value)),
+    );
+
+    lines
+}
+
+fn create_preview_content(preview: &S3ObjectPreview) -> PreviewContent {
+    let is_gzip = preview.content.starts_with(&[0x1f, 0x8b]);
+    let (content, is_truncated) = if is_gzip {
+        match gunzip(&preview.content, preview.content.len() * GZIP_MAX_EXPANSION) {
+            Some((content, is_complete)) => (content, preview.is_truncated || !is_complete),
+            None => return PreviewContent::Binary,
+        }
+    } else {
+        (preview.content.clone(), preview.is_truncated)
+    };
+
+    let Some(text) = decode_text(&content) else {
+        return PreviewContent::Binary;
+    };
+
+    let key = preview.key.strip_suffix(".gz").unwrap_or(&preview.key);
+    let content_type = preview.metadata.content_type.as_deref().unwrap_or_default();
+
+    if key.ends_with(".json") || content_type.contains("json") {
+        if let Some(lines) = highlight_json(&text) {
+            return PreviewContent::Text(lines);
+        }
+    }
+
+    if key.ends_with(".csv") || content_type.contains("csv") {
+        return PreviewContent::Csv(parse_csv(&text, is_truncated));
+    }
+
+    PreviewContent::Text(
+        text.lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect(),
+    )
+}
+
+/// Text with a character cut off at the end of the fetched bytes is still text.
+fn decode_text(content: &[u8]) -> Option<String> {
+    let text = match std::str::from_utf8(content) {
+        Ok(text) => text,
+        Err(err) if err.error_len().is_none() => {
+            std::str::from_utf8(&content[..err.valid_up_to()]).ok()?
+        }
+        Err(_) => return None,
+    };
+
+    (!text.contains('\0')).then(|| text.to_string())
+}
+
+/// Inflates the gzip member as far as the bytes go, the flag tells if the stream ended.
+fn gunzip(content: &[u8], max_output: usize) -> Option<(Vec<u8>, bool)> {
+    const FLAG_HEADER_CRC: u8 = 0x02;
+    const FLAG_EXTRA: u8 = 0x04;
+    const FLAG_NAME: u8 = 0x08;
+    const FLAG_COMMENT: u8 = 0x10;
+
+    // only deflate is defined as compression method
+    if content.len() < 10 || content[2] != 8 {
+        return None;
+    }
+
+    let flags = content[3];
+    let mut index = 10;
+    if flags & FLAG_EXTRA != 0 {
+        let extra_len = u16::from_le_bytes([*content.get(index)?, *content.get(index + 1)?]);
+        index += 2 + extra_len as usize;
+    }
+    for flag in [FLAG_NAME, FLAG_COMMENT] {
+        if flags & flag != 0 {
+            index += content.get(index..)?.iter().position(|byte| *byte == 0)? + 1;
+        }
+    }
+    if flags & FLAG_HEADER_CRC != 0 {
+        index += 2;
+    }
+
+    let mut state = InflateState::new_boxed(DataFormat::Raw);
+    let mut output = vec![0; max_output];
+    let result = inflate(
+        &mut state,
+        content.get(index..)?,
+        &mut output,
+        MZFlush::Finish,
+    );
+    output.truncate(result.bytes_written);
+
+    Some((
+        output,
+        result.status == Ok(miniz_oxide::MZStatus::StreamEnd),
+    ))
+}
+
+/// Quoted fields may contain separators, quotes are escaped by doubling them.
+fn parse_csv(text: &str, is_truncated: bool) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if is_quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !is_quoted => {}
+            '\n' if !is_quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(char),
+        }
+    }
+
+    // the last row of a truncated preview is incomplete
+    if !is_truncated && (!field.is_empty() || !row.is_empty()) {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use miniz_oxide::deflate::compress_to_vec;
+
+    use super::*;
+
+    fn create_gzip(content: &[u8]) -> Vec<u8> {
+        // header with a file name flag, the trailer is not needed for the preview
+        let mut gzip = vec![0x1f, 0x8b, 8, 0x08, 0, 0, 0, 0, 0, 255];
+        gzip.extend_from_slice(b"data.csv\0");
+        gzip.extend(compress_to_vec(content, 6));
+        gzip
+    }
+
+    #[test]
+    fn test_parse_quoted_csv_fields() {
+        assert_eq!(
+            parse_csv(
+                "name,comment\r\na,\"x, \"\"y\"\"\"\nb,\"multi\nline\"\n",
+                false
+            ),
+            vec![
+                vec!["name".to_string(), "comment".to_string()],
+                vec!["a".to_string(), "x, \"y\"".to_string()],
+                vec!["b".to_string(), "multi\nline".to_string()],
+            ]
+        );
+        assert_eq!(parse_csv("a,b\nc,d\ne,", true).len(), 2);
+    }
+
+    #[test]
+    fn test_inflate_complete_and_truncated_gzip() {
+        let content = "line\n".repeat(1000);
+        let gzip = create_gzip(content.as_bytes());
+
+        assert_eq!(
+            gunzip(&gzip, 100_000),
+            Some((content.as_bytes().to_vec(), true))
+        );
+
+        let (partial, is_complete) = gunzip(&gzip[..gzip.len() / 2], 100_000).unwrap();
+        assert!(!is_complete);
+        assert!(content.as_bytes().starts_with(&partial));
+
+        assert_eq!(gunzip(b"not gzip", 100), None);
+    }
+
+    #[test]
+    fn test_detect_content_by_key_and_bytes() {
+        let preview = S3ObjectPreview {
+            key: "report.csv.gz".into(),
+            content: create_gzip(b"a,b\n1,2\n"),
+            ..S3ObjectPreview::default()
+        };
+        assert_eq!(
+            create_preview_content(&preview),
+            PreviewContent::Csv(vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["1".to_string(), "2".to_string()],
+            ])
+        );
+
+        let preview = S3ObjectPreview {
+            key: "image.png".into(),
+            content: vec![0x89, b'P', b'N', b'G', 0, 0xff],
+            ..S3ObjectPreview::default()
+        };
+        assert_eq!(create_preview_content(&preview), PreviewContent::Binary);
+
+        assert_eq!(
+            decode_text("grün".as_bytes()[..3].as_ref()),
+            Some("gr".into())
+        );
+    }
+}
@@ -0,0 +1,387 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use human_bytes::human_bytes;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    config::app_config::get_default_download_path,
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::S3ObjectVersion,
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+use super::{create_path_textarea, format_date_time, s3_object_preview::S3ObjectPreviewComponent};
+
+/// The versions and delete markers of an object or, to find deleted objects, of a prefix.
+pub struct S3ObjectVersionComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    version_list: ListComponent<'a>,
+    breadcrumbs: Vec<String>,
+    path_textarea: TextArea<'a>,
+    is_download_dialog: bool,
+    preview_component: S3ObjectPreviewComponent,
+    is_preview_view: bool,
+}
+
+impl<'a> Component for S3ObjectVersionComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3ObjectVersionComponent {
+            action_tx: action_tx.clone(),
+            version_list: ListComponent::new(),
+            breadcrumbs: vec![],
+            path_textarea: TextArea::default(),
+            is_download_dialog: false,
+            preview_component: S3ObjectPreviewComponent::new(action_tx.clone()),
+            is_preview_view: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_preview_view {
+            return self.preview_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: self.breadcrumbs.clone(),
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.download.into(),
+                    TUI_CONFIG.menu.restore.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_download_dialog
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_download_dialog {
+            return self.handle_download_dialog_key_event(key, app_state);
+        }
+
+        if self.is_preview_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.action_tx.send(Action::S3 {
+                    action: S3Action::ClosePreview,
+                })?;
+                self.is_preview_view = false;
+                return self.set_focus();
+            }
+            return self.preview_component.handle_key_event(key, app_state);
+        }
+
+        let selected_version = app_state
+            .s3_state
+            .object_versions
+            .get(self.version_list.get_selected_index());
+
+        match key.code {
+            KeyCode::Char('u') => {
+                self.action_tx.send(Action::S3 {
+                    action: S3Action::GetObjectVersions {
+                        key: app_state.s3_state.object_versions_key.clone(),
+                    },
+                })?;
+            }
+            KeyCode::Char('d')
+                if selected_version.is_some_and(|version| !version.is_delete_marker) =>
+            {
+                self.path_textarea = create_path_textarea(
+                    " Download selected version to directory ",
+                    get_default_download_path().display().to_string(),
+                );
+                self.is_download_dialog = true;
+            }
+            KeyCode::Char('r') => {
+                if let Some(action) = selected_version.and_then(create_restore_action) {
+                    self.action_tx.send(Action::S3 { action })?;
+                }
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.version_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.version_list.has_list_elements() =>
+            {
+                self.version_list.move_down()
+            }
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                if let Some(version) = selected_version.filter(|version| !version.is_delete_marker)
+                {
+                    let mut breadcrumbs = self.breadcrumbs.clone();
+                    breadcrumbs.push(TUI_CONFIG.breadcrumbs.s3_preview.into());
+
+                    self.action_tx.send(Action::S3 {
+                        action: S3Action::PreviewObject {
+                            key: version.key.clone(),
+                            version_id: version.version_id.clone(),
+                        },
+                    })?;
+                    self.preview_component.show(breadcrumbs);
+                    self.is_preview_view = true;
+                    self.set_focus()?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.is_preview_view {
+            self.preview_component.render(frame, area, app_state);
+            return;
+        }
+
+        let s3_state = &app_state.s3_state;
+        let is_single_key = s3_state.object_versions_key.is_some();
+        self.version_list.create_list_items(
+            s3_state
+                .object_versions
+                .iter()
+                .map(|version| create_list_item(version, &s3_state.prefix, is_single_key)),
+        );
+        self.version_list.set_selected_index(
+            self.version_list
+                .get_selected_index()
+                .min(s3_state.object_versions.len().saturating_sub(1)),
+        );
+
+        if s3_state.object_versions.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo S3 Object versions available")
+                    .block(self.create_block(app_state)),
+                area,
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.version_list.get_selected_index()));
+            let list = List::new(self.version_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(self.create_block(app_state));
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+
+        if self.is_download_dialog {
+            let popup_area = self.centered_rect(60, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(self.path_textarea.widget(), popup_area);
+        }
+    }
+}
+
+impl<'a> S3ObjectVersionComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self, breadcrumbs: Vec<String>) {
+        self.breadcrumbs = breadcrumbs;
+        self.is_download_dialog = false;
+        self.is_preview_view = false;
+        self.version_list.set_selected_index(0);
+    }
+
+    /// Esc leaves the version list, a shown preview handles it itself.
+    pub fn is_list_view(&self) -> bool {
+        !self.is_preview_view
+    }
+
+    fn handle_download_dialog_key_event(
+        &mut self,
+        key: KeyEvent,
+        app_state: &AppState,
+    ) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.is_download_dialog = false,
+            KeyCode::Enter => {
+                let path = PathBuf::from(self.path_textarea.lines().join("").trim());
+                if path.as_os_str().is_empty() {
+                    return Ok(());
+                }
+
+                if let Some(version) = app_state
+                    .s3_state
+                    .object_versions
+                    .get(self.version_list.get_selected_index())
+                {
+                    self.action_tx.send(Action::S3 {
+                        action: S3Action::DownloadObject {
+                            key: version.key.clone(),
+                            version_id: version.version_id.clone(),
+                            local_dir: path,
+                        },
+                    })?;
+                }
+                self.is_download_dialog = false;
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ => {
+                self.path_textarea.input(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn create_block(&self, app_state: &AppState) -> Block<'_> {
+        let s3_state = &app_state.s3_state;
+        let location = match &s3_state.object_versions_key {
+            Some(key) => key.as_str(),
+            None => s3_state.prefix.as_str(),
+        };
+
+        Block::default()
+            .title(format!(
+                " Versions of {}/{} ({}) [{}] ",
+                s3_state
+                    .selected_bucket
+                    .as_ref()
+                    .map(|bucket| bucket.name.as_str())
+                    .unwrap_or_default(),
+                location,
+                s3_state.object_versions.len(),
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+/// Removing the current delete marker undeletes an object, copying an older version
+/// over the object makes it current. The current version has nothing to restore.
+fn create_restore_action(version: &S3ObjectVersion) -> Option<S3Action> {
+    let key = version.key.clone();
+    let version_id = version.version_id.clone()?;
+
+    match (version.is_delete_marker, version.is_latest) {
+        (true, true) => Some(S3Action::DeleteObjectVersion { key, version_id }),
+        (false, false) => Some(S3Action::RestoreObjectVersion { key, version_id }),
+        _ => None,
+    }
+}
+
+fn create_list_item(version: &S3ObjectVersion, prefix: &str, is_single_key: bool) -> String {
+    let size = if version.is_delete_marker {
+        "<deleted>".into()
+    } else {
+        human_bytes(version.size.unwrap_or_default() as f64)
+    };
+    let item = format!(
+        "{:<6}  {:>10}  {:<20}  {:<32}",
+        if version.is_latest { "latest" } else { "" },
+        size,
+        format_date_time(version.last_modified, ""),
+        version.version_id.as_deref().unwrap_or_default()
+    );
+
+    if is_single_key {
+        item.trim_end().into()
+    } else {
+        format!(
+            "{}  {}",
+            item,
+            version.key.strip_prefix(prefix).unwrap_or(&version.key)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_older_versions_and_remove_current_delete_marker() {
+        let version = S3ObjectVersion {
+            key: "app.log".into(),
+            version_id: Some("v1".into()),
+            ..S3ObjectVersion::default()
+        };
+        assert!(matches!(
+            create_restore_action(&version),
+            Some(S3Action::RestoreObjectVersion { version_id, .. }) if version_id == "v1"
+        ));
+
+        let delete_marker = S3ObjectVersion {
+            is_latest: true,
+            is_delete_marker: true,
+            ..version.clone()
+        };
+        assert!(matches!(
+            create_restore_action(&delete_marker),
+            Some(S3Action::DeleteObjectVersion { .. })
+        ));
+
+        let latest = S3ObjectVersion {
+            is_latest: true,
+            ..version.clone()
+        };
+        assert!(create_restore_action(&latest).is_none());
+        assert!(create_restore_action(&S3ObjectVersion::default()).is_none());
+    }
+
+    #[test]
+    fn test_list_delete_markers_with_key_below_prefix() {
+        let delete_marker = S3ObjectVersion {
+            key: "logs/app.log".into(),
+            version_id: Some("v2".into()),
+            is_latest: true,
+            is_delete_marker: true,
+            last_modified: Some(1_700_000_000),
+            ..S3ObjectVersion::default()
+        };
+
+        assert_eq!(
+            create_list_item(&delete_marker, "logs/", false),
+            format!(
+                "latest   <deleted>  2023-11-14T22:13:20Z  {:<32}  app.log",
+                "v2"
+            )
+        );
+        assert_eq!(
+            create_list_item(&delete_marker, "logs/", true),
+            "latest   <deleted>  2023-11-14T22:13:20Z  v2"
+        );
+    }
+}
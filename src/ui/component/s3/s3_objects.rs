@@ -0,0 +1,718 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use human_bytes::human_bytes;
+
+use crate::{
+    config::app_config::get_default_download_path,
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::{S3Object, S3SyncDirection, S3Transfer, S3TransferDirection, S3TransferStatus},
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use super::{
+    create_path_textarea, format_date_time, s3_object_preview::S3ObjectPreviewComponent,
+    s3_object_versions::S3ObjectVersionComponent, s3_presign::S3PresignComponent,
+    s3_sync::S3SyncComponent,
+};
+
+pub struct S3ObjectComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    object_list: ListComponent<'a>,
+    bucket_name: String,
+    prefix: String,
+    path_textarea: TextArea<'a>,
+    transfer_dialog: Option<S3TransferDirection>,
+    transfer_list: ListComponent<'a>,
+    is_showing_transfers: bool,
+    preview_component: S3ObjectPreviewComponent,
+    is_preview_view: bool,
+    version_component: S3ObjectVersionComponent<'a>,
+    is_version_view: bool,
+    presign_component: S3PresignComponent<'a>,
+    sync_component: S3SyncComponent<'a>,
+    is_sync_view: bool,
+}
+
+impl<'a> Component for S3ObjectComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3ObjectComponent {
+            action_tx: action_tx.clone(),
+            object_list: ListComponent::new(),
+            bucket_name: String::default(),
+            prefix: String::default(),
+            path_textarea: TextArea::default(),
+            transfer_dialog: None,
+            transfer_list: ListComponent::new(),
+            is_showing_transfers: false,
+            preview_component: S3ObjectPreviewComponent::new(action_tx.clone()),
+            is_preview_view: false,
+            version_component: S3ObjectVersionComponent::new(action_tx.clone()),
+            is_version_view: false,
+            presign_component: S3PresignComponent::new(action_tx.clone()),
+            sync_component: S3SyncComponent::new(action_tx.clone()),
+            is_sync_view: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        if self.is_preview_view {
+            return self.preview_component.set_focus();
+        }
+
+        if self.is_version_view {
+            return self.version_component.set_focus();
+        }
+
+        if self.is_sync_view {
+            return self.sync_component.set_focus();
+        }
+
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: create_breadcrumbs(&self.bucket_name, &self.prefix),
+        })?;
+
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [
+                vec![],
+                vec![],
+                vec![
+                    TUI_CONFIG.menu.up.into(),
+                    TUI_CONFIG.menu.down.into(),
+                    TUI_CONFIG.menu.select.into(),
+                    TUI_CONFIG.menu.parent_folder.into(),
+                    TUI_CONFIG.menu.download.into(),
+                    TUI_CONFIG.menu.upload.into(),
+                    TUI_CONFIG.menu.transfers.into(),
+                    TUI_CONFIG.menu.versions.into(),
+                    TUI_CONFIG.menu.presign.into(),
+                    TUI_CONFIG.menu.compare.into(),
+                    TUI_CONFIG.menu.next_page.into(),
+                    TUI_CONFIG.menu.refresh.into(),
+                    TUI_CONFIG.menu.back.into(),
+                ],
+            ],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.transfer_dialog.is_some()
+            || self.is_showing_transfers
+            || self.presign_component.is_capturing_input()
+            || (self.is_version_view && self.version_component.is_capturing_input())
+            || (self.is_sync_view && self.sync_component.is_capturing_input())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(direction) = self.transfer_dialog {
+            return self.handle_transfer_dialog_key_event(key, direction, app_state);
+        }
+
+        if self.is_showing_transfers {
+            return self.handle_transfer_list_key_event(key, app_state);
+        }
+
+        if self.presign_component.is_capturing_input() {
+            return self.presign_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_preview_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code {
+                self.action_tx.send(Action::S3 {
+                    action: S3Action::ClosePreview,
+                })?;
+                self.is_preview_view = false;
+                return self.set_focus();
+            }
+            return self.preview_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_version_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && self.version_component.is_list_view()
+                && !self.version_component.is_capturing_input()
+            {
+                self.is_version_view = false;
+                return self.set_focus();
+            }
+            return self.version_component.handle_key_event(key, app_state);
+        }
+
+        if self.is_sync_view {
+            if TUI_CONFIG.list_config.undo_selection == key.code
+                && self.sync_component.is_comparison_view()
+                && !self.sync_component.is_capturing_input()
+            {
+                self.action_tx.send(Action::S3 {
+                    action: S3Action::CloseSync,
+                })?;
+                self.is_sync_view = false;
+                return self.set_focus();
+            }
+            return self.sync_component.handle_key_event(key, app_state);
+        }
+
+        match key.code {
+            KeyCode::Char('d') => self.show_transfer_dialog(S3TransferDirection::Download),
+            KeyCode::Char('p') => self.show_transfer_dialog(S3TransferDirection::Upload),
+            KeyCode::Char('t') => {
+                self.transfer_list.set_selected_index(0);
+                self.is_showing_transfers = true;
+            }
+            KeyCode::Char('v') => self.show_versions(app_state)?,
+            KeyCode::Char('y') => self.show_sync()?,
+            KeyCode::Char('g') => {
+                if let Some(object) = app_state
+                    .s3_state
+                    .objects
+                    .get(self.object_list.get_selected_index())
+                    .filter(|object| !object.is_prefix)
+                {
+                    self.presign_component.show(object.key.clone());
+                }
+            }
+            KeyCode::Char('u') => self.open_prefix(self.prefix.clone())?,
+            KeyCode::Char('n') => self.load_more_objects(app_state)?,
+            KeyCode::Backspace => self.open_prefix(parent_prefix(&self.prefix).into())?,
+            val if TUI_CONFIG.list_config.undo_selection == val => {
+                self.open_prefix(parent_prefix(&self.prefix).into())?
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.object_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.object_list.has_list_elements() =>
+            {
+                self.object_list.move_down();
+                if self.object_list.get_selected_index() + 1 == self.object_list.get_list_len() {
+                    self.load_more_objects(app_state)?;
+                }
+            }
+            val if TUI_CONFIG.list_config.do_selection == val => {
+                match app_state
+                    .s3_state
+                    .objects
+                    .get(self.object_list.get_selected_index())
+                {
+                    Some(object) if object.is_prefix => self.open_prefix(object.key.clone())?,
+                    Some(object) => self.show_preview(object.key.clone())?,
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.is_preview_view {
+            self.preview_component.render(frame, area, app_state);
+            return;
+        }
+
+        if self.is_version_view {
+            self.version_component.render(frame, area, app_state);
+            if self.is_showing_transfers {
+                self.render_transfers(frame, app_state);
+            }
+            return;
+        }
+
+        if self.is_sync_view {
+            self.sync_component.render(frame, area, app_state);
+            return;
+        }
+
+        let s3_state = &app_state.s3_state;
+        self.object_list.create_list_items(
+            s3_state
+                .objects
+                .iter()
+                .map(|object| create_list_item(object, &s3_state.prefix)),
+        );
+        self.object_list.set_selected_index(
+            self.object_list
+                .get_selected_index()
+                .min(s3_state.objects.len().saturating_sub(1)),
+        );
+
+        if s3_state.objects.is_empty() {
+            frame.render_widget(
+                Paragraph::new("\nNo S3 Objects available").block(self.create_block(app_state)),
+                area,
+            );
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.object_list.get_selected_index()));
+            let list = List::new(self.object_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(self.create_block(app_state));
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+
+        if self.transfer_dialog.is_some() {
+            let popup_area = self.centered_rect(60, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(self.path_textarea.widget(), popup_area);
+        }
+
+        if self.is_showing_transfers {
+            self.render_transfers(frame, app_state);
+        }
+
+        self.presign_component
+            .render(frame, app_state.area, app_state);
+    }
+}
+
+impl<'a> S3ObjectComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn set_bucket_name(&mut self, bucket_name: String) {
+        self.bucket_name = bucket_name;
+        self.prefix = String::default();
+        self.is_preview_view = false;
+        self.is_version_view = false;
+        self.is_sync_view = false;
+        self.object_list.set_selected_index(0);
+    }
+
+    pub fn is_root_prefix(&self) -> bool {
+        self.prefix.is_empty()
+            && !self.is_preview_view
+            && !self.is_version_view
+            && !self.is_sync_view
+    }
+
+    fn show_sync(&mut self) -> anyhow::Result<()> {
+        let mut breadcrumbs = create_breadcrumbs(&self.bucket_name, &self.prefix);
+        breadcrumbs.push(TUI_CONFIG.breadcrumbs.s3_sync.into());
+
+        self.sync_component.show(breadcrumbs);
+        self.is_sync_view = true;
+        self.set_focus()
+    }
+
+    /// Shows the versions of the selected object, or of the whole folder so that
+    /// deleted objects can be found through their delete markers.
+    fn show_versions(&mut self, app_state: &AppState) -> anyhow::Result<()> {
+        let key = app_state
+            .s3_state
+            .objects
+            .get(self.object_list.get_selected_index())
+            .filter(|object| !object.is_prefix)
+            .map(|object| object.key.clone());
+        let mut breadcrumbs = create_breadcrumbs(&self.bucket_name, &self.prefix);
+        breadcrumbs.push(TUI_CONFIG.breadcrumbs.s3_versions.into());
+
+        self.action_tx.send(Action::S3 {
+            action: S3Action::GetObjectVersions { key },
+        })?;
+        self.version_component.show(breadcrumbs);
+        self.is_version_view = true;
+        self.set_focus()
+    }
+
+    fn show_preview(&mut self, key: String) -> anyhow::Result<()> {
+        let mut breadcrumbs = create_breadcrumbs(&self.bucket_name, &self.prefix);
+        breadcrumbs.push(TUI_CONFIG.breadcrumbs.s3_preview.into());
+
+        self.action_tx.send(Action::S3 {
+            action: S3Action::PreviewObject {
+                key,
+                version_id: None,
+            },
+        })?;
+        self.preview_component.show(breadcrumbs);
+        self.is_preview_view = true;
+        self.set_focus()
+    }
+
+    fn open_prefix(&mut self, prefix: String) -> anyhow::Result<()> {
+        self.action_tx.send(Action::S3 {
+            action: S3Action::ListObjects {
+                prefix: prefix.clone(),
+                token: None,
+            },
+        })?;
+        self.prefix = prefix;
+        self.object_list.set_selected_index(0);
+        self.set_focus()
+    }
+
+    fn show_transfer_dialog(&mut self, direction: S3TransferDirection) {
+        let (title, path) = match direction {
+            S3TransferDirection::Download => (
+                " Download selected object or folder to directory ",
+                get_default_download_path().display().to_string(),
+            ),
+            S3TransferDirection::Upload => (
+                " Upload file or directory into the current folder ",
+                String::default(),
+            ),
+        };
+
+        self.path_textarea = create_path_textarea(title, path);
+        self.transfer_dialog = Some(direction);
+    }
+
+    fn handle_transfer_dialog_key_event(
+        &mut self,
+        key: KeyEvent,
+        direction: S3TransferDirection,
+        app_state: &AppState,
+    ) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.transfer_dialog = None,
+            KeyCode::Enter => {
+                let path = PathBuf::from(self.path_textarea.lines().join("").trim());
+                if path.as_os_str().is_empty() {
+                    return Ok(());
+                }
+
+                let action = match direction {
+                    S3TransferDirection::Download => app_state
+                        .s3_state
+                        .objects
+                        .get(self.object_list.get_selected_index())
+                        .map(|object| S3Action::DownloadObject {
+                            key: object.key.clone(),
+                            version_id: None,
+                            local_dir: path,
+                        }),
+                    S3TransferDirection::Upload => Some(S3Action::UploadPath { local_path: path }),
+                };
+                if let Some(action) = action {
+                    self.action_tx.send(Action::S3 { action })?;
+                }
+                self.transfer_dialog = None;
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ => {
+                self.path_textarea.input(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_transfer_list_key_event(
+        &mut self,
+        key: KeyEvent,
+        app_state: &AppState,
+    ) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.is_showing_transfers = false,
+            KeyCode::Char('k') => {
+                if let Some(transfer) = app_state
+                    .s3_state
+                    .transfers
+                    .get(self.transfer_list.get_selected_index())
+                {
+                    self.action_tx.send(Action::S3 {
+                        action: S3Action::CancelTransfer {
+                            transfer_id: transfer.id,
+                        },
+                    })?;
+                }
+            }
+            KeyCode::Char('c') => {
+                self.action_tx.send(Action::S3 {
+                    action: S3Action::ClearTransfers,
+                })?;
+                self.transfer_list.set_selected_index(0);
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.transfer_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.transfer_list.has_list_elements() =>
+            {
+                self.transfer_list.move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render_transfers(&mut self, frame: &mut Frame, app_state: &AppState) {
+        let transfers = &app_state.s3_state.transfers;
+        self.transfer_list
+            .create_list_items(transfers.iter().map(create_transfer_item));
+        self.transfer_list.set_selected_index(
+            self.transfer_list
+                .get_selected_index()
+                .min(transfers.len().saturating_sub(1)),
+        );
+
+        let popup_area = self.centered_list_rect(80, transfers.len(), app_state.area);
+        let block = Block::default()
+            .title(" Transfers (<k> cancel, <c> clear done, <esc> close) ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL);
+        frame.render_widget(Clear, popup_area);
+
+        if transfers.is_empty() {
+            frame.render_widget(Paragraph::new("No transfers").block(block), popup_area);
+        } else {
+            let mut list_state =
+                ListState::default().with_selected(Some(self.transfer_list.get_selected_index()));
+            let list = List::new(self.transfer_list.create_tui_list())
+                .highlight_style(TUI_CONFIG.list_config.selected_style)
+                .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                .block(block);
+            frame.render_stateful_widget(list, popup_area, &mut list_state);
+        }
+    }
+
+    fn load_more_objects(&self, app_state: &AppState) -> anyhow::Result<()> {
+        if let Some(token) = &app_state.s3_state.objects_next_token {
+            self.action_tx.send(Action::S3 {
+                action: S3Action::ListObjects {
+                    prefix: self.prefix.clone(),
+                    token: Some(token.clone()),
+                },
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn create_block(&self, app_state: &AppState) -> Block<'_> {
+        let s3_state = &app_state.s3_state;
+        let more_info = if s3_state.objects_next_token.is_some() {
+            ", more available"
+        } else {
+            ""
+        };
+        let active_transfers = s3_state
+            .transfers
+            .iter()
+            .filter(|transfer| !transfer.is_done())
+            .count();
+        let transfer_info = if active_transfers > 0 {
+            format!(", {} transfers", active_transfers)
+        } else {
+            String::default()
+        };
+
+        Block::default()
+            .title(format!(
+                " s3://{}/{} (loaded {}{}{}) [{}] ",
+                self.bucket_name,
+                self.prefix,
+                s3_state.objects.len(),
+                more_info,
+                transfer_info,
+                TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+fn create_list_item(object: &S3Object, prefix: &str) -> String {
+    let name = object.key.strip_prefix(prefix).unwrap_or(&object.key);
+
+    if object.is_prefix {
+        format!("{:>10}  {:<20}  {:<19}  {}", "<dir>", "", "", name)
+    } else {
+        format!(
+            "{:>10}  {:<20}  {:<19}  {}",
+            human_bytes(object.size.unwrap_or_default() as f64),
+            format_date_time(object.last_modified, ""),
+            object.storage_class.as_deref().unwrap_or_default(),
+            name
+        )
+    }
+}
+
+fn create_transfer_item(transfer: &S3Transfer) -> String {
+    let status = match transfer.status {
+        S3TransferStatus::Queued => "queued",
+        S3TransferStatus::Running => "running",
+        S3TransferStatus::Finished => "finished",
+        S3TransferStatus::Cancelled => "cancelled",
+        S3TransferStatus::Failed => "failed",
+    };
+    let bytes = match transfer.bytes_total {
+        Some(bytes_total) => format!(
+            "{} / {}",
+            human_bytes(transfer.bytes_done as f64),
+            human_bytes(bytes_total as f64)
+        ),
+        None => human_bytes(transfer.bytes_done as f64),
+    };
+    let remote_path = format!("s3://{}/{}", transfer.bucket.name, transfer.key);
+    let (source, destination) = match &transfer.sync_plan {
+        Some(sync_plan) => match sync_plan.direction {
+            S3SyncDirection::SourceToTarget => (remote_path, sync_plan.target.to_string()),
+            S3SyncDirection::TargetToSource => (sync_plan.target.to_string(), remote_path),
+        },
+        None => {
+            let local_path = transfer.local_path.display().to_string();
+            match transfer.direction {
+                S3TransferDirection::Download => (remote_path, local_path),
+                S3TransferDirection::Upload => (local_path, remote_path),
+            }
+        }
+    };
+
+    format!(
+        "{:<9}  {:>21}  {}{} -> {}",
+        status,
+        bytes,
+        if transfer.sync_plan.is_some() {
+            "sync "
+        } else {
+            ""
+        },
+        source,
+        destination
+    )
+}
+
+/// The prefix one folder up, `logs/2023/` becomes `logs/` and `logs/` the bucket root.
+fn parent_prefix(prefix: &str) -> &str {
+    let trimmed = prefix.strip_suffix('/').unwrap_or(prefix);
+    match trimmed.rfind('/') {
+        Some(index) => &prefix[..=index],
+        None => "",
+    }
+}
+
+fn create_breadcrumbs(bucket_name: &str, prefix: &str) -> Vec<String> {
+    [TUI_CONFIG.breadcrumbs.s3_buckets.to_string()]
+        .into_iter()
+        .chain([format!(" <{}> ", bucket_name)])
+        .chain(
+            prefix
+                .split_terminator('/')
+                .map(|folder| format!(" {}/ ", folder)),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_util::sync::CancellationToken;
+
+    use crate::state::s3_state::{S3Bucket, S3SyncLocation, S3SyncPlan};
+
+    use super::*;
+
+    #[test]
+    fn test_find_parent_prefix() {
+        assert_eq!(parent_prefix("logs/2023/12/"), "logs/2023/");
+        assert_eq!(parent_prefix("logs/"), "");
+        assert_eq!(parent_prefix(""), "");
+    }
+
+    #[test]
+    fn test_create_breadcrumbs_for_key_path() {
+        assert_eq!(
+            create_breadcrumbs("my-bucket", "logs/2023/"),
+            vec![
+                TUI_CONFIG.breadcrumbs.s3_buckets.to_string(),
+                " <my-bucket> ".to_string(),
+                " logs/ ".to_string(),
+                " 2023/ ".to_string(),
+            ]
+        );
+        assert_eq!(create_breadcrumbs("my-bucket", "").len(), 2);
+    }
+
+    #[test]
+    fn test_show_transfer_direction_and_progress() {
+        let transfer = S3Transfer {
+            id: 0,
+            direction: S3TransferDirection::Upload,
+            bucket: S3Bucket {
+                name: "my-bucket".into(),
+                ..S3Bucket::default()
+            },
+            key: "backup/".into(),
+            version_id: None,
+            base_prefix: "backup/".into(),
+            local_path: PathBuf::from("/data"),
+            bytes_done: 2000,
+            bytes_total: Some(4000),
+            status: S3TransferStatus::Running,
+            cancellation_token: CancellationToken::new(),
+            sync_plan: None,
+            target_sdk_config: None,
+        };
+
+        assert_eq!(
+            create_transfer_item(&transfer),
+            "running              2 KB / 4 KB  /data -> s3://my-bucket/backup/"
+        );
+
+        let sync = S3Transfer {
+            sync_plan: Some(S3SyncPlan {
+                target: S3SyncLocation::Local {
+                    path: PathBuf::from("/data"),
+                },
+                direction: S3SyncDirection::SourceToTarget,
+                operations: vec![],
+            }),
+            ..transfer
+        };
+        assert_eq!(
+            create_transfer_item(&sync),
+            "running              2 KB / 4 KB  sync s3://my-bucket/backup/ -> /data"
+        );
+    }
+
+    #[test]
+    fn test_list_names_relative_to_prefix() {
+        let folder = S3Object {
+            key: "logs/2023/".into(),
+            is_prefix: true,
+            ..S3Object::default()
+        };
+        assert!(create_list_item(&folder, "logs/").ends_with("  2023/"));
+
+        let object = S3Object {
+            key: "logs/app.log".into(),
+            size: Some(2048),
+            last_modified: Some(1_700_000_000),
+            storage_class: Some("STANDARD".into()),
+            ..S3Object::default()
+        };
+        assert_eq!(
+            create_list_item(&object, "logs/"),
+            "      2 KB  2023-11-14T22:13:20Z  STANDARD             app.log"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::{
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::{S3PresignMethod, S3PresignedUrl},
+    },
+    ui::{clipboard::copy_to_clipboard, component::Component},
+};
+
+const DEFAULT_EXPIRY: &str = "1h";
+
+/// Popups to choose method and expiry of a presigned URL and to show and copy it.
+pub struct S3PresignComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    key: String,
+    method: S3PresignMethod,
+    expiry_textarea: TextArea<'a>,
+    is_expiry_dialog: bool,
+    is_showing_url: bool,
+    is_copied: bool,
+}
+
+impl<'a> Component for S3PresignComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3PresignComponent {
+            action_tx,
+            key: String::default(),
+            method: S3PresignMethod::Get,
+            expiry_textarea: TextArea::default(),
+            is_expiry_dialog: false,
+            is_showing_url: false,
+            is_copied: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_expiry_dialog || self.is_showing_url
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_expiry_dialog {
+            match key.code {
+                KeyCode::Esc => self.is_expiry_dialog = false,
+                KeyCode::Tab => {
+                    self.method = match self.method {
+                        S3PresignMethod::Get => S3PresignMethod::Put,
+                        S3PresignMethod::Put => S3PresignMethod::Get,
+                    };
+                    self.set_expiry_block();
+                }
+                KeyCode::Enter => {
+                    if let Some(expires_in) = parse_expiry(&self.expiry_textarea.lines().join("")) {
+                        self.action_tx.send(Action::S3 {
+                            action: S3Action::PresignObject {
+                                key: self.key.clone(),
+                                method: self.method,
+                                expires_in,
+                            },
+                        })?;
+                        self.is_expiry_dialog = false;
+                        self.is_showing_url = true;
+                        self.is_copied = false;
+                    }
+                }
+                KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+                _ => {
+                    self.expiry_textarea.input(key);
+                }
+            }
+        } else if self.is_showing_url {
+            match key.code {
+                KeyCode::Esc => self.is_showing_url = false,
+                KeyCode::Char('c') => {
+                    if let Some(presigned_url) = self.get_presigned_url(app_state) {
+                        copy_to_clipboard(&presigned_url.url)?;
+                        self.is_copied = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        if self.is_expiry_dialog {
+            let popup_area = self.centered_rect(60, 10, area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(self.expiry_textarea.widget(), popup_area);
+        }
+
+        if self.is_showing_url {
+            let text = match self.get_presigned_url(app_state) {
+                Some(presigned_url) => presigned_url.url.clone(),
+                None => "Creating presigned URL ...".into(),
+            };
+            let title = format!(
+                " Presigned {} URL of {} ({}) ",
+                method_name(self.method),
+                self.key,
+                if self.is_copied {
+                    "copied to clipboard, <esc> close"
+                } else {
+                    "<c> copy, <esc> close"
+                }
+            );
+
+            // the wrapped URL gets as many lines as it needs
+            let line_width = (area.width * 80 / 100).saturating_sub(2).max(1) as usize;
+            let line_count = text.chars().count().div_ceil(line_width);
+            let popup_area = self.centered_list_rect(80, line_count, area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(
+                Paragraph::new(text).wrap(Wrap { trim: false }).block(
+                    Block::default()
+                        .title(title)
+                        .title_alignment(Alignment::Center)
+                        .borders(Borders::ALL),
+                ),
+                popup_area,
+            );
+        }
+    }
+}
+
+impl<'a> S3PresignComponent<'a> {
+    pub fn show(&mut self, key: String) {
+        self.key = key;
+        self.method = S3PresignMethod::Get;
+        self.expiry_textarea = TextArea::new(vec![DEFAULT_EXPIRY.into()]);
+        self.expiry_textarea.set_cursor_line_style(Style::default());
+        self.expiry_textarea
+            .set_placeholder_text("Enter expiry like 30m, 12h or 7d");
+        self.expiry_textarea.move_cursor(CursorMove::End);
+        self.set_expiry_block();
+        self.is_expiry_dialog = true;
+        self.is_showing_url = false;
+    }
+
+    fn set_expiry_block(&mut self) {
+        self.expiry_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " Expiry of presigned {} URL (<tab> switch GET/PUT) ",
+                    method_name(self.method)
+                ))
+                .title_alignment(Alignment::Center),
+        );
+    }
+
+    fn get_presigned_url<'b>(&self, app_state: &'b AppState) -> Option<&'b S3PresignedUrl> {
+        app_state
+            .s3_state
+            .presigned_url
+            .as_ref()
+            .filter(|presigned_url| {
+                presigned_url.key == self.key && presigned_url.method == self.method
+            })
+    }
+}
+
+fn method_name(method: S3PresignMethod) -> &'static str {
+    match method {
+        S3PresignMethod::Get => "GET",
+        S3PresignMethod::Put => "PUT",
+    }
+}
+
+/// Parses durations like `90s`, `30m`, `12h` or `7d`, a plain number are seconds.
+fn parse_expiry(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (number, unit_secs) = match text.char_indices().last()? {
+        (index, 's') => (&text[..index], 1),
+        (index, 'm') => (&text[..index], 60),
+        (index, 'h') => (&text[..index], 60 * 60),
+        (index, 'd') => (&text[..index], 24 * 60 * 60),
+        _ => (text, 1),
+    };
+
+    match number.trim().parse::<u64>() {
+        Ok(number) if number > 0 => number.checked_mul(unit_secs).map(Duration::from_secs),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expiry_with_unit() {
+        assert_eq!(parse_expiry("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_expiry("30m"), Some(Duration::from_secs(1800)));
+        assert_eq!(parse_expiry(" 12h "), Some(Duration::from_secs(43_200)));
+        assert_eq!(parse_expiry("7d"), Some(Duration::from_secs(604_800)));
+        assert_eq!(parse_expiry("0h"), None);
+        assert_eq!(parse_expiry("h"), None);
+        assert_eq!(parse_expiry("1w"), None);
+        assert_eq!(parse_expiry(""), None);
+    }
+}
@@ -0,0 +1,545 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use human_bytes::human_bytes;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph},
+    Frame,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::{
+    repository::s3_sync::plan_sync,
+    state::{
+        action_handlers::actions::{Action, S3Action},
+        appstate::{AppState, ComponentType},
+        s3_state::{
+            S3SyncComparison, S3SyncDirection, S3SyncEntry, S3SyncFile, S3SyncLocation,
+            S3SyncStatus, S3TransferOperation,
+        },
+    },
+    ui::{
+        component::{base::list_component::ListComponent, Component},
+        tui_config::TUI_CONFIG,
+    },
+};
+
+/// Compares the current prefix with a local directory or another prefix and shows the
+/// operations of a sync in either direction before they are applied.
+pub struct S3SyncComponent<'a> {
+    action_tx: UnboundedSender<Action>,
+    breadcrumbs: Vec<String>,
+    target_textarea: TextArea<'a>,
+    is_target_dialog: bool,
+    target: Option<S3SyncLocation>,
+    entry_list: ListComponent<'a>,
+    operation_list: ListComponent<'a>,
+    direction: Option<S3SyncDirection>,
+    with_delete: bool,
+}
+
+impl<'a> Component for S3SyncComponent<'a> {
+    fn new(action_tx: UnboundedSender<Action>) -> Self
+    where
+        Self: Sized,
+    {
+        S3SyncComponent {
+            action_tx,
+            breadcrumbs: vec![],
+            target_textarea: TextArea::default(),
+            is_target_dialog: false,
+            target: None,
+            entry_list: ListComponent::new(),
+            operation_list: ListComponent::new(),
+            direction: None,
+            with_delete: false,
+        }
+    }
+
+    fn component_type(&self) -> ComponentType {
+        ComponentType::AWSService
+    }
+
+    fn set_focus(&self) -> anyhow::Result<()> {
+        self.action_tx.send(Action::SetBreadcrumbs {
+            breadcrumbs: self.breadcrumbs.clone(),
+        })?;
+
+        let menu_items = if self.direction.is_some() {
+            vec![
+                TUI_CONFIG.menu.up.into(),
+                TUI_CONFIG.menu.down.into(),
+                TUI_CONFIG.menu.apply.into(),
+                TUI_CONFIG.menu.toggle_delete.into(),
+                TUI_CONFIG.menu.back.into(),
+            ]
+        } else {
+            vec![
+                TUI_CONFIG.menu.up.into(),
+                TUI_CONFIG.menu.down.into(),
+                TUI_CONFIG.menu.sync_to_target.into(),
+                TUI_CONFIG.menu.sync_to_source.into(),
+                TUI_CONFIG.menu.compare.into(),
+                TUI_CONFIG.menu.refresh.into(),
+                TUI_CONFIG.menu.back.into(),
+            ]
+        };
+        self.action_tx.send(Action::SetMenu {
+            menu_items: [vec![], vec![], menu_items],
+        })?;
+
+        Ok(())
+    }
+
+    fn is_capturing_input(&self) -> bool {
+        self.is_target_dialog
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, app_state: &AppState) -> anyhow::Result<()> {
+        if self.is_target_dialog {
+            return self.handle_target_dialog_key_event(key);
+        }
+
+        if self.direction.is_some() {
+            match key.code {
+                KeyCode::Char('d') => self.with_delete = !self.with_delete,
+                val if TUI_CONFIG.list_config.undo_selection == val => {
+                    self.direction = None;
+                    self.set_focus()?;
+                }
+                val if TUI_CONFIG.list_config.selection_up == val => self.operation_list.move_up(),
+                val if TUI_CONFIG.list_config.selection_down == val
+                    && self.operation_list.has_list_elements() =>
+                {
+                    self.operation_list.move_down()
+                }
+                val if TUI_CONFIG.list_config.do_selection == val => {
+                    if let Some(direction) = self.direction {
+                        self.action_tx.send(Action::S3 {
+                            action: S3Action::ApplySync {
+                                direction,
+                                with_delete: self.with_delete,
+                            },
+                        })?;
+                    }
+                    self.direction = None;
+                    self.set_focus()?;
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Char('y') => self.show_target_dialog(),
+            KeyCode::Char('u') => self.compare()?,
+            KeyCode::Char('>') if self.get_comparison(app_state).is_some() => {
+                self.show_plan(S3SyncDirection::SourceToTarget)?
+            }
+            KeyCode::Char('<') if self.get_comparison(app_state).is_some() => {
+                self.show_plan(S3SyncDirection::TargetToSource)?
+            }
+            val if TUI_CONFIG.list_config.selection_up == val => self.entry_list.move_up(),
+            val if TUI_CONFIG.list_config.selection_down == val
+                && self.entry_list.has_list_elements() =>
+            {
+                self.entry_list.move_down()
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, app_state: &AppState) {
+        let comparison = self.get_comparison(app_state);
+
+        match (comparison, self.direction) {
+            (Some(comparison), Some(direction)) => {
+                let (operations, skipped_paths) =
+                    plan_sync(comparison, direction, self.with_delete);
+                self.operation_list
+                    .create_list_items(operations.iter().map(create_operation_item));
+                self.operation_list.set_selected_index(
+                    self.operation_list
+                        .get_selected_index()
+                        .min(operations.len().saturating_sub(1)),
+                );
+
+                let title = format!(
+                    " Dry run: sync {} -> {}, {} operations{}, delete {} ",
+                    match direction {
+                        S3SyncDirection::SourceToTarget => "prefix",
+                        S3SyncDirection::TargetToSource => "target",
+                    },
+                    match direction {
+                        S3SyncDirection::SourceToTarget => "target",
+                        S3SyncDirection::TargetToSource => "prefix",
+                    },
+                    operations.len(),
+                    if skipped_paths.is_empty() {
+                        String::default()
+                    } else {
+                        format!(", {} skipped outside of the directory", skipped_paths.len())
+                    },
+                    if self.with_delete { "on" } else { "off" }
+                );
+                if operations.is_empty() {
+                    frame.render_widget(
+                        Paragraph::new("\nNothing to synchronize")
+                            .block(self.create_block(app_state, title)),
+                        area,
+                    );
+                } else {
+                    let mut list_state = ListState::default()
+                        .with_selected(Some(self.operation_list.get_selected_index()));
+                    let list = List::new(self.operation_list.create_tui_list())
+                        .highlight_style(TUI_CONFIG.list_config.selected_style)
+                        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                        .block(self.create_block(app_state, title));
+                    frame.render_stateful_widget(list, area, &mut list_state);
+                }
+            }
+            (Some(comparison), None) => {
+                self.entry_list
+                    .create_list_items(comparison.entries.iter().map(create_entry_item));
+                self.entry_list.set_selected_index(
+                    self.entry_list
+                        .get_selected_index()
+                        .min(comparison.entries.len().saturating_sub(1)),
+                );
+
+                let title = create_comparison_title(comparison);
+                if comparison.entries.is_empty() {
+                    frame.render_widget(
+                        Paragraph::new("\nNo files on either side")
+                            .block(self.create_block(app_state, title)),
+                        area,
+                    );
+                } else {
+                    let mut list_state = ListState::default()
+                        .with_selected(Some(self.entry_list.get_selected_index()));
+                    let list = List::new(self.entry_list.create_tui_list())
+                        .highlight_style(TUI_CONFIG.list_config.selected_style)
+                        .highlight_symbol(TUI_CONFIG.list_config.selected_symbol)
+                        .block(self.create_block(app_state, title));
+                    frame.render_stateful_widget(list, area, &mut list_state);
+                }
+            }
+            (None, _) => {
+                let text = match &self.target {
+                    Some(_) => "\nComparing ...",
+                    None => "\nNo target to compare with, press <y> to enter one",
+                };
+                frame.render_widget(
+                    Paragraph::new(text).block(self.create_block(app_state, " Compare ".into())),
+                    area,
+                );
+            }
+        }
+
+        if self.is_target_dialog {
+            let popup_area = self.centered_rect(60, 10, app_state.area);
+            frame.render_widget(Clear, popup_area);
+            frame.render_widget(self.target_textarea.widget(), popup_area);
+        }
+    }
+}
+
+impl<'a> S3SyncComponent<'a> {
+    fn has_focus(&self, app_state: &AppState) -> bool {
+        app_state.focus_component == self.component_type()
+    }
+
+    pub fn show(&mut self, breadcrumbs: Vec<String>) {
+        self.breadcrumbs = breadcrumbs;
+        self.target = None;
+        self.direction = None;
+        self.with_delete = false;
+        self.entry_list.set_selected_index(0);
+        self.show_target_dialog();
+    }
+
+    /// Esc leaves the comparison, a shown dry run handles it itself.
+    pub fn is_comparison_view(&self) -> bool {
+        self.direction.is_none()
+    }
+
+    fn show_target_dialog(&mut self) {
+        let text = self
+            .target
+            .as_ref()
+            .map(|target| target.to_string())
+            .unwrap_or_default();
+        self.target_textarea = TextArea::new(vec![text]);
+        self.target_textarea.set_cursor_line_style(Style::default());
+        self.target_textarea
+            .set_placeholder_text("Enter local directory or [profile:]s3://bucket/prefix");
+        self.target_textarea.move_cursor(CursorMove::End);
+        self.target_textarea.set_block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Compare prefix with ")
+                .title_alignment(Alignment::Center),
+        );
+        self.is_target_dialog = true;
+    }
+
+    fn handle_target_dialog_key_event(&mut self, key: KeyEvent) -> anyhow::Result<()> {
+        match key.code {
+            KeyCode::Esc => self.is_target_dialog = false,
+            KeyCode::Enter => {
+                if let Some(target) = parse_sync_location(&self.target_textarea.lines().join("")) {
+                    self.target = Some(target);
+                    self.is_target_dialog = false;
+                    self.entry_list.set_selected_index(0);
+                    self.compare()?;
+                }
+            }
+            KeyCode::Char('m') if key.modifiers == KeyModifiers::CONTROL => {}
+            _ => {
+                self.target_textarea.input(key);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn compare(&self) -> anyhow::Result<()> {
+        if let Some(target) = &self.target {
+            self.action_tx.send(Action::S3 {
+                action: S3Action::CompareSync {
+                    target: target.clone(),
+                },
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn show_plan(&mut self, direction: S3SyncDirection) -> anyhow::Result<()> {
+        self.direction = Some(direction);
+        self.operation_list.set_selected_index(0);
+        self.set_focus()
+    }
+
+    fn get_comparison<'b>(&self, app_state: &'b AppState) -> Option<&'b S3SyncComparison> {
+        app_state
+            .s3_state
+            .sync_comparison
+            .as_ref()
+            .filter(|comparison| Some(&comparison.target) == self.target.as_ref())
+    }
+
+    fn create_block(&self, app_state: &AppState, title: String) -> Block<'_> {
+        Block::default()
+            .title(format!(
+                "{}[{}] ",
+                title, TUI_CONFIG.key_config.focus_aws_service.key_string
+            ))
+            .title_alignment(Alignment::Center)
+            .border_style(Style::new().fg(if self.has_focus(app_state) {
+                TUI_CONFIG.theme.border_highlight
+            } else {
+                TUI_CONFIG.theme.border
+            }))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+    }
+}
+
+/// `[profile:]s3://bucket/prefix` is a prefix, anything else a local directory.
+fn parse_sync_location(text: &str) -> Option<S3SyncLocation> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let Some(index) = text.find("s3://") else {
+        return Some(S3SyncLocation::Local {
+            path: PathBuf::from(text),
+        });
+    };
+    let profile_name = match &text[..index] {
+        "" => None,
+        profile => Some(
+            profile
+                .strip_suffix(':')
+                .filter(|name| !name.is_empty())?
+                .into(),
+        ),
+    };
+    let (bucket_name, prefix) = text[index + 5..]
+        .split_once('/')
+        .unwrap_or((&text[index + 5..], ""));
+    if bucket_name.is_empty() {
+        return None;
+    }
+
+    Some(S3SyncLocation::S3 {
+        profile_name,
+        bucket_name: bucket_name.into(),
+        prefix: if prefix.is_empty() || prefix.ends_with('/') {
+            prefix.into()
+        } else {
+            format!("{}/", prefix)
+        },
+    })
+}
+
+fn create_comparison_title(comparison: &S3SyncComparison) -> String {
+    let count = |status: S3SyncStatus| {
+        comparison
+            .entries
+            .iter()
+            .filter(|entry| entry.status == status)
+            .count()
+    };
+
+    format!(
+        " s3://{}/{} <> {} ({} added, {} changed, {} removed) ",
+        comparison.source_bucket.name,
+        comparison.source_prefix,
+        comparison.target,
+        count(S3SyncStatus::SourceOnly),
+        count(S3SyncStatus::Changed),
+        count(S3SyncStatus::TargetOnly)
+    )
+}
+
+/// Added and removed are seen from the prefix, a sync to the target adds and removes there.
+fn create_entry_item(entry: &S3SyncEntry) -> String {
+    let size = |file: &Option<S3SyncFile>| {
+        file.as_ref()
+            .map(|file| human_bytes(file.size as f64))
+            .unwrap_or_else(|| "-".into())
+    };
+
+    format!(
+        "{:<9}  {:>10}  {:>10}  {}",
+        match entry.status {
+            S3SyncStatus::SourceOnly => "added",
+            S3SyncStatus::TargetOnly => "removed",
+            S3SyncStatus::Changed => "changed",
+            S3SyncStatus::Unchanged => "",
+        },
+        size(&entry.source),
+        size(&entry.target),
+        entry.path
+    )
+}
+
+fn create_operation_item(operation: &S3TransferOperation) -> String {
+    let (name, size, path) = match operation {
+        S3TransferOperation::Download {
+            bucket,
+            key,
+            path,
+            size,
+        } => (
+            "download",
+            Some(size),
+            format!("s3://{}/{} -> {}", bucket.name, key, path.display()),
+        ),
+        S3TransferOperation::Upload {
+            path,
+            bucket,
+            key,
+            size,
+        } => (
+            "upload",
+            Some(size),
+            format!("{} -> s3://{}/{}", path.display(), bucket.name, key),
+        ),
+        S3TransferOperation::Copy {
+            source_bucket,
+            source_key,
+            bucket,
+            key,
+            size,
+        } => (
+            "copy",
+            Some(size),
+            format!(
+                "s3://{}/{} -> s3://{}/{}",
+                source_bucket.name, source_key, bucket.name, key
+            ),
+        ),
+        S3TransferOperation::DeleteObject { bucket, key } => {
+            ("delete", None, format!("s3://{}/{}", bucket.name, key))
+        }
+        S3TransferOperation::DeleteFile { path } => ("delete", None, path.display().to_string()),
+    };
+
+    format!(
+        "{:<8}  {:>10}  {}",
+        name,
+        size.map(|size| human_bytes(*size as f64))
+            .unwrap_or_default(),
+        path
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::s3_state::S3Bucket;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_prefix_of_profile_or_local_directory() {
+        assert_eq!(
+            parse_sync_location("s3://my-bucket/site"),
+            Some(S3SyncLocation::S3 {
+                profile_name: None,
+                bucket_name: "my-bucket".into(),
+                prefix: "site/".into(),
+            })
+        );
+        assert_eq!(
+            parse_sync_location(" prod:s3://my-bucket "),
+            Some(S3SyncLocation::S3 {
+                profile_name: Some("prod".into()),
+                bucket_name: "my-bucket".into(),
+                prefix: String::default(),
+            })
+        );
+        assert_eq!(
+            parse_sync_location("/data/site"),
+            Some(S3SyncLocation::Local {
+                path: PathBuf::from("/data/site"),
+            })
+        );
+        assert_eq!(parse_sync_location("s3://"), None);
+        assert_eq!(parse_sync_location("prod s3://my-bucket"), None);
+        assert_eq!(parse_sync_location(""), None);
+    }
+
+    #[test]
+    fn test_show_operations_of_dry_run() {
+        let bucket = S3Bucket {
+            name: "my-bucket".into(),
+            ..S3Bucket::default()
+        };
+
+        assert_eq!(
+            create_operation_item(&S3TransferOperation::Upload {
+                path: PathBuf::from("/data/index.html"),
+                bucket: bucket.clone(),
+                key: "site/index.html".into(),
+                size: 2000,
+            }),
+            "upload          2 KB  /data/index.html -> s3://my-bucket/site/index.html"
+        );
+        assert_eq!(
+            create_operation_item(&S3TransferOperation::DeleteObject {
+                bucket,
+                key: "site/old.html".into(),
+            }),
+            "delete                s3://my-bucket/site/old.html"
+        );
+    }
+}
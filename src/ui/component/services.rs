@@ -33,6 +33,7 @@ impl<'a> Component for ServicesComponent<'a> {
             services_list: ListComponent::from([
                 TUI_CONFIG.services.cloud_watch_logs,
                 TUI_CONFIG.services.dynamodb,
+                TUI_CONFIG.services.ec2,
                 TUI_CONFIG.services.eks,
                 TUI_CONFIG.services.s3_simple_storage_service,
                 TUI_CONFIG.services.service_catalog,
@@ -142,6 +143,7 @@ impl<'a> ServicesComponent<'a> {
         match service_name {
             val if TUI_CONFIG.services.cloud_watch_logs == val => AWSService::CloudWatchLogs,
             val if TUI_CONFIG.services.dynamodb == val => AWSService::DynamoDB,
+            val if TUI_CONFIG.services.ec2 == val => AWSService::Ec2,
             val if TUI_CONFIG.services.eks == val => AWSService::Eks,
             val if TUI_CONFIG.services.s3_simple_storage_service == val => AWSService::S3,
             val if TUI_CONFIG.services.service_catalog == val => AWSService::ServiceCatalog,
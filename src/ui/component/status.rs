@@ -3,7 +3,7 @@ use ratatui::{
     prelude::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{LineGauge, Paragraph},
     Frame,
 };
 use tokio::sync::mpsc::UnboundedSender;
@@ -11,7 +11,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use crate::{
     state::{
         action_handlers::actions::Action,
-        appstate::{AppState, ComponentType},
+        appstate::{AppState, ComponentType, ProgressState},
     },
     ui::tui_config::TUI_CONFIG,
 };
@@ -73,9 +73,40 @@ impl Component for StatusComponent {
             )
         };
 
+        let status_area = match &app_state.status_state.progress {
+            Some(progress) => {
+                let status_layout = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+                    .split(layout[1]);
+                self.render_progress(frame, status_layout[1], progress);
+                status_layout[0]
+            }
+            None => layout[1],
+        };
+
         frame.render_widget(
             Paragraph::new(status_text).alignment(Alignment::Center),
-            layout[1],
+            status_area,
         );
     }
 }
+
+impl StatusComponent {
+    fn render_progress(&self, frame: &mut Frame, area: Rect, progress: &ProgressState) {
+        match progress.total {
+            Some(total) if total > 0 => frame.render_widget(
+                LineGauge::default()
+                    .label(format!("{} {}/{}", progress.title, progress.current, total))
+                    .gauge_style(Style::default().fg(TUI_CONFIG.theme.border_highlight))
+                    .ratio((progress.current as f64 / total as f64).clamp(0.0, 1.0)),
+                area,
+            ),
+            _ => frame.render_widget(
+                Paragraph::new(format!("{} {}", progress.title, progress.current))
+                    .style(Style::default().fg(TUI_CONFIG.theme.status_message_text)),
+                area,
+            ),
+        }
+    }
+}
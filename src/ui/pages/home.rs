@@ -51,6 +51,12 @@ impl<'a> Component for HomePage<'a> {
             return Ok(());
         }
 
+        if app_state.focus_component == ComponentType::AWSService
+            && self.aws_service_page.is_capturing_input()
+        {
+            return self.aws_service_page.handle_key_event(key, app_state);
+        }
+
         match key.code {
             KeyCode::Char('x')
                 if app_state.focus_component == ComponentType::AWSService
@@ -63,7 +69,9 @@ impl<'a> Component for HomePage<'a> {
                 self.action_tx.send(Action::ToggleSidePane)?
             }
 
-            KeyCode::Tab if app_state.active_profile.is_some() => {
+            val if TUI_CONFIG.key_config.cycle_forward.key_code == val
+                && app_state.active_profile.is_some() =>
+            {
                 let component_type = match app_state.focus_component {
                     ComponentType::Profiles => ComponentType::Regions,
                     ComponentType::Regions => ComponentType::Services,
@@ -85,18 +93,16 @@ impl<'a> Component for HomePage<'a> {
                 self.set_child_component_focus(&component_type)?;
             }
 
-            KeyCode::BackTab => {
-                if app_state.active_profile.is_some() {
+            val if TUI_CONFIG.key_config.cycle_backward.key_code == val => {
+                if let Some(active_profile) = &app_state.active_profile {
                     let component_type = match app_state.focus_component {
                         ComponentType::Profiles
-                            if app_state.active_profile.as_ref().unwrap().selected_service
-                                != AWSService::None =>
+                            if active_profile.selected_service != AWSService::None =>
                         {
                             ComponentType::AWSService
                         }
                         ComponentType::Profiles
-                            if app_state.active_profile.as_ref().unwrap().selected_service
-                                == AWSService::None =>
+                            if active_profile.selected_service == AWSService::None =>
                         {
                             ComponentType::Services
                         }
@@ -193,3 +199,131 @@ impl<'a> HomePage<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use aws_config::SdkConfig;
+    use crossterm::event::KeyModifiers;
+    use ratatui::backend::TestBackend;
+    use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+    use crate::{
+        config::app_config::AppConfig,
+        state::{
+            action_handlers::actions::Ec2Action,
+            appstate::{Profile, ProfileSource},
+            ec2_state::{Ec2Image, Ec2Volume},
+        },
+    };
+
+    use super::*;
+
+    fn create_app_state(is_expanded: bool) -> AppState {
+        let mut app_state = AppState::new(&AppConfig::default());
+        app_state.focus_component = ComponentType::AWSService;
+        app_state.is_expanded = is_expanded;
+        app_state.active_profile = Some(Profile {
+            name: "test".into(),
+            source: ProfileSource::Environment,
+            sdk_config: SdkConfig::builder().build(),
+            account: String::default(),
+            user: String::default(),
+            err_message: String::default(),
+            err_message_backtrace: String::default(),
+            regions: vec![],
+            selected_region: None,
+            selected_service: AWSService::Ec2,
+        });
+        app_state.ec2_state.volumes = vec![Ec2Volume {
+            volume_id: "vol-1".into(),
+            ..Ec2Volume::default()
+        }];
+        app_state.ec2_state.images = vec![Ec2Image {
+            image_id: "ami-1".into(),
+            ..Ec2Image::default()
+        }];
+        app_state
+    }
+
+    /// Renders once, so the service page creates the component of the selected service.
+    fn create_home_page<'a>(app_state: &AppState) -> (HomePage<'a>, UnboundedReceiver<Action>) {
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let mut home_page = HomePage::new(action_tx);
+        let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+        terminal
+            .draw(|frame| home_page.render(frame, frame.size(), app_state))
+            .unwrap();
+        (home_page, action_rx)
+    }
+
+    fn press_keys(home_page: &mut HomePage, key_codes: &[KeyCode], app_state: &AppState) {
+        for key_code in key_codes {
+            home_page
+                .handle_key_event(KeyEvent::new(*key_code, KeyModifiers::NONE), app_state)
+                .unwrap();
+        }
+    }
+
+    fn receive_actions(action_rx: &mut UnboundedReceiver<Action>) -> Vec<Action> {
+        let mut actions = vec![];
+        while let Ok(action) = action_rx.try_recv() {
+            actions.push(action);
+        }
+        actions
+    }
+
+    #[test]
+    fn test_create_snapshot_with_expanded_side_pane() {
+        let app_state = create_app_state(true);
+        let (mut home_page, mut action_rx) = create_home_page(&app_state);
+
+        press_keys(
+            &mut home_page,
+            &[KeyCode::Char('e'), KeyCode::Char('s'), KeyCode::Enter],
+            &app_state,
+        );
+
+        let actions = receive_actions(&mut action_rx);
+        assert!(!actions
+            .iter()
+            .any(|action| matches!(action, Action::ToggleSidePane)));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Ec2 {
+                action: Ec2Action::CreateSnapshot { volume_id }
+            } if volume_id == "vol-1"
+        )));
+    }
+
+    #[test]
+    fn test_deregister_image_with_collapsed_side_pane() {
+        let app_state = create_app_state(false);
+        let (mut home_page, mut action_rx) = create_home_page(&app_state);
+
+        press_keys(
+            &mut home_page,
+            &[KeyCode::Char('e'), KeyCode::Char('a'), KeyCode::Char('a')],
+            &app_state,
+        );
+        press_keys(
+            &mut home_page,
+            &[KeyCode::Char('d')]
+                .into_iter()
+                .chain("ami-1".chars().map(KeyCode::Char))
+                .chain([KeyCode::Enter])
+                .collect::<Vec<KeyCode>>(),
+            &app_state,
+        );
+
+        let actions = receive_actions(&mut action_rx);
+        assert!(!actions
+            .iter()
+            .any(|action| matches!(action, Action::ToggleSidePane)));
+        assert!(actions.iter().any(|action| matches!(
+            action,
+            Action::Ec2 {
+                action: Ec2Action::DeregisterImage { image_id }
+            } if image_id == "ami-1"
+        )));
+    }
+}
@@ -6,6 +6,10 @@ use crate::state::action_handlers::actions::Action;
 use crate::state::appstate::{AWSService, AppState, ComponentType};
 
 use crate::ui::component::cloud_watch_logs::cloud_watch_log_groups::CloudWatchLogGroupComponent;
+use crate::ui::component::dynamodb::dynamodb_tables::DynamoDbTableComponent;
+use crate::ui::component::ec2::ec2_instances::Ec2InstanceComponent;
+use crate::ui::component::eks::eks_clusters::EksClusterComponent;
+use crate::ui::component::s3::s3_buckets::S3BucketComponent;
 use crate::ui::component::Component;
 use crate::ui::tui_config::TUI_CONFIG;
 
@@ -45,6 +49,10 @@ impl Component for AWSServicePage {
         Ok(())
     }
 
+    fn is_capturing_input(&self) -> bool {
+        self.active_component.is_capturing_input()
+    }
+
     fn set_focus(&self) -> anyhow::Result<()> {
         self.active_component.set_focus()?;
         self.send_focus_action(&self.action_tx)
@@ -89,11 +97,15 @@ impl AWSServicePage {
     }
 
     fn create_service_component(&self, selected_service: &AWSService) -> Box<dyn Component> {
-        let component = match selected_service {
-            AWSService::CloudWatchLogs => CloudWatchLogGroupComponent::new(self.action_tx.clone()),
-            _ => CloudWatchLogGroupComponent::new(self.action_tx.clone()),
-        };
-
-        Box::new(component)
+        match selected_service {
+            AWSService::CloudWatchLogs => {
+                Box::new(CloudWatchLogGroupComponent::new(self.action_tx.clone()))
+            }
+            AWSService::DynamoDB => Box::new(DynamoDbTableComponent::new(self.action_tx.clone())),
+            AWSService::Ec2 => Box::new(Ec2InstanceComponent::new(self.action_tx.clone())),
+            AWSService::Eks => Box::new(EksClusterComponent::new(self.action_tx.clone())),
+            AWSService::S3 => Box::new(S3BucketComponent::new(self.action_tx.clone())),
+            _ => Box::new(CloudWatchLogGroupComponent::new(self.action_tx.clone())),
+        }
     }
 }
@@ -6,6 +6,12 @@ pub struct TuiConfig<'a> {
     pub tick_rate_in_ms: u64,
     pub sys_info_update_rate_in_sec: u64,
     pub performance_measure_rate_in_sec: u64,
+    pub live_tail_poll_rate_in_sec: u64,
+    pub live_tail_lookback_in_min: u64,
+    pub live_tail_max_events: usize,
+    pub insights_poll_rate_in_sec: u64,
+    pub insights_time_ranges_in_min: &'a [u64],
+    pub s3_preview_size_in_kb: u64,
     pub key_config: KeyConfig<'a>,
     pub list_config: ListConfig<'a>,
     pub services: Services<'a>,
@@ -40,11 +46,19 @@ pub struct ListConfig<'a> {
     pub selection_up: KeyCode,
     pub selection_down: KeyCode,
     pub do_selection: KeyCode,
+    pub undo_selection: KeyCode,
+    pub page_up: KeyCode,
+    pub page_down: KeyCode,
+    pub scroll_left: KeyCode,
+    pub scroll_right: KeyCode,
+    pub jump_to_start: KeyCode,
+    pub jump_to_end: KeyCode,
 }
 
 pub struct Services<'a> {
     pub cloud_watch_logs: &'a str,
     pub dynamodb: &'a str,
+    pub ec2: &'a str,
     pub eks: &'a str,
     pub s3_simple_storage_service: &'a str,
     pub service_catalog: &'a str,
@@ -54,6 +68,68 @@ pub struct Messages<'a> {
     pub pending_action: &'a str,
     pub error_caller_identity: &'a str,
     pub error_describe_cloud_watch_log_groups: &'a str,
+    pub error_describe_cloud_watch_log_streams: &'a str,
+    pub error_get_cloud_watch_log_events: &'a str,
+    pub error_live_tail: &'a str,
+    pub error_insights_query: &'a str,
+    pub error_saved_queries: &'a str,
+    pub query_saved: &'a str,
+    pub error_put_cloud_watch_retention_policy: &'a str,
+    pub error_delete_cloud_watch_log_group: &'a str,
+    pub log_group_retention_updated: &'a str,
+    pub log_group_deleted: &'a str,
+    pub error_export_log_events: &'a str,
+    pub exporting_log_events: &'a str,
+    pub log_events_exported: &'a str,
+    pub error_describe_cloud_watch_filters: &'a str,
+    pub error_test_metric_filter: &'a str,
+    pub error_list_s3_buckets: &'a str,
+    pub error_list_s3_objects: &'a str,
+    pub error_s3_transfer: &'a str,
+    pub s3_transfer_finished: &'a str,
+    pub error_s3_preview: &'a str,
+    pub error_s3_versions: &'a str,
+    pub error_s3_restore: &'a str,
+    pub s3_version_restored: &'a str,
+    pub error_s3_bucket_config: &'a str,
+    pub error_s3_presign: &'a str,
+    pub error_s3_compare: &'a str,
+    pub error_s3_sync_profile: &'a str,
+    pub s3_sync_nothing: &'a str,
+    pub error_s3_sync_skipped: &'a str,
+    pub error_describe_ec2_instances: &'a str,
+    pub error_ec2_instance_state: &'a str,
+    pub error_describe_ec2_network_security: &'a str,
+    pub error_describe_ec2_vpcs: &'a str,
+    pub error_describe_ec2_storage: &'a str,
+    pub error_ec2_create_snapshot: &'a str,
+    pub error_ec2_deregister_image: &'a str,
+    pub error_ec2_delete_snapshot: &'a str,
+    pub ec2_snapshot_created: &'a str,
+    pub ec2_image_deregistered: &'a str,
+    pub ec2_snapshot_deleted: &'a str,
+    pub ec2_instance_started: &'a str,
+    pub ec2_instance_stopped: &'a str,
+    pub ec2_instance_rebooted: &'a str,
+    pub ec2_instance_terminated: &'a str,
+    pub error_describe_eks_clusters: &'a str,
+    pub error_describe_eks_cluster: &'a str,
+    pub error_eks_kubeconfig: &'a str,
+    pub eks_kubeconfig_written: &'a str,
+    pub error_list_dynamodb_tables: &'a str,
+    pub error_fetch_dynamodb_items: &'a str,
+    pub error_dynamodb_save_item: &'a str,
+    pub error_dynamodb_delete_item: &'a str,
+    pub dynamodb_item_saved: &'a str,
+    pub dynamodb_item_deleted: &'a str,
+    pub error_dynamodb_execute_statement: &'a str,
+    pub dynamodb_statement_executed: &'a str,
+    pub error_export_dynamodb_items: &'a str,
+    pub exporting_dynamodb_items: &'a str,
+    pub dynamodb_items_exported: &'a str,
+    pub error_import_dynamodb_items: &'a str,
+    pub importing_dynamodb_items: &'a str,
+    pub dynamodb_items_imported: &'a str,
 }
 
 pub struct Breadcrumbs<'a> {
@@ -62,6 +138,30 @@ pub struct Breadcrumbs<'a> {
     pub services: &'a str,
     pub cloud_watch_logs: &'a str,
     pub cloud_watch_logs_filtered: &'a str,
+    pub cloud_watch_log_streams: &'a str,
+    pub cloud_watch_log_events: &'a str,
+    pub cloud_watch_insights: &'a str,
+    pub cloud_watch_log_group_filters: &'a str,
+    pub s3_buckets: &'a str,
+    pub s3_preview: &'a str,
+    pub s3_versions: &'a str,
+    pub s3_bucket_config: &'a str,
+    pub s3_sync: &'a str,
+    pub ec2_instances: &'a str,
+    pub ec2_security_groups: &'a str,
+    pub ec2_network_acls: &'a str,
+    pub ec2_vpcs: &'a str,
+    pub ec2_volumes: &'a str,
+    pub ec2_snapshots: &'a str,
+    pub ec2_images: &'a str,
+    pub eks_clusters: &'a str,
+    pub eks_node_groups: &'a str,
+    pub eks_fargate_profiles: &'a str,
+    pub eks_addons: &'a str,
+    pub dynamodb_tables: &'a str,
+    pub dynamodb_items: &'a str,
+    pub dynamodb_item: &'a str,
+    pub dynamodb_partiql: &'a str,
 }
 
 pub struct Menu<'a> {
@@ -75,6 +175,66 @@ pub struct Menu<'a> {
     pub up: MenuItemText<'a>,
     pub down: MenuItemText<'a>,
     pub select: MenuItemText<'a>,
+    pub back: MenuItemText<'a>,
+    pub page_up: MenuItemText<'a>,
+    pub page_down: MenuItemText<'a>,
+    pub scroll_horizontal: MenuItemText<'a>,
+    pub follow: MenuItemText<'a>,
+    pub mark: MenuItemText<'a>,
+    pub insights: MenuItemText<'a>,
+    pub next_page: MenuItemText<'a>,
+    pub retention: MenuItemText<'a>,
+    pub delete: MenuItemText<'a>,
+    pub export: MenuItemText<'a>,
+    pub use_pattern: MenuItemText<'a>,
+    pub edit_pattern: MenuItemText<'a>,
+    pub edit_messages: MenuItemText<'a>,
+    pub test_pattern: MenuItemText<'a>,
+    pub refresh: MenuItemText<'a>,
+    pub parent_folder: MenuItemText<'a>,
+    pub download: MenuItemText<'a>,
+    pub upload: MenuItemText<'a>,
+    pub transfers: MenuItemText<'a>,
+    pub versions: MenuItemText<'a>,
+    pub presign: MenuItemText<'a>,
+    pub start: MenuItemText<'a>,
+    pub stop: MenuItemText<'a>,
+    pub reboot: MenuItemText<'a>,
+    pub terminate: MenuItemText<'a>,
+    pub security: MenuItemText<'a>,
+    pub switch_view: MenuItemText<'a>,
+    pub vpcs: MenuItemText<'a>,
+    pub toggle_node: MenuItemText<'a>,
+    pub storage: MenuItemText<'a>,
+    pub switch_storage_view: MenuItemText<'a>,
+    pub create_snapshot: MenuItemText<'a>,
+    pub deregister: MenuItemText<'a>,
+    pub switch_cluster_view: MenuItemText<'a>,
+    pub kubeconfig: MenuItemText<'a>,
+    pub compare: MenuItemText<'a>,
+    pub sync_to_target: MenuItemText<'a>,
+    pub sync_to_source: MenuItemText<'a>,
+    pub toggle_delete: MenuItemText<'a>,
+    pub apply: MenuItemText<'a>,
+    pub restore: MenuItemText<'a>,
+    pub edit_query: MenuItemText<'a>,
+    pub run_query: MenuItemText<'a>,
+    pub cancel_query: MenuItemText<'a>,
+    pub time_range: MenuItemText<'a>,
+    pub sort: MenuItemText<'a>,
+    pub save_query: MenuItemText<'a>,
+    pub load_query: MenuItemText<'a>,
+    pub scan: MenuItemText<'a>,
+    pub query: MenuItemText<'a>,
+    pub edit_item: MenuItemText<'a>,
+    pub create_item: MenuItemText<'a>,
+    pub save: MenuItemText<'a>,
+    pub toggle_json: MenuItemText<'a>,
+    pub partiql: MenuItemText<'a>,
+    pub edit_statement: MenuItemText<'a>,
+    pub run_statement: MenuItemText<'a>,
+    pub export_items: MenuItemText<'a>,
+    pub import_items: MenuItemText<'a>,
 }
 
 pub struct MenuItemText<'a> {
@@ -94,12 +254,24 @@ pub struct Theme<'a> {
     pub breadcrumb_foreground: Color,
     pub breadcrumb_background: Color,
     pub breadcrumb_background_active: Color,
+    pub log_event_timestamp: Color,
+    pub json_key: Color,
+    pub json_string: Color,
+    pub json_number: Color,
+    pub json_literal: Color,
+    pub filter_match: Color,
 }
 
 pub const TUI_CONFIG: TuiConfig = TuiConfig {
     tick_rate_in_ms: 250,
     sys_info_update_rate_in_sec: 5,
     performance_measure_rate_in_sec: 5,
+    live_tail_poll_rate_in_sec: 2,
+    live_tail_lookback_in_min: 5,
+    live_tail_max_events: 10_000,
+    insights_poll_rate_in_sec: 1,
+    insights_time_ranges_in_min: &[5, 15, 60, 180, 720, 1440, 4320, 10080],
+    s3_preview_size_in_kb: 64,
     key_config: KeyConfig {
         cycle_forward: KeyDescription {
             key_string: TAB,
@@ -138,10 +310,18 @@ pub const TUI_CONFIG: TuiConfig = TuiConfig {
         selection_up: KeyCode::Up,
         selection_down: KeyCode::Down,
         do_selection: KeyCode::Enter,
+        undo_selection: KeyCode::Esc,
+        page_up: KeyCode::PageUp,
+        page_down: KeyCode::PageDown,
+        scroll_left: KeyCode::Left,
+        scroll_right: KeyCode::Right,
+        jump_to_start: KeyCode::Home,
+        jump_to_end: KeyCode::End,
     },
     services: Services {
         cloud_watch_logs: "CloudWatch Logs",
         dynamodb: "DynamoDB",
+        ec2: "EC2 Elastic Compute Cloud",
         eks: "EKS Elastic Kubernetes Service",
         s3_simple_storage_service: "S3 Simple Storage Service",
         service_catalog: "Service Catalog",
@@ -152,6 +332,107 @@ pub const TUI_CONFIG: TuiConfig = TuiConfig {
             "Error: Cloud not fetch caller identity. Press <CTRL-m> for more information",
         error_describe_cloud_watch_log_groups:
             "Error: CloudWatch Log Groups could not be fetched. Press <CTRL-m> for more information",
+        error_describe_cloud_watch_log_streams:
+            "Error: CloudWatch Log Streams could not be fetched. Press <CTRL-m> for more information",
+        error_get_cloud_watch_log_events:
+            "Error: CloudWatch Log Events could not be fetched. Press <CTRL-m> for more information",
+        error_live_tail:
+            "Error: Following the CloudWatch Log Events stopped. Press <CTRL-m> for more information",
+        error_insights_query:
+            "Error: CloudWatch Logs Insights query failed. Press <CTRL-m> for more information",
+        error_saved_queries:
+            "Error: Saved queries could not be read or written. Press <CTRL-m> for more information",
+        query_saved: "Query saved",
+        error_put_cloud_watch_retention_policy:
+            "Error: Retention of the CloudWatch Log Group could not be changed. Press <CTRL-m> for more information",
+        error_delete_cloud_watch_log_group:
+            "Error: CloudWatch Log Group could not be deleted. Press <CTRL-m> for more information",
+        log_group_retention_updated: "Retention of the Log Group changed",
+        log_group_deleted: "Log Group deleted",
+        error_export_log_events:
+            "Error: Log Events could not be exported. Press <CTRL-m> for more information",
+        exporting_log_events: "Exporting Log Events",
+        log_events_exported: "Log Events exported to",
+        error_describe_cloud_watch_filters:
+            "Error: Metric and Subscription Filters could not be fetched. Press <CTRL-m> for more information",
+        error_test_metric_filter:
+            "Error: Filter Pattern could not be tested. Press <CTRL-m> for more information",
+        error_list_s3_buckets:
+            "Error: S3 Buckets could not be fetched. Press <CTRL-m> for more information",
+        error_list_s3_objects:
+            "Error: S3 Objects could not be fetched. Press <CTRL-m> for more information",
+        error_s3_transfer:
+            "Error: S3 transfer failed. Press <CTRL-m> for more information",
+        s3_transfer_finished: "S3 transfer finished:",
+        error_s3_preview:
+            "Error: S3 Object could not be previewed. Press <CTRL-m> for more information",
+        error_s3_versions:
+            "Error: S3 Object versions could not be fetched. Press <CTRL-m> for more information",
+        error_s3_restore:
+            "Error: S3 Object version could not be restored. Press <CTRL-m> for more information",
+        s3_version_restored: "S3 Object version restored:",
+        error_s3_bucket_config:
+            "Error: S3 Bucket configuration could not be fetched. Press <CTRL-m> for more information",
+        error_s3_presign:
+            "Error: Presigned URL could not be created. Press <CTRL-m> for more information",
+        error_s3_compare:
+            "Error: S3 prefix could not be compared. Press <CTRL-m> for more information",
+        error_s3_sync_profile:
+            "Error: Profile of the sync target could not be used. Press <CTRL-m> for more information",
+        s3_sync_nothing: "Nothing to synchronize",
+        error_s3_sync_skipped:
+            "Error: Objects with keys outside of the directory were skipped. Press <CTRL-m> for more information",
+        error_describe_ec2_instances:
+            "Error: EC2 instances could not be fetched. Press <CTRL-m> for more information",
+        error_ec2_instance_state:
+            "Error: EC2 instance state could not be changed. Press <CTRL-m> for more information",
+        error_describe_ec2_network_security:
+            "Error: Security groups could not be fetched. Press <CTRL-m> for more information",
+        error_describe_ec2_vpcs:
+            "Error: VPCs could not be fetched. Press <CTRL-m> for more information",
+        error_describe_ec2_storage:
+            "Error: Volumes, snapshots or AMIs could not be fetched. Press <CTRL-m> for more information",
+        error_ec2_create_snapshot:
+            "Error: Snapshot could not be created. Press <CTRL-m> for more information",
+        error_ec2_deregister_image:
+            "Error: AMI could not be deregistered. Press <CTRL-m> for more information",
+        error_ec2_delete_snapshot:
+            "Error: Snapshot could not be deleted. Press <CTRL-m> for more information",
+        ec2_snapshot_created: "Creating snapshot",
+        ec2_image_deregistered: "Deregistered AMI",
+        ec2_snapshot_deleted: "Deleted snapshot",
+        ec2_instance_started: "Starting EC2 instance",
+        ec2_instance_stopped: "Stopping EC2 instance",
+        ec2_instance_rebooted: "Rebooting EC2 instance",
+        ec2_instance_terminated: "Terminating EC2 instance",
+        error_describe_eks_clusters:
+            "Error: EKS clusters could not be fetched. Press <CTRL-m> for more information",
+        error_describe_eks_cluster:
+            "Error: Node groups, Fargate profiles or add-ons could not be fetched. Press <CTRL-m> for more information",
+        error_eks_kubeconfig:
+            "Error: Kubeconfig could not be written. Press <CTRL-m> for more information",
+        eks_kubeconfig_written: "Wrote kubeconfig entry of",
+        error_list_dynamodb_tables:
+            "Error: DynamoDB tables could not be fetched. Press <CTRL-m> for more information",
+        error_fetch_dynamodb_items:
+            "Error: DynamoDB items could not be fetched. Press <CTRL-m> for more information",
+        error_dynamodb_save_item:
+            "Error: DynamoDB item could not be saved. Press <CTRL-m> for more information",
+        error_dynamodb_delete_item:
+            "Error: DynamoDB item could not be deleted. Press <CTRL-m> for more information",
+        dynamodb_item_saved: "Saved item",
+        dynamodb_item_deleted: "Deleted item",
+        error_dynamodb_execute_statement:
+            "Error: PartiQL statement failed. Press <CTRL-m> for more information",
+        dynamodb_statement_executed: "Executed statement",
+        error_export_dynamodb_items:
+            "Error: DynamoDB items could not be exported. Press <CTRL-m> for more information",
+        exporting_dynamodb_items: "Exporting DynamoDB items",
+        dynamodb_items_exported: "DynamoDB items exported to",
+        error_import_dynamodb_items:
+            "Error: DynamoDB items could not be imported. Press <CTRL-m> for more information",
+        importing_dynamodb_items: "Importing DynamoDB items",
+        dynamodb_items_imported: "DynamoDB items imported from",
     },
     breadcrumbs: Breadcrumbs {
         profiles: " <profiles> ",
@@ -159,6 +440,30 @@ pub const TUI_CONFIG: TuiConfig = TuiConfig {
         services: " <services> ",
         cloud_watch_logs: " <logs>  ",
         cloud_watch_logs_filtered: " <filtered> ",
+        cloud_watch_log_streams: " <streams> ",
+        cloud_watch_log_events: " <events> ",
+        cloud_watch_insights: " <insights> ",
+        cloud_watch_log_group_filters: " <filters> ",
+        s3_buckets: " <buckets> ",
+        s3_preview: " <preview> ",
+        s3_versions: " <versions> ",
+        s3_bucket_config: " <configuration> ",
+        s3_sync: " <compare> ",
+        ec2_instances: " <instances> ",
+        ec2_security_groups: " <security groups> ",
+        ec2_network_acls: " <network acls> ",
+        ec2_vpcs: " <vpcs> ",
+        ec2_volumes: " <volumes> ",
+        ec2_snapshots: " <snapshots> ",
+        ec2_images: " <amis> ",
+        eks_clusters: " <clusters> ",
+        eks_node_groups: " <node groups> ",
+        eks_fargate_profiles: " <fargate profiles> ",
+        eks_addons: " <add-ons> ",
+        dynamodb_tables: " <tables> ",
+        dynamodb_items: " <items> ",
+        dynamodb_item: " <item> ",
+        dynamodb_partiql: " <partiql> ",
     },
     menu: Menu {
         collapse: MenuItemText {
@@ -201,6 +506,306 @@ pub const TUI_CONFIG: TuiConfig = TuiConfig {
             command: "<enter>",
             color_index: 0,
         },
+        back: MenuItemText {
+            title: "back",
+            command: "<esc>",
+            color_index: 0,
+        },
+        page_up: MenuItemText {
+            title: "prev page",
+            command: "<pgup>",
+            color_index: 0,
+        },
+        page_down: MenuItemText {
+            title: "next page",
+            command: "<pgdn>",
+            color_index: 0,
+        },
+        scroll_horizontal: MenuItemText {
+            title: "scroll sideways",
+            command: "<left/right>",
+            color_index: 0,
+        },
+        follow: MenuItemText {
+            title: "toggle follow",
+            command: "<t>",
+            color_index: 0,
+        },
+        mark: MenuItemText {
+            title: "mark",
+            command: "<space>",
+            color_index: 0,
+        },
+        insights: MenuItemText {
+            title: "insights",
+            command: "<q>",
+            color_index: 0,
+        },
+        retention: MenuItemText {
+            title: "retention",
+            command: "<r>",
+            color_index: 1,
+        },
+        delete: MenuItemText {
+            title: "delete",
+            command: "<d>",
+            color_index: 1,
+        },
+        use_pattern: MenuItemText {
+            title: "use pattern",
+            command: "<enter>",
+            color_index: 0,
+        },
+        edit_pattern: MenuItemText {
+            title: "edit pattern",
+            command: "<p>",
+            color_index: 0,
+        },
+        edit_messages: MenuItemText {
+            title: "edit samples",
+            command: "<m>",
+            color_index: 0,
+        },
+        refresh: MenuItemText {
+            title: "refresh",
+            command: "<u>",
+            color_index: 0,
+        },
+        download: MenuItemText {
+            title: "download",
+            command: "<d>",
+            color_index: 0,
+        },
+        upload: MenuItemText {
+            title: "upload",
+            command: "<p>",
+            color_index: 0,
+        },
+        transfers: MenuItemText {
+            title: "transfers",
+            command: "<t>",
+            color_index: 0,
+        },
+        versions: MenuItemText {
+            title: "versions",
+            command: "<v>",
+            color_index: 0,
+        },
+        compare: MenuItemText {
+            title: "compare",
+            command: "<y>",
+            color_index: 0,
+        },
+        sync_to_target: MenuItemText {
+            title: "preview sync to target",
+            command: "<>>",
+            color_index: 0,
+        },
+        sync_to_source: MenuItemText {
+            title: "preview sync to prefix",
+            command: "<<>",
+            color_index: 0,
+        },
+        toggle_delete: MenuItemText {
+            title: "toggle delete",
+            command: "<d>",
+            color_index: 1,
+        },
+        apply: MenuItemText {
+            title: "apply",
+            command: "<enter>",
+            color_index: 1,
+        },
+        start: MenuItemText {
+            title: "start",
+            command: "<s>",
+            color_index: 0,
+        },
+        stop: MenuItemText {
+            title: "stop",
+            command: "<o>",
+            color_index: 1,
+        },
+        reboot: MenuItemText {
+            title: "reboot",
+            command: "<b>",
+            color_index: 1,
+        },
+        security: MenuItemText {
+            title: "security groups",
+            command: "<g>",
+            color_index: 0,
+        },
+        switch_view: MenuItemText {
+            title: "groups/acls",
+            command: "<a>",
+            color_index: 0,
+        },
+        vpcs: MenuItemText {
+            title: "vpcs",
+            command: "<v>",
+            color_index: 0,
+        },
+        toggle_node: MenuItemText {
+            title: "expand/collapse",
+            command: "<enter>",
+            color_index: 0,
+        },
+        storage: MenuItemText {
+            title: "volumes",
+            command: "<e>",
+            color_index: 0,
+        },
+        switch_storage_view: MenuItemText {
+            title: "volumes/snapshots/amis",
+            command: "<a>",
+            color_index: 0,
+        },
+        create_snapshot: MenuItemText {
+            title: "create snapshot",
+            command: "<s>",
+            color_index: 0,
+        },
+        deregister: MenuItemText {
+            title: "deregister",
+            command: "<d>",
+            color_index: 1,
+        },
+        switch_cluster_view: MenuItemText {
+            title: "node groups/fargate/add-ons",
+            command: "<a>",
+            color_index: 0,
+        },
+        kubeconfig: MenuItemText {
+            title: "write kubeconfig",
+            command: "<k>",
+            color_index: 1,
+        },
+        terminate: MenuItemText {
+            title: "terminate",
+            command: "<t>",
+            color_index: 1,
+        },
+        presign: MenuItemText {
+            title: "presigned url",
+            command: "<g>",
+            color_index: 0,
+        },
+        restore: MenuItemText {
+            title: "restore",
+            command: "<r>",
+            color_index: 1,
+        },
+        parent_folder: MenuItemText {
+            title: "parent folder",
+            command: "<backspace>",
+            color_index: 0,
+        },
+        test_pattern: MenuItemText {
+            title: "test pattern",
+            command: "<r>",
+            color_index: 0,
+        },
+        export: MenuItemText {
+            title: "export",
+            command: "<e>",
+            color_index: 1,
+        },
+        next_page: MenuItemText {
+            title: "load more",
+            command: "<n>",
+            color_index: 0,
+        },
+        edit_query: MenuItemText {
+            title: "edit query",
+            command: "<e>",
+            color_index: 0,
+        },
+        run_query: MenuItemText {
+            title: "run query",
+            command: "<r>",
+            color_index: 0,
+        },
+        cancel_query: MenuItemText {
+            title: "cancel query",
+            command: "<k>",
+            color_index: 0,
+        },
+        time_range: MenuItemText {
+            title: "time range",
+            command: "<+/->",
+            color_index: 0,
+        },
+        sort: MenuItemText {
+            title: "sort column",
+            command: "<s>",
+            color_index: 0,
+        },
+        save_query: MenuItemText {
+            title: "save query",
+            command: "<w>",
+            color_index: 1,
+        },
+        load_query: MenuItemText {
+            title: "saved queries",
+            command: "<l>",
+            color_index: 1,
+        },
+        scan: MenuItemText {
+            title: "scan",
+            command: "<s>",
+            color_index: 0,
+        },
+        query: MenuItemText {
+            title: "query",
+            command: "<q>",
+            color_index: 0,
+        },
+        edit_item: MenuItemText {
+            title: "edit item",
+            command: "<e>",
+            color_index: 1,
+        },
+        create_item: MenuItemText {
+            title: "create item",
+            command: "<a>",
+            color_index: 1,
+        },
+        save: MenuItemText {
+            title: "save",
+            command: "<ctrl-s>",
+            color_index: 1,
+        },
+        toggle_json: MenuItemText {
+            title: "typed/plain json",
+            command: "<ctrl-t>",
+            color_index: 0,
+        },
+        partiql: MenuItemText {
+            title: "partiql console",
+            command: "<p>",
+            color_index: 0,
+        },
+        edit_statement: MenuItemText {
+            title: "edit statement",
+            command: "<e>",
+            color_index: 0,
+        },
+        run_statement: MenuItemText {
+            title: "run statement",
+            command: "<r>",
+            color_index: 0,
+        },
+        export_items: MenuItemText {
+            title: "export",
+            command: "<w>",
+            color_index: 0,
+        },
+        import_items: MenuItemText {
+            title: "import",
+            command: "<i>",
+            color_index: 0,
+        },
         info: MenuItemText {
             title: "infos",
             command: "<i>",
@@ -223,5 +828,11 @@ pub const TUI_CONFIG: TuiConfig = TuiConfig {
         breadcrumb_foreground: Color::Indexed(232),
         breadcrumb_background: Color::Rgb(0, 255, 255),
         breadcrumb_background_active: Color::Rgb(255, 165, 0),
+        log_event_timestamp: Color::Rgb(231, 120, 0),
+        json_key: Color::Rgb(135, 206, 250),
+        json_string: Color::Rgb(152, 195, 121),
+        json_number: Color::Rgb(209, 154, 102),
+        json_literal: Color::Rgb(198, 120, 221),
+        filter_match: Color::Rgb(255, 215, 0),
     },
 };